@@ -7,16 +7,12 @@ use super::*;
 use crate::Pallet as RmrkCore;
 
 use codec::alloc::string::ToString;
-use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::traits::{Currency, Get};
 use frame_system::RawOrigin;
 use rmrk_traits::{AccountIdOrCollectionNftTuple, BasicResource};
 use sp_runtime::traits::Bounded;
-use sp_std::vec;
-
-pub type BalanceOf<T> = <<T as pallet_uniques::Config>::Currency as Currency<
-	<T as frame_system::Config>::AccountId,
->>::Balance;
+use sp_std::{vec, vec::Vec};
 
 const SEED: u32 = 0;
 
@@ -71,6 +67,7 @@ fn create_test_collection<T: Config>(
 		metadata,
 		max,
 		symbol,
+		CollectionPolicy { transferability_mutable: true, revocable: true },
 	);
 
 	collection_id
@@ -98,6 +95,7 @@ fn mint_test_nft<T: Config>(
 		nft_metadata,
 		true,
 		resource,
+		None,
 	);
 	nft_id
 }
@@ -141,6 +139,7 @@ fn send_test_nft<T: Config>(
 		collection_id,
 		nft_id,
 		new_owner_enum,
+		None,
 	);
 }
 
@@ -156,6 +155,103 @@ fn prepare_resource<T: Config>(
 	(alice, bob, collection_id, nft_id, resource_id)
 }
 
+// Add `n` resources accepted by the owner to an NFT, returning them as a priority list
+fn add_accepted_resources<T: Config>(
+	issuer: T::AccountId,
+	owner: T::AccountId,
+	collection_id: T::CollectionId,
+	nft_id: T::ItemId,
+	n: u32,
+) -> BoundedVec<ResourceId, T::MaxPriorities> {
+	let mut priorities: BoundedVec<ResourceId, T::MaxPriorities> = vec![].try_into().unwrap();
+	for resource_id in 0..n {
+		let basic_resource = BasicResource { metadata: stbd::<T>("basic test metadata") };
+		let _ = RmrkCore::<T>::add_basic_resource(
+			RawOrigin::Signed(issuer.clone()).into(),
+			collection_id,
+			nft_id,
+			basic_resource,
+			Some(resource_id),
+			None,
+		);
+		let _ = RmrkCore::<T>::accept_resource(
+			RawOrigin::Signed(owner.clone()).into(),
+			collection_id,
+			nft_id,
+			resource_id,
+		);
+		let _ = priorities.try_push(resource_id);
+	}
+	priorities
+}
+
+// Give an NFT `k` accepted resources, all of them in its priorities and in the priority lists
+// of `c` contexts, with the first one a slot resource the NFT is equipped with
+fn prepare_resource_removal<T: Config>(
+	issuer: T::AccountId,
+	owner: T::AccountId,
+	collection_id: T::CollectionId,
+	nft_id: T::ItemId,
+	k: u32,
+	c: u32,
+) {
+	let slot_resource =
+		SlotResource { base: 0, metadata: Some(stbd::<T>("basic test metadata")), slot: 0 };
+	let _ = RmrkCore::<T>::add_slot_resource(
+		RawOrigin::Signed(issuer.clone()).into(),
+		collection_id,
+		nft_id,
+		slot_resource,
+		Some(0),
+		None,
+	);
+	let _ = RmrkCore::<T>::accept_resource(
+		RawOrigin::Signed(owner.clone()).into(),
+		collection_id,
+		nft_id,
+		0,
+	);
+	let mut priorities: BoundedVec<ResourceId, T::MaxPriorities> = bvec![0];
+	for resource_id in 1..k {
+		let basic_resource = BasicResource { metadata: stbd::<T>("basic test metadata") };
+		let _ = RmrkCore::<T>::add_basic_resource(
+			RawOrigin::Signed(issuer.clone()).into(),
+			collection_id,
+			nft_id,
+			basic_resource,
+			Some(resource_id),
+			None,
+		);
+		let _ = RmrkCore::<T>::accept_resource(
+			RawOrigin::Signed(owner.clone()).into(),
+			collection_id,
+			nft_id,
+			resource_id,
+		);
+		let _ = priorities.try_push(resource_id);
+	}
+	let _ = RmrkCore::<T>::set_priority(
+		RawOrigin::Signed(owner.clone()).into(),
+		collection_id,
+		nft_id,
+		priorities.clone(),
+	);
+	for context in 0..c {
+		let _ = RmrkCore::<T>::set_priority_for_context(
+			RawOrigin::Signed(owner.clone()).into(),
+			collection_id,
+			nft_id,
+			stbk::<T>(context.to_string().as_str()),
+			priorities.clone(),
+		);
+	}
+	Nfts::<T>::mutate(collection_id, nft_id, |nft| {
+		if let Some(nft) = nft {
+			nft.equipped = Some((0, 0));
+		}
+	});
+}
+
 fn set_properties<T: Config>(
 	caller: T::AccountId,
 	collection_id: T::CollectionId,
@@ -183,7 +279,8 @@ benchmarks! {
 		let symbol = bvec![0u8; 15];
 		<T as pallet_uniques::Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 
-	}: _(RawOrigin::Signed(caller.clone()), collection_id, metadata, max, symbol)
+		let policy = CollectionPolicy::default();
+	}: _(RawOrigin::Signed(caller.clone()), collection_id, metadata, max, symbol, policy)
 	verify {
 		assert_last_event::<T>(Event::CollectionCreated { issuer: caller, collection_id }.into());
 	}
@@ -197,11 +294,12 @@ benchmarks! {
 		let royalty = Permill::from_percent(1);
 		let nft_metadata = bvec![0u8; 20];
 		let resource = None;
+		let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
 		let owner_enum = AccountIdOrCollectionNftTuple::AccountId(owner.clone());
 
 		<T as pallet_uniques::Config>::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value());
 
-	}: _(RawOrigin::Signed(owner.clone()), None, nft_id, collection_id, Some(royalty_recipient), Some(royalty), nft_metadata, true, resource)
+	}: _(RawOrigin::Signed(owner.clone()), None, nft_id, collection_id, Some(royalty_recipient), Some(royalty), nft_metadata, true, resource, Some(expires_at))
 	verify {
 		assert_last_event::<T>(Event::NftMinted{ owner: owner_enum, collection_id, nft_id }.into());
 	}
@@ -224,8 +322,9 @@ benchmarks! {
 		let royalty = Permill::from_percent(1);
 		let nft_metadata = bvec![0u8; 20];
 		let resource = None;
+		let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
 
-	}: _(RawOrigin::Signed(owner.clone()), nft_owner_tuple, nft_child_id, collection_id, Some(royalty_recipient), Some(royalty), nft_metadata, true, resource)
+	}: _(RawOrigin::Signed(owner.clone()), nft_owner_tuple, nft_child_id, collection_id, Some(royalty_recipient), Some(royalty), nft_metadata, true, resource, Some(expires_at))
 	verify {
 		assert!(RmrkCore::<T>::nfts(collection_id, nft_id).is_some());
 		assert!(RmrkCore::<T>::nfts(collection_id, nft_child_id).is_some());
@@ -233,9 +332,11 @@ benchmarks! {
 	}
 
 	destroy_collection {
+		let k in 0 .. T::PropertiesLimit::get();
 		let owner: T::AccountId = whitelisted_caller();
 		let collection_index = 1;
 		let collection_id = create_test_collection::<T>(owner.clone(), collection_index);
+		set_properties::<T>(owner.clone(), collection_id, None, k);
 
 	}:  _(RawOrigin::Signed(owner.clone()), collection_id)
 	verify {
@@ -256,7 +357,7 @@ benchmarks! {
 		let bob = funded_account::<T>("bob", 0);
 		let new_owner = AccountIdOrCollectionNftTuple::AccountId(bob);
 
-	}: send(RawOrigin::Signed(owner.clone()), collection_id, nft_id, new_owner.clone())
+	}: send(RawOrigin::Signed(owner.clone()), collection_id, nft_id, new_owner.clone(), None)
 	verify {
 		assert_last_event::<T>(Event::NFTSent {
 			sender: owner,
@@ -284,7 +385,7 @@ benchmarks! {
 		// Alice sends child NFT (0,n) to parent NFT (0,1)
 		let parent_nft = AccountIdOrCollectionNftTuple::CollectionAndNftTuple(collection_id, nft_id1);
 
-	}: send(RawOrigin::Signed(alice.clone()), collection_id, child_nft, parent_nft.clone())
+	}: send(RawOrigin::Signed(alice.clone()), collection_id, child_nft, parent_nft.clone(), None)
 	verify {
 		assert_last_event::<T>(Event::NFTSent {
 			sender: alice,
@@ -298,6 +399,7 @@ benchmarks! {
 	burn_nft {
 		let n in 1 .. T::NestingBudget::get();
 		let k in 0 .. T::PropertiesLimit::get();
+		let r in 0 .. T::MaxResourcesPerNft::get();
 
 		let owner: T::AccountId = whitelisted_caller();
 		let collection_index = 1;
@@ -305,8 +407,11 @@ benchmarks! {
 		let nft_id = mint_test_nft::<T>(owner.clone(), None, collection_id, 0);
 
 		set_properties::<T>(owner.clone(), collection_id, Some(nft_id), k);
+		let _ = add_accepted_resources::<T>(owner.clone(), owner.clone(), collection_id, nft_id, r);
 		mint_and_send_to_parent::<T>(owner.clone(), collection_id, n);
-		RmrkCore::<T>::set_lock((collection_id, nft_id), true);
+		let _ = RmrkCore::<T>::set_lock(*b"rmrkbnch", (collection_id, nft_id));
+		let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		let _ = RmrkCore::<T>::set_nft_expiry(collection_id, nft_id, expires_at);
 	}: _(RawOrigin::Signed(owner.clone()), collection_id, nft_id)
 	verify {
 		assert_last_event::<T>(Event::NFTBurned { owner, collection_id, nft_id }.into());
@@ -361,29 +466,45 @@ benchmarks! {
 		let parent_nft = AccountIdOrCollectionNftTuple::CollectionAndNftTuple(collection_id, nft_id1);
 		send_test_nft::<T>(alice.clone(), collection_id, child_nft, parent_nft.clone());
 
-	}: _(RawOrigin::Signed(bob.clone()), collection_id, child_nft)
+	}: _(RawOrigin::Signed(bob.clone()), collection_id, child_nft, true)
 	verify {
 		assert_last_event::<T>(Event::NFTRejected {
 			sender: bob,
 			collection_id,
 			nft_id: child_nft,
+			burned: true,
 		}.into());
 	}
 
-	change_collection_issuer {
+	propose_issuer {
 		let alice: T::AccountId = whitelisted_caller();
 		let collection_index = 1;
 		let collection_id = create_test_collection::<T>(alice.clone(), collection_index);
 		let _ = mint_test_nft::<T>(alice.clone(), None, collection_id, 1);
 
-		// Must set ownership acceptance with BOB before transfer due to uniques dependency
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
-		T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value());
-		let origin = RawOrigin::Signed(target.clone()).into();
-		pallet_uniques::Pallet::<T>::set_accept_ownership(origin, Some(collection_id))?;
 
 	}: _(RawOrigin::Signed(alice.clone()), collection_id, target_lookup)
+	verify {
+		assert_last_event::<T>(Event::IssuerProposed {
+			issuer: alice,
+			proposed_issuer: target,
+			collection_id,
+		}.into());
+	}
+
+	accept_issuer {
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_index = 1;
+		let collection_id = create_test_collection::<T>(alice.clone(), collection_index);
+		let _ = mint_test_nft::<T>(alice.clone(), None, collection_id, 1);
+
+		let target = funded_account::<T>("target", 0);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		RmrkCore::<T>::propose_issuer(RawOrigin::Signed(alice.clone()).into(), collection_id, target_lookup)?;
+
+	}: _(RawOrigin::Signed(target.clone()), collection_id)
 	verify {
 		assert_last_event::<T>(Event::IssuerChanged {
 			old_issuer: alice,
@@ -392,6 +513,39 @@ benchmarks! {
 		}.into());
 	}
 
+	force_collection_issuer {
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let target = funded_account::<T>("target", 0);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let origin = T::ProtocolOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, collection_id, target_lookup)
+	verify {
+		assert_last_event::<T>(Event::IssuerChanged {
+			old_issuer: alice,
+			new_issuer: target,
+			collection_id,
+		}.into());
+	}
+
+	cancel_issuer_proposal {
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_index = 1;
+		let collection_id = create_test_collection::<T>(alice.clone(), collection_index);
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		RmrkCore::<T>::propose_issuer(RawOrigin::Signed(alice.clone()).into(), collection_id, target_lookup)?;
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id)
+	verify {
+		assert_last_event::<T>(Event::IssuerProposalCancelled {
+			issuer: alice,
+			proposed_issuer: target,
+			collection_id,
+		}.into());
+	}
+
 	set_property {
 		let alice: T::AccountId = whitelisted_caller();
 
@@ -418,14 +572,248 @@ benchmarks! {
 		assert_last_event::<T>(Event::CollectionLocked { issuer: alice, collection_id }.into());
 	}
 
-	replace_resource {
+	unlock_collection {
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_index = 1;
+		let collection_id = create_test_collection::<T>(alice.clone(), collection_index);
+		RmrkCore::<T>::lock_collection(RawOrigin::Signed(alice.clone()).into(), collection_id)?;
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id)
+	verify {
+		assert_last_event::<T>(Event::CollectionUnlocked { issuer: alice, collection_id }.into());
+	}
+
+	set_collection_max {
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_index = 1;
+		let collection_id = create_test_collection::<T>(alice.clone(), collection_index);
+		let _ = mint_test_nft::<T>(alice.clone(), None, collection_id, 1);
+		let max = Some(1);
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, max)
+	verify {
+		assert_last_event::<T>(Event::CollectionMaxSet { issuer: alice, collection_id, max }.into());
+	}
+
+	start_destroy_collection {
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_index = 1;
+		let collection_id = create_test_collection::<T>(alice.clone(), collection_index);
+		let _ = mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id)
+	verify {
+		assert_last_event::<T>(Event::CollectionDestroyStarted { issuer: alice, collection_id }.into());
+	}
+
+	destroy_collection_items {
+		let n in 1 .. T::MaxDestroyItems::get();
+		let b in 1 .. T::NestingBudget::get();
+		let p in 0 .. T::PropertiesLimit::get();
+
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let children_collection_id = create_test_collection::<T>(alice.clone(), 2);
+		(0..n).for_each(|i| {
+			mint_test_nft::<T>(alice.clone(), None, collection_id, i);
+		});
+		// Nest a chain of `b - 1` NFTs of another collection under the last NFT, which are moved
+		// out to alice
+		(0..b.saturating_sub(1)).for_each(|i| {
+			let child_id = mint_test_nft::<T>(alice.clone(), None, children_collection_id, i);
+			let new_owner = if i == 0 {
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(collection_id, T::Helper::item(n - 1))
+			} else {
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(children_collection_id, T::Helper::item(i - 1))
+			};
+			send_test_nft::<T>(alice.clone(), children_collection_id, child_id, new_owner);
+		});
+		set_properties::<T>(alice.clone(), collection_id, None, p);
+		let _ = RmrkCore::<T>::start_destroy_collection(RawOrigin::Signed(alice.clone()).into(), collection_id);
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, n)
+	verify {
+		assert_last_event::<T>(Event::CollectionItemsDestroyed { collection_id, destroyed: n, remaining: 0 }.into());
+	}
+
+	approve_transfer {
+		let n in 1 .. T::NestingBudget::get();
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
+		let nft_id = T::Helper::item(n - 1);
+		let bob = funded_account::<T>("bob", 0);
+		let delegate = T::Lookup::unlookup(bob.clone());
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, delegate, None)
+	verify {
+		assert_last_event::<T>(Event::TransferApproved { owner: alice, collection_id, nft_id, delegate: bob, expires: None }.into());
+	}
+
+	cancel_approval {
+		let n in 1 .. T::NestingBudget::get();
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
+		let nft_id = T::Helper::item(n - 1);
+		let bob = funded_account::<T>("bob", 0);
+		let _ = RmrkCore::<T>::approve_transfer(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, T::Lookup::unlookup(bob.clone()), None);
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id)
+	verify {
+		assert_last_event::<T>(Event::ApprovalCancelled { owner: alice, collection_id, nft_id, delegate: bob }.into());
+	}
+
+	set_operator {
+		let alice: T::AccountId = whitelisted_caller();
+		let bob = funded_account::<T>("bob", 0);
+		let operator = T::Lookup::unlookup(bob.clone());
+
+	}: _(RawOrigin::Signed(alice.clone()), operator, true)
+	verify {
+		assert_last_event::<T>(Event::OperatorSet { owner: alice, operator: bob, approved: true }.into());
+	}
+
+	send_batch {
+		let n in 1 .. T::MaxBatchSends::get();
+		let b in 1 .. T::NestingBudget::get();
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let items_collection_id = create_test_collection::<T>(alice.clone(), 2);
+		// Nested chain of `b` NFTs, the items are sent to the deepest one
+		mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, b);
+		let parent_nft = AccountIdOrCollectionNftTuple::CollectionAndNftTuple(collection_id, T::Helper::item(b - 1));
+		let items: BoundedSendBatchOf<T> = (0..n)
+			.map(|i| (items_collection_id, mint_test_nft::<T>(alice.clone(), None, items_collection_id, i), parent_nft.clone()))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let nft_id = T::Helper::item(n - 1);
+
+	}: _(RawOrigin::Signed(alice.clone()), items)
+	verify {
+		assert_last_event::<T>(Event::NFTSent {
+			sender: alice,
+			recipient: parent_nft,
+			collection_id: items_collection_id,
+			nft_id,
+			approval_required: false,
+		}.into());
+	}
+
+	set_pending_expiry {
 		let (alice, _, collection_id, nft_id, resource_id) = prepare_resource::<T>();
 		let basic_resource = BasicResource{ metadata: stbd::<T> ("basic test metadata") };
-		let _ = RmrkCore::<T>::add_basic_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, basic_resource, resource_id);
+		let _ = RmrkCore::<T>::add_basic_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, basic_resource, Some(resource_id), None);
+		let expires: T::BlockNumber = 10u32.into();
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, Some(resource_id), expires)
+	verify {
+		assert_last_event::<T>(Event::PendingExpirySet { collection_id, nft_id, maybe_resource_id: Some(resource_id), expires }.into());
+	}
+
+	expire_pending {
+		let alice: T::AccountId = whitelisted_caller();
+		let bob = funded_account::<T>("bob", 0);
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let nft_id = mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
+		let bob_nft_id = mint_test_nft::<T>(alice.clone(), Some(bob.clone()), collection_id, 1);
+		let _ = RmrkCore::<T>::accept_nft(
+			RawOrigin::Signed(bob.clone()).into(),
+			collection_id,
+			bob_nft_id,
+			AccountIdOrCollectionNftTuple::AccountId(bob),
+		);
+		send_test_nft::<T>(
+			alice.clone(),
+			collection_id,
+			nft_id,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(collection_id, bob_nft_id),
+		);
+		let expires = frame_system::Pallet::<T>::block_number();
+		let _ = RmrkCore::<T>::set_pending_expiry(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, None, expires);
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, None)
+	verify {
+		assert_last_event::<T>(Event::PendingNftExpired { collection_id, nft_id, returned_to: AccountIdOrCollectionNftTuple::AccountId(alice) }.into());
+	}
+
+	set_transferable {
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let nft_id = mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, false)
+	verify {
+		assert_last_event::<T>(Event::NFTTransferabilitySet { issuer: alice, collection_id, nft_id, transferable: false }.into());
+	}
+
+	revoke {
+		let n in 1 .. T::NestingBudget::get();
+		let k in 0 .. T::PropertiesLimit::get();
+
+		let alice: T::AccountId = whitelisted_caller();
+		let bob = funded_account::<T>("bob", 0);
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let nft_id = mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
+		set_properties::<T>(alice.clone(), collection_id, Some(nft_id), k);
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
+		send_test_nft::<T>(alice.clone(), collection_id, nft_id, AccountIdOrCollectionNftTuple::AccountId(bob));
+		let _ = RmrkCore::<T>::set_transferable(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, false);
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id)
+	verify {
+		assert_last_event::<T>(Event::NFTRevoked { issuer: alice, collection_id, nft_id }.into());
+	}
+
+	force_unlock {
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let nft_id = mint_test_nft::<T>(alice, None, collection_id, 0);
+		let _ = RmrkCore::<T>::set_lock(*b"rmrkbnch", (collection_id, nft_id));
+		let origin = T::ProtocolOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, collection_id, nft_id, *b"rmrkbnch")
+	verify {
+		assert_last_event::<T>(Event::NFTUnlocked { collection_id, nft_id, id: *b"rmrkbnch" }.into());
+	}
+
+	pause_calls {
+		let collection_id = T::Helper::collection(1);
+		let origin = T::ProtocolOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, CallGroup::Transfers, Some(collection_id))
+	verify {
+		assert_last_event::<T>(Event::CallsPaused { group: CallGroup::Transfers, collection_id: Some(collection_id) }.into());
+	}
+
+	unpause_calls {
+		let collection_id = T::Helper::collection(1);
+		PausedCalls::<T>::insert(CallGroup::Transfers, Some(collection_id), ());
+		let origin = T::ProtocolOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, CallGroup::Transfers, Some(collection_id))
+	verify {
+		assert_last_event::<T>(Event::CallsUnpaused { group: CallGroup::Transfers, collection_id: Some(collection_id) }.into());
+	}
+
+	replace_resource {
+		let (alice, _, collection_id, _, resource_id) = prepare_resource::<T>();
+
+		let n in 1 .. T::NestingBudget::get();
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
+		let nft_id = T::Helper::item(n-1);
+
+		let basic_resource = BasicResource{ metadata: stbd::<T> ("basic test metadata") };
+		let _ = RmrkCore::<T>::add_basic_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, basic_resource.clone(), Some(resource_id), None);
 		let resource = ResourceTypes::Basic(BasicResource { metadata: stbd::<T> ("replaced basic test metadata") }); // new_resource
+		// The NFT already has as many pending replacements as it can but one
+		for other_resource_id in 1..T::MaxReplacementsPerNft::get() {
+			let _ = RmrkCore::<T>::add_basic_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, basic_resource.clone(), Some(other_resource_id), None);
+			let _ = RmrkCore::<T>::replace_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, resource.clone(), other_resource_id);
+		}
+		// The NFT isn't owned by the issuer, so the replacement is pending
 	}:  _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, resource, resource_id)
 	verify {
-		assert_last_event::<T>(Event::ResourceReplaced { nft_id, resource_id, collection_id }.into());
+		assert_last_event::<T>(Event::ResourceReplacementProposed { nft_id, resource_id, collection_id }.into());
 	}
 
 	add_basic_resource{
@@ -436,7 +824,7 @@ benchmarks! {
 		let nft_id = T::Helper::item(n-1);
 
 		let basic_resource = BasicResource{ metadata: stbd::<T> ("basic test metadata") };
-	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, basic_resource, resource_id)
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, basic_resource, Some(resource_id), None)
 	verify {
 		assert_last_event::<T>(Event::ResourceAdded { nft_id, resource_id, collection_id }.into());
 	}
@@ -455,7 +843,7 @@ benchmarks! {
 			slot: None,
 		};
 
-	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, composable_resource, resource_id)
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, composable_resource, Some(resource_id), None)
 	verify {
 		assert_last_event::<T>(Event::ResourceAdded { nft_id, resource_id, collection_id }.into());
 	}
@@ -473,7 +861,7 @@ benchmarks! {
 			slot: 0, // SlotID
 		};
 
-	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, slot_resource, resource_id)
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, slot_resource, Some(resource_id), None)
 	verify {
 		assert_last_event::<T>(Event::ResourceAdded { nft_id, resource_id, collection_id }.into());
 	}
@@ -487,7 +875,7 @@ benchmarks! {
 
 		let basic_resource = BasicResource{ metadata: stbd::<T> ("basic test metadata") };
 		// Alice is collection issuer and she adds resource to bob's nft
-		let _ = RmrkCore::<T>::add_basic_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, basic_resource, resource_id);
+		let _ = RmrkCore::<T>::add_basic_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, basic_resource, Some(resource_id), None);
 		// Bob accepts new resource
 	}: _(RawOrigin::Signed(bob), collection_id, nft_id, resource_id)
 	verify {
@@ -495,35 +883,37 @@ benchmarks! {
 	}
 
 	remove_resource{
-		let (alice, bob, collection_id, _, resource_id) = prepare_resource::<T>();
-
 		let n in 1 .. T::NestingBudget::get();
+		let k in 1 .. T::MaxPriorities::get();
+		let c in 1 .. T::MaxPriorityContexts::get();
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let _ = mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
 		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
 		let nft_id = T::Helper::item(n-1);
+		let resource_id = 0;
 
-		let basic_resource = BasicResource{ metadata: stbd::<T> ("basic test metadata") };
-		// Alice is collection issuer and she adds resource to bob's nft
-		let _ = RmrkCore::<T>::add_basic_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, basic_resource, resource_id);
-		// Bob accepts new resource
-		let _ = RmrkCore::<T>::accept_resource(RawOrigin::Signed(bob).into(), collection_id, nft_id, resource_id);
-		// Only collection issuer can request resource removal
+		prepare_resource_removal::<T>(alice.clone(), alice.clone(), collection_id, nft_id, k, c);
+		// Alice is collection issuer and root owner, the resource is removed right away
 	}: _(RawOrigin::Signed(alice), collection_id, nft_id, resource_id)
 	verify {
 		assert_last_event::<T>(Event::ResourceRemoval { nft_id, resource_id, collection_id }.into());
 	}
 
 	accept_resource_removal{
-		let (alice, bob, collection_id, _, resource_id) = prepare_resource::<T>();
-
 		let n in 1 .. T::NestingBudget::get();
+		let k in 1 .. T::MaxPriorities::get();
+		let c in 1 .. T::MaxPriorityContexts::get();
+		let alice: T::AccountId = whitelisted_caller();
+		let bob = funded_account::<T>("bob", 0);
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let root_nft_id = mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
 		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
+		send_test_nft::<T>(alice.clone(), collection_id, root_nft_id, AccountIdOrCollectionNftTuple::AccountId(bob.clone()));
 		let nft_id = T::Helper::item(n-1);
+		let resource_id = 0;
 
-		let basic_resource = BasicResource{ metadata: stbd::<T> ("basic test metadata") };
-		// Alice is collection issuer and she adds resource to bob's nft
-		let _ = RmrkCore::<T>::add_basic_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, basic_resource, resource_id);
-		// Bob accepts new resource
-		let _ = RmrkCore::<T>::accept_resource(RawOrigin::Signed(bob.clone()).into(), collection_id, nft_id, resource_id);
+		prepare_resource_removal::<T>(alice.clone(), bob.clone(), collection_id, nft_id, k, c);
 		// Only collection issuer can request resource removal
 		let _ = RmrkCore::<T>::remove_resource(RawOrigin::Signed(alice).into(), collection_id, nft_id, resource_id);
 		// Bob accepts resource removal
@@ -535,22 +925,265 @@ benchmarks! {
 	set_priority{
 		let n in 1 .. T::MaxPriorities::get();
 		let k in 1 .. T::NestingBudget::get();
-		let (alice, bob, collection_id, _, resource_id) = prepare_resource::<T>();
+		let (alice, bob, collection_id, _, _) = prepare_resource::<T>();
 
 		mint_and_send_to_parent::<T>(alice.clone(), collection_id, k);
 		let nft_id = T::Helper::item(k-1);
 
-		let basic_resource = BasicResource{ metadata: stbd::<T> ("basic test metadata") };
-		let mut priorities: BoundedVec<ResourceId, T::MaxPriorities> = vec![].try_into().unwrap();
-		for resource_id in 1 .. n{
-			let _ = priorities.try_push(resource_id);
-		}
+		let priorities = add_accepted_resources::<T>(alice, bob.clone(), collection_id, nft_id, n);
 
 	}: _(RawOrigin::Signed(bob), collection_id, nft_id, priorities)
 	verify {
 		assert_last_event::<T>(Event::PrioritySet { collection_id, nft_id }.into());
 	}
 
+	add_collection_resource {
+		let (alice, _, collection_id, _, resource_id) = prepare_resource::<T>();
+		// The collection already shares as many resources as it can but one
+		for other_resource_id in 1..T::MaxCollectionResources::get() {
+			let basic_resource = ResourceTypes::Basic(BasicResource { metadata: stbd::<T>("basic test metadata") });
+			let _ = RmrkCore::<T>::add_collection_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, basic_resource, other_resource_id);
+		}
+		let composable_resource = ResourceTypes::Composable(ComposableResource {
+			parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
+			base: 0,                               // BaseID
+			metadata: Some(stbd::<T> ("basic test metadata")),
+			slot: Some((0, 1)),
+		});
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, composable_resource, resource_id)
+	verify {
+		assert_last_event::<T>(Event::CollectionResourceAdded { collection_id, resource_id }.into());
+	}
+
+	remove_collection_resource {
+		let n in 0 .. T::MaxCollectionResourceNfts::get();
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		// The ID doesn't collide with the resources of the NFTs
+		let resource_id: ResourceId = T::MaxPriorities::get();
+		let slot_resource = ResourceTypes::Slot(SlotResource {
+			base: 0,
+			metadata: Some(stbd::<T>("basic test metadata")),
+			slot: 0,
+		});
+		let _ = RmrkCore::<T>::add_collection_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, slot_resource, resource_id);
+		for i in 0..n {
+			let nft_id = mint_test_nft::<T>(alice.clone(), None, collection_id, i);
+			// The shared resource comes first in the priorities of the NFT and of its contexts
+			let own = add_accepted_resources::<T>(alice.clone(), alice.clone(), collection_id, nft_id, T::MaxPriorities::get() - 1);
+			let mut priorities: BoundedVec<ResourceId, T::MaxPriorities> = bvec![resource_id];
+			for own_resource_id in own {
+				let _ = priorities.try_push(own_resource_id);
+			}
+			let _ = RmrkCore::<T>::set_priority(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, priorities.clone());
+			for context in 0..T::MaxPriorityContexts::get() {
+				let _ = RmrkCore::<T>::set_priority_for_context(
+					RawOrigin::Signed(alice.clone()).into(),
+					collection_id,
+					nft_id,
+					stbk::<T>(context.to_string().as_str()),
+					priorities.clone(),
+				);
+			}
+			Nfts::<T>::mutate(collection_id, nft_id, |nft| {
+				if let Some(nft) = nft {
+					nft.equipped = Some((resource_id, 0));
+				}
+			});
+		}
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, resource_id)
+	verify {
+		assert_last_event::<T>(Event::CollectionResourceRemoved { collection_id, resource_id }.into());
+	}
+
+	add_resource_to_many {
+		let n in 1 .. T::MaxBatchResources::get();
+		let b in 1 .. T::NestingBudget::get();
+		let alice: T::AccountId = whitelisted_caller();
+		let collection_id = create_test_collection::<T>(alice.clone(), 1);
+		let items_collection_id = create_test_collection::<T>(alice.clone(), 2);
+		// The NFTs are nested at the bottom of a chain of `b` NFTs
+		mint_test_nft::<T>(alice.clone(), None, collection_id, 0);
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, b);
+		let parent_nft = AccountIdOrCollectionNftTuple::CollectionAndNftTuple(collection_id, T::Helper::item(b - 1));
+		let nft_ids: BoundedResourceBatchOf<T> = (0..n)
+			.map(|i| {
+				let nft_id = mint_test_nft::<T>(alice.clone(), None, items_collection_id, i);
+				send_test_nft::<T>(alice.clone(), items_collection_id, nft_id, parent_nft.clone());
+				nft_id
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let nft_id = T::Helper::item(n - 1);
+		let resource_id = 0;
+		let basic_resource = ResourceTypes::Basic(BasicResource { metadata: stbd::<T> ("basic test metadata") });
+
+	}: _(RawOrigin::Signed(alice.clone()), items_collection_id, basic_resource, Some(resource_id), nft_ids)
+	verify {
+		assert_last_event::<T>(Event::ResourceAdded { nft_id, resource_id, collection_id: items_collection_id }.into());
+	}
+
+	cleanup_burned_nft_entry {
+		let (alice, _, collection_id, nft_id, resource_id) = prepare_resource::<T>();
+		let composable_resource = ComposableResource {
+			parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
+			base: 0,                               // BaseID
+			metadata: Some(stbd::<T> ("basic test metadata")),
+			slot: Some((0, 1)),
+		};
+		let _ = RmrkCore::<T>::add_composable_resource(RawOrigin::Signed(alice.clone()).into(), collection_id, nft_id, composable_resource, Some(resource_id), None);
+	}: {
+		RmrkCore::<T>::cleanup_burned_nft_entry(collection_id, nft_id);
+	}
+	verify {
+		assert!(RmrkCore::<T>::resources((collection_id, nft_id, resource_id)).is_none());
+	}
+
+	add_media_resource{
+		let (alice, _, collection_id, _, resource_id) = prepare_resource::<T>();
+
+		let n in 1 .. T::NestingBudget::get();
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
+		let nft_id = T::Helper::item(n-1);
+
+		let media_resource = MediaResource {
+			metadata: stbd::<T> ("media test metadata"),
+			media_type: Some(stbd::<T> ("image/svg+xml")),
+			hash: Some(stbd::<T> ("0x1fbf3ba58bc44a5e")),
+			thumb: Some(stbd::<T> ("ipfs://thumb")),
+			license: Some(stbd::<T> ("https://creativecommons.org/licenses/by/4.0/")),
+			src: Some(stbd::<T> ("ipfs://src")),
+		};
+
+	}: _(RawOrigin::Signed(alice.clone()), collection_id, nft_id, media_resource, Some(resource_id), None)
+	verify {
+		assert_last_event::<T>(Event::ResourceAdded { nft_id, resource_id, collection_id }.into());
+	}
+
+	set_priority_for_context{
+		let n in 1 .. T::MaxPriorities::get();
+		let k in 1 .. T::NestingBudget::get();
+		let c in 1 .. T::MaxPriorityContexts::get();
+		let (alice, bob, collection_id, _, _) = prepare_resource::<T>();
+
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, k);
+		let nft_id = T::Helper::item(k-1);
+
+		let context = stbk::<T>("thumbnail");
+		let priorities = add_accepted_resources::<T>(alice, bob.clone(), collection_id, nft_id, n);
+		// The other contexts of the NFT are counted against the limit
+		for other_context in 1..c {
+			let _ = RmrkCore::<T>::set_priority_for_context(
+				RawOrigin::Signed(bob.clone()).into(),
+				collection_id,
+				nft_id,
+				stbk::<T>(other_context.to_string().as_str()),
+				priorities.clone(),
+			);
+		}
+
+	}: _(RawOrigin::Signed(bob), collection_id, nft_id, context.clone(), priorities)
+	verify {
+		assert_last_event::<T>(Event::ContextPrioritySet { collection_id, nft_id, context }.into());
+	}
+
+	move_priority{
+		let n in 1 .. T::MaxPriorities::get();
+		let k in 1 .. T::NestingBudget::get();
+		let (alice, bob, collection_id, _, _) = prepare_resource::<T>();
+
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, k);
+		let nft_id = T::Helper::item(k-1);
+
+		let priorities = add_accepted_resources::<T>(alice, bob.clone(), collection_id, nft_id, n);
+		let _ = RmrkCore::<T>::set_priority(RawOrigin::Signed(bob.clone()).into(), collection_id, nft_id, priorities);
+		// Moving the last resource first shifts all of the others
+	}: _(RawOrigin::Signed(bob), collection_id, nft_id, n - 1, 0)
+	verify {
+		assert_last_event::<T>(Event::PrioritySet { collection_id, nft_id }.into());
+	}
+
+	swap_priorities{
+		let k in 1 .. T::NestingBudget::get();
+		let (alice, bob, collection_id, _, _) = prepare_resource::<T>();
+
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, k);
+		let nft_id = T::Helper::item(k-1);
+
+		let priorities = add_accepted_resources::<T>(alice, bob.clone(), collection_id, nft_id, 2);
+		let _ = RmrkCore::<T>::set_priority(RawOrigin::Signed(bob.clone()).into(), collection_id, nft_id, priorities);
+	}: _(RawOrigin::Signed(bob), collection_id, nft_id, 0, 1)
+	verify {
+		assert_last_event::<T>(Event::PrioritySet { collection_id, nft_id }.into());
+	}
+
+	propose_resource{
+		let (alice, bob, collection_id, _, resource_id) = prepare_resource::<T>();
+
+		let n in 1 .. T::NestingBudget::get();
+		mint_and_send_to_parent::<T>(alice, collection_id, n);
+		let nft_id = T::Helper::item(n-1);
+
+		let basic_resource = ResourceTypes::Basic(BasicResource { metadata: stbd::<T> ("community variant") });
+		// The NFT already has as many proposed resources as it can but one
+		for other_resource_id in 1..T::MaxProposalsPerNft::get() {
+			let _ = RmrkCore::<T>::propose_resource(RawOrigin::Signed(bob.clone()).into(), collection_id, nft_id, basic_resource.clone(), Some(other_resource_id));
+		}
+
+	}: _(RawOrigin::Signed(bob.clone()), collection_id, nft_id, basic_resource, Some(resource_id))
+	verify {
+		assert_last_event::<T>(Event::ResourceProposed { proposer: bob, nft_id, resource_id, collection_id }.into());
+	}
+
+	approve_resource_proposal{
+		let (alice, bob, collection_id, nft_id, resource_id) = prepare_resource::<T>();
+		let basic_resource = ResourceTypes::Basic(BasicResource { metadata: stbd::<T> ("community variant") });
+		let _ = RmrkCore::<T>::propose_resource(RawOrigin::Signed(bob).into(), collection_id, nft_id, basic_resource, Some(resource_id));
+	}: _(RawOrigin::Signed(alice), collection_id, nft_id, resource_id)
+	verify {
+		assert_last_event::<T>(Event::ResourceAccepted { nft_id, resource_id, collection_id }.into());
+	}
+
+	reject_resource_proposal{
+		let (alice, bob, collection_id, nft_id, resource_id) = prepare_resource::<T>();
+		let basic_resource = ResourceTypes::Basic(BasicResource { metadata: stbd::<T> ("community variant") });
+		let _ = RmrkCore::<T>::propose_resource(RawOrigin::Signed(bob).into(), collection_id, nft_id, basic_resource, Some(resource_id));
+	}: _(RawOrigin::Signed(alice), collection_id, nft_id, resource_id)
+	verify {
+		assert_last_event::<T>(Event::ResourceProposalRejected { nft_id, resource_id, collection_id }.into());
+	}
+
+	accept_resource_replacement{
+		let (alice, bob, collection_id, _, resource_id) = prepare_resource::<T>();
+
+		let n in 1 .. T::NestingBudget::get();
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
+		let nft_id = T::Helper::item(n-1);
+
+		let _ = add_accepted_resources::<T>(alice.clone(), bob.clone(), collection_id, nft_id, 1);
+		let resource = ResourceTypes::Basic(BasicResource { metadata: stbd::<T> ("replaced basic test metadata") });
+		let _ = RmrkCore::<T>::replace_resource(RawOrigin::Signed(alice).into(), collection_id, nft_id, resource, resource_id);
+	}: _(RawOrigin::Signed(bob), collection_id, nft_id, resource_id)
+	verify {
+		assert_last_event::<T>(Event::ResourceReplacementAccepted { nft_id, resource_id, collection_id }.into());
+	}
+
+	reject_resource_replacement{
+		let (alice, bob, collection_id, _, resource_id) = prepare_resource::<T>();
+
+		let n in 1 .. T::NestingBudget::get();
+		mint_and_send_to_parent::<T>(alice.clone(), collection_id, n);
+		let nft_id = T::Helper::item(n-1);
+
+		let _ = add_accepted_resources::<T>(alice.clone(), bob.clone(), collection_id, nft_id, 1);
+		let resource = ResourceTypes::Basic(BasicResource { metadata: stbd::<T> ("replaced basic test metadata") });
+		let _ = RmrkCore::<T>::replace_resource(RawOrigin::Signed(alice).into(), collection_id, nft_id, resource, resource_id);
+	}: _(RawOrigin::Signed(bob), collection_id, nft_id, resource_id)
+	verify {
+		assert_last_event::<T>(Event::ResourceReplacementRejected { nft_id, resource_id, collection_id }.into());
+	}
+
 	// This line generates test cases for benchmarking, and could be run by:
 	//   `cargo test --package pallet-rmrk-core --features runtime-benchmarks`
 	impl_benchmark_test_suite!(RmrkCore, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
@@ -9,16 +9,19 @@ use codec::{Codec, Decode, Encode};
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
 	pallet_prelude::*,
-	traits::{tokens::Locker, Get},
+	storage::with_storage_layer,
+	traits::{tokens::Locker, Contains, Get, ReservableCurrency},
 };
 
 use sp_runtime::{
-	traits::{One, Saturating, TrailingZeroInput},
+	traits::{Hash, One, Saturating, TrailingZeroInput, Zero},
 	ArithmeticError,
 };
 
-use rmrk_traits::{budget::Budget, misc::TransferHooks};
-use sp_std::collections::btree_set::BTreeSet;
+#[allow(deprecated)]
+use rmrk_traits::misc::TransferHooks;
+use rmrk_traits::{budget::Budget, misc::NftHooks};
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 // Randomness to generate NFT virtual accounts
 pub const SALT_RMRK_NFT: &[u8; 8] = b"RmrkNft/";
@@ -33,15 +36,20 @@ impl<T: Config>
 	> for Pallet<T>
 {
 	fn priority_set(
-		_sender: T::AccountId,
+		sender: T::AccountId,
 		collection_id: T::CollectionId,
 		nft_id: T::ItemId,
 		priorities: BoundedVec<ResourceId, T::MaxPriorities>,
 	) -> DispatchResultWithPostInfo {
-		let _multi_removal_results =
-			Priorities::<T>::clear_prefix((collection_id, nft_id), T::MaxPriorities::get(), None);
+		Self::ensure_valid_priorities(collection_id, nft_id, &priorities)?;
+		Self::clear_priorities(collection_id, nft_id);
 		let mut priority_index = 0u32;
 		for resource_id in priorities {
+			Self::reserve_storage_deposit(
+				&sender,
+				&Priorities::<T>::hashed_key_for((collection_id, nft_id, resource_id)),
+				priority_index.encoded_size(),
+			)?;
 			Priorities::<T>::insert((collection_id, nft_id, resource_id), priority_index);
 			priority_index += 1;
 		}
@@ -73,11 +81,18 @@ impl<T: Config> Property<KeyLimitOf<T>, ValueLimitOf<T>, T::AccountId, T::Collec
 				!Pallet::<T>::is_locked(collection_id, *nft_id),
 				pallet_uniques::Error::<T>::Locked
 			);
+			Pallet::<T>::ensure_not_expired(collection_id, *nft_id)?;
 			let budget = budget::Value::new(T::NestingBudget::get());
 			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, *nft_id, &budget)?;
 			ensure!(root_owner == collection.issuer, Error::<T>::NoPermission);
 		}
+		Self::reserve_storage_deposit(
+			&sender,
+			&Properties::<T>::hashed_key_for((&collection_id, maybe_nft_id, &key)),
+			value.encoded_size(),
+		)?;
 		Properties::<T>::insert((&collection_id, maybe_nft_id, &key), &value);
+		T::NftHooks::post_property_set(&collection_id, &maybe_nft_id, &key, &value)?;
 		Ok(())
 	}
 
@@ -91,6 +106,7 @@ impl<T: Config> Property<KeyLimitOf<T>, ValueLimitOf<T>, T::AccountId, T::Collec
 		// Ensure collection exists
 		Collections::<T>::get(&collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 		Properties::<T>::insert((&collection_id, maybe_nft_id, &key), &value);
+		T::NftHooks::post_property_set(&collection_id, &maybe_nft_id, &key, &value)?;
 
 		Self::deposit_event(Event::PropertySet { collection_id, maybe_nft_id, key, value });
 		Ok(())
@@ -102,6 +118,11 @@ impl<T: Config> Property<KeyLimitOf<T>, ValueLimitOf<T>, T::AccountId, T::Collec
 		maybe_nft_id: Option<T::ItemId>,
 		key: KeyLimitOf<T>,
 	) -> sp_runtime::DispatchResult {
+		Self::release_storage_deposit(&Properties::<T>::hashed_key_for((
+			&collection_id,
+			maybe_nft_id,
+			&key,
+		)));
 		Properties::<T>::remove((&collection_id, maybe_nft_id, &key));
 
 		Self::deposit_event(Event::PropertyRemoved { collection_id, maybe_nft_id, key });
@@ -115,7 +136,7 @@ impl<T: Config> Property<KeyLimitOf<T>, ValueLimitOf<T>, T::AccountId, T::Collec
 		maybe_nft_id: Option<T::ItemId>,
 		limit: u32,
 	) -> sp_runtime::DispatchResult {
-		let _ = Properties::<T>::clear_prefix((&collection_id, maybe_nft_id), limit, None);
+		Self::clear_properties(collection_id, maybe_nft_id, limit);
 
 		Self::deposit_event(Event::PropertiesRemoved { collection_id, maybe_nft_id });
 		Ok(())
@@ -132,7 +153,7 @@ impl<T: Config>
 	> for Pallet<T>
 {
 	fn resource_add(
-		_sender: T::AccountId,
+		sender: T::AccountId,
 		collection_id: T::CollectionId,
 		nft_id: T::ItemId,
 		resource: ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
@@ -143,25 +164,16 @@ impl<T: Config>
 			Resources::<T>::get((collection_id, nft_id, resource_id)).is_none(),
 			Error::<T>::ResourceAlreadyExists
 		);
-
-		match resource.clone() {
-			ResourceTypes::Basic(_r) => (),
-			ResourceTypes::Composable(r) => {
-				EquippableBases::<T>::insert((collection_id, nft_id, r.base), ());
-				if let Some((base, slot)) = r.slot {
-					EquippableSlots::<T>::insert(
-						(collection_id, nft_id, resource_id, base, slot),
-						(),
-					);
-				}
-			},
-			ResourceTypes::Slot(r) => {
-				EquippableSlots::<T>::insert(
-					(collection_id, nft_id, resource_id, r.base, r.slot),
-					(),
-				);
-			},
+		let resources_count = ResourcesCount::<T>::get(collection_id, nft_id);
+		ensure!(resources_count < T::MaxResourcesPerNft::get(), Error::<T>::TooManyResources);
+		// Only the auto-assigned ID moves the counter, so that a high ID chosen by the caller
+		// can't use up the IDs left to assign. The other taken IDs are skipped when assigning.
+		if Self::get_next_resource_id(collection_id, nft_id).ok() == Some(resource_id) {
+			NextResourceId::<T>::insert(collection_id, nft_id, resource_id.saturating_add(1));
 		}
+		ResourcesCount::<T>::insert(collection_id, nft_id, resources_count + 1);
+
+		Self::insert_equippable_entries(collection_id, nft_id, resource_id, &resource);
 
 		let res: ResourceInfo<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>> =
 			ResourceInfo::<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>> {
@@ -170,7 +182,13 @@ impl<T: Config>
 				pending_removal: false,
 				resource,
 			};
+		Self::reserve_storage_deposit(
+			&sender,
+			&Resources::<T>::hashed_key_for((collection_id, nft_id, resource_id)),
+			res.encoded_size(),
+		)?;
 		Resources::<T>::insert((collection_id, nft_id, resource_id), res);
+		T::NftHooks::post_resource_add(&collection_id, &nft_id, &resource_id, pending)?;
 
 		Self::deposit_event(Event::ResourceAdded { nft_id, resource_id, collection_id });
 
@@ -197,6 +215,7 @@ impl<T: Config>
 				Ok(())
 			},
 		)?;
+		PendingResourceExpiry::<T>::remove((collection_id, nft_id, resource_id));
 
 		Self::deposit_event(Event::ResourceAccepted { nft_id, resource_id, collection_id });
 
@@ -233,9 +252,11 @@ impl<T: Config>
 						collection_id,
 						nft_id,
 						resource_id,
-					);
+					)?;
 				},
 			}
+			PendingResourceExpiry::<T>::remove((collection_id, nft_id, resource_id));
+			T::NftHooks::post_resource_remove(&collection_id, &nft_id, &resource_id)?;
 		}
 
 		Self::deposit_event(Event::ResourceRemoval { nft_id, resource_id, collection_id });
@@ -244,7 +265,7 @@ impl<T: Config>
 	}
 
 	fn resource_replace(
-		_sender: T::AccountId,
+		sender: T::AccountId,
 		collection_id: T::CollectionId,
 		nft_id: T::ItemId,
 		resource: ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
@@ -259,7 +280,20 @@ impl<T: Config>
 			(collection_id, nft_id, resource_id),
 			|current_resource| -> DispatchResult {
 				if let Some(res) = current_resource.into_mut() {
+					Self::remove_equippable_entries(
+						collection_id,
+						nft_id,
+						resource_id,
+						&res.resource,
+					);
+					Self::insert_equippable_entries(collection_id, nft_id, resource_id, &resource);
 					res.resource = resource;
+					// The deposit follows the size of the replacing resource
+					Self::reserve_storage_deposit(
+						&sender,
+						&Resources::<T>::hashed_key_for((collection_id, nft_id, resource_id)),
+						res.encoded_size(),
+					)?;
 				}
 				Ok(())
 			},
@@ -289,9 +323,11 @@ impl<T: Config>
 					collection_id,
 					nft_id,
 					resource_id,
-				);
+				)?;
 			},
 		}
+		PendingResourceExpiry::<T>::remove((collection_id, nft_id, resource_id));
+		T::NftHooks::post_resource_remove(&collection_id, &nft_id, &resource_id)?;
 
 		Self::deposit_event(Event::ResourceRemovalAccepted { nft_id, resource_id, collection_id });
 
@@ -312,9 +348,18 @@ impl<T: Config>
 		metadata: StringLimitOf<T>,
 		max: Option<u32>,
 		symbol: BoundedCollectionSymbolOf<T>,
+		policy: CollectionPolicy,
 	) -> Result<(), DispatchError> {
-		let collection =
-			CollectionInfo { issuer: issuer.clone(), metadata, max, symbol, nfts_count: 0 };
+		let collection = CollectionInfo {
+			issuer: issuer.clone(),
+			metadata,
+			max,
+			symbol,
+			nfts_count: 0,
+			locked: false,
+			ever_minted: false,
+			policy,
+		};
 
 		// Call the pallet_uniques function to create collection
 		pallet_uniques::Pallet::<T>::do_create_collection(
@@ -341,8 +386,23 @@ impl<T: Config>
 		let witness = pallet_uniques::Pallet::<T>::get_destroy_witness(&collection_id)
 			.ok_or(Error::<T>::NoWitness)?;
 		ensure!(witness.items == 0u32, Error::<T>::CollectionNotEmpty);
+		ensure!(
+			CollectionResources::<T>::iter_key_prefix(collection_id).next().is_none(),
+			Error::<T>::CollectionHasResources
+		);
+		// Remove all of the properties of the collection itself, collections with more
+		// properties are cleared beforehand through `destroy_collection_items`
+		Self::clear_properties(collection_id, None, T::PropertiesLimit::get());
+		ensure!(
+			Properties::<T>::iter_key_prefix((collection_id, None::<T::ItemId>))
+				.next()
+				.is_none(),
+			Error::<T>::CollectionHasProperties
+		);
 		// Remove from RMRK storage
 		Collections::<T>::remove(collection_id);
+		PendingIssuer::<T>::remove(collection_id);
+		DestroyingCollections::<T>::remove(collection_id);
 
 		pallet_uniques::Pallet::<T>::do_destroy_collection(
 			collection_id,
@@ -376,7 +436,7 @@ impl<T: Config>
 	) -> Result<T::CollectionId, DispatchError> {
 		Collections::<T>::try_mutate_exists(collection_id, |collection| -> DispatchResult {
 			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
-			collection.max = Some(0);
+			collection.locked = true;
 			Ok(())
 		})?;
 
@@ -384,6 +444,121 @@ impl<T: Config>
 
 		Ok(collection_id)
 	}
+
+	fn collection_unlock(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+	) -> Result<T::CollectionId, DispatchError> {
+		Collections::<T>::try_mutate_exists(collection_id, |collection| -> DispatchResult {
+			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
+			// A collection can only be unlocked before any NFT is minted in it, even if all of
+			// its NFTs were burned since
+			ensure!(!collection.ever_minted, Error::<T>::CollectionAlreadyMinted);
+			collection.locked = false;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::CollectionUnlocked { issuer: sender, collection_id });
+
+		Ok(collection_id)
+	}
+
+	fn collection_set_max(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		max: Option<u32>,
+	) -> Result<T::CollectionId, DispatchError> {
+		Collections::<T>::try_mutate_exists(collection_id, |collection| -> DispatchResult {
+			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
+			// Max can't be lowered below the number of existing NFTs
+			if let Some(max) = max {
+				ensure!(max >= collection.nfts_count, Error::<T>::MaxLowerThanNftsCount);
+			}
+			// Max can only be raised while the collection is not locked
+			let raising = match (collection.max, max) {
+				(Some(current), Some(new)) => new > current,
+				(Some(_), None) => true,
+				(None, _) => false,
+			};
+			ensure!(!(raising && collection.locked), Error::<T>::CollectionFullOrLocked);
+			collection.max = max;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::CollectionMaxSet { issuer: sender, collection_id, max });
+
+		Ok(collection_id)
+	}
+
+	fn collection_start_destroy(
+		issuer: T::AccountId,
+		collection_id: T::CollectionId,
+	) -> DispatchResult {
+		ensure!(Collections::<T>::contains_key(collection_id), Error::<T>::CollectionUnknown);
+		ensure!(
+			!DestroyingCollections::<T>::contains_key(collection_id),
+			Error::<T>::CollectionIsDestroying
+		);
+		DestroyingCollections::<T>::insert(collection_id, ());
+
+		Self::deposit_event(Event::CollectionDestroyStarted { issuer, collection_id });
+		Ok(())
+	}
+
+	fn collection_destroy_items(
+		collection_id: T::CollectionId,
+		limit: u32,
+	) -> Result<(u32, u32), DispatchError> {
+		ensure!(
+			DestroyingCollections::<T>::contains_key(collection_id),
+			Error::<T>::CollectionNotDestroying
+		);
+
+		// Collect the keys first, burning an NFT also burns its children which may belong to
+		// this collection and would otherwise invalidate the iterator.
+		let nft_ids: Vec<T::ItemId> = Nfts::<T>::iter_key_prefix(collection_id)
+			.take(limit.min(T::MaxDestroyItems::get()) as usize)
+			.collect();
+		let mut destroyed = 0u32;
+		for nft_id in nft_ids {
+			// Skip the NFTs already burned as a child of a previous NFT
+			if !Self::nft_exists((collection_id, nft_id)) {
+				continue
+			}
+			let (root_owner, _) = Self::lookup_root_owner(
+				collection_id,
+				nft_id,
+				&budget::Value::new(T::NestingBudget::get()),
+			)?;
+			Self::release_foreign_children(
+				collection_id,
+				nft_id,
+				&root_owner,
+				&budget::Value::new(T::NestingBudget::get()),
+			)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			Self::nft_burn(root_owner, collection_id, nft_id, &budget)
+				.map_err(|error| error.error)?;
+			destroyed.saturating_inc();
+		}
+
+		let remaining = Self::collections(collection_id)
+			.ok_or(Error::<T>::CollectionUnknown)?
+			.nfts_count;
+		// Once the collection is empty, its own properties are removed over as many calls as
+		// needed
+		let cleared_properties = if remaining == 0 {
+			Self::clear_properties(collection_id, None, T::PropertiesLimit::get())
+		} else {
+			0
+		};
+		Self::deposit_event(Event::CollectionItemsDestroyed {
+			collection_id,
+			destroyed,
+			remaining,
+		});
+		Ok((destroyed, cleared_properties))
+	}
 }
 
 impl<T: Config>
@@ -402,9 +577,16 @@ impl<T: Config>
 		resources: Option<BoundedResourceInfoTypeOf<T>>,
 	) -> sp_std::result::Result<(T::CollectionId, T::ItemId), DispatchError> {
 		ensure!(!Self::nft_exists((collection_id, nft_id)), Error::<T>::NftAlreadyExists);
+		ensure!(
+			!PendingCleanup::<T>::contains_key(collection_id, nft_id),
+			Error::<T>::NftPendingCleanup
+		);
 		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		Self::ensure_not_destroying(collection_id)?;
 
-		// Prevent minting when nfts_count is greater than the collection max.
+		// Prevent minting in a locked collection or when nfts_count is greater than the
+		// collection max.
+		ensure!(!collection.locked, Error::<T>::CollectionFullOrLocked);
 		if let Some(max) = collection.max {
 			ensure!(collection.nfts_count < max, Error::<T>::CollectionFullOrLocked);
 		}
@@ -436,12 +618,24 @@ impl<T: Config>
 		};
 
 		Nfts::<T>::insert(collection_id, nft_id, nft);
+		if pending {
+			// A rejected NFT goes back to the minter
+			PendingTransfers::<T>::insert(
+				(collection_id, nft_id),
+				PendingTransfer {
+					sender: sender.clone(),
+					previous_owner: AccountIdOrCollectionNftTuple::AccountId(sender.clone()),
+					expires: None,
+				},
+			);
+		}
 
 		// increment nfts counter
 		let nfts_count = collection.nfts_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
 		Collections::<T>::try_mutate(collection_id, |collection| -> DispatchResult {
 			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
 			collection.nfts_count = nfts_count;
+			collection.ever_minted = true;
 			Ok(())
 		})?;
 
@@ -464,11 +658,10 @@ impl<T: Config>
 			}
 		}
 
-		Self::deposit_event(Event::NftMinted {
-			owner: AccountIdOrCollectionNftTuple::AccountId(owner),
-			collection_id,
-			nft_id,
-		});
+		let owner = AccountIdOrCollectionNftTuple::AccountId(owner);
+		T::NftHooks::post_mint(&sender, &owner, &collection_id, &nft_id)?;
+
+		Self::deposit_event(Event::NftMinted { owner, collection_id, nft_id });
 
 		Ok((collection_id, nft_id))
 	}
@@ -485,13 +678,23 @@ impl<T: Config>
 		resources: Option<BoundedResourceInfoTypeOf<T>>,
 	) -> sp_std::result::Result<(T::CollectionId, T::ItemId), DispatchError> {
 		ensure!(!Self::nft_exists((collection_id, nft_id)), Error::<T>::NftAlreadyExists);
+		ensure!(
+			!PendingCleanup::<T>::contains_key(collection_id, nft_id),
+			Error::<T>::NftPendingCleanup
+		);
 		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		Self::ensure_not_destroying(collection_id)?;
 
-		// Prevent minting when nfts_count is greater than the collection max.
+		// Prevent minting in a locked collection or when nfts_count is greater than the
+		// collection max.
+		ensure!(!collection.locked, Error::<T>::CollectionFullOrLocked);
 		if let Some(max) = collection.max {
 			ensure!(collection.nfts_count < max, Error::<T>::CollectionFullOrLocked);
 		}
 
+		// Minting into an NFT of a collection being destroyed is not allowed
+		Self::ensure_not_destroying(owner.0)?;
+
 		// Calculate the rootowner of the intended owner of the minted NFT
 		let budget = budget::Value::new(T::NestingBudget::get().saturating_sub(One::one()));
 		let (rootowner, _) = Self::lookup_root_owner(owner.0, owner.1, &budget)?;
@@ -522,6 +725,17 @@ impl<T: Config>
 		};
 
 		Nfts::<T>::insert(collection_id, nft_id, nft);
+		if pending {
+			// A rejected NFT goes back to the minter
+			PendingTransfers::<T>::insert(
+				(collection_id, nft_id),
+				PendingTransfer {
+					sender: sender.clone(),
+					previous_owner: AccountIdOrCollectionNftTuple::AccountId(sender.clone()),
+					expires: None,
+				},
+			);
+		}
 
 		// For Uniques, we need to decode the "virtual account" ID to be the owner
 		let uniques_owner = Self::nft_to_account_id(owner.0, owner.1);
@@ -531,10 +745,12 @@ impl<T: Config>
 		Collections::<T>::try_mutate(collection_id, |collection| -> DispatchResult {
 			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
 			collection.nfts_count = nfts_count;
+			collection.ever_minted = true;
 			Ok(())
 		})?;
 
-		Pallet::<T>::add_child((owner.0, owner.1), (collection_id, nft_id));
+		Pallet::<T>::add_child((owner.0, owner.1), (collection_id, nft_id), &sender)?;
+		Self::update_root_owner((collection_id, nft_id), Some(owner), &budget)?;
 
 		pallet_uniques::Pallet::<T>::do_mint(collection_id, nft_id, uniques_owner, |_details| {
 			Ok(())
@@ -554,11 +770,10 @@ impl<T: Config>
 			}
 		}
 
-		Self::deposit_event(Event::NftMinted {
-			owner: AccountIdOrCollectionNftTuple::CollectionAndNftTuple(owner.0, owner.1),
-			collection_id,
-			nft_id,
-		});
+		let owner = AccountIdOrCollectionNftTuple::CollectionAndNftTuple(owner.0, owner.1);
+		T::NftHooks::post_mint(&sender, &owner, &collection_id, &nft_id)?;
+
+		Self::deposit_event(Event::NftMinted { owner, collection_id, nft_id });
 
 		Ok((collection_id, nft_id))
 	}
@@ -574,26 +789,55 @@ impl<T: Config>
 			if let AccountIdOrCollectionNftTuple::CollectionAndNftTuple(parent_col, parent_nft) =
 				nft.owner
 			{
-				Children::<T>::remove((parent_col, parent_nft), (collection_id, nft_id));
+				Self::remove_child((parent_col, parent_nft), (collection_id, nft_id));
 			}
 		}
 
+		// Remove the resources while the NFT exists, for the hooks to see it, e.g. to unequip it
+		let resource_ids = Resources::<T>::iter_key_prefix((collection_id, nft_id))
+			.take(T::MaxResourcesPerNft::get() as usize)
+			.collect::<Vec<_>>();
+		let resources = resource_ids.len() as u32;
+		for resource_id in resource_ids {
+			Self::remove_burned_resource(collection_id, nft_id, resource_id)?;
+		}
+
 		Nfts::<T>::remove(collection_id, nft_id);
+		RootOwner::<T>::remove((collection_id, nft_id));
 
 		// Remove all of the properties of the NFT
 		Self::do_remove_properties(collection_id, Some(nft_id), T::PropertiesLimit::get())?;
-		// Remove the lock from the NFT if it was locked
-		Lock::<T>::remove((&collection_id, nft_id));
+		// Remove the locks from the NFT if it was locked
+		Locks::<T>::remove((&collection_id, nft_id));
+		// Remove the transfer approval and the pending state of the NFT
+		Approvals::<T>::remove((collection_id, nft_id));
+		PendingTransfers::<T>::remove((collection_id, nft_id));
+		// Remove the expiry of the NFT
+		if let Some(expires_at) = NftExpiry::<T>::take(collection_id, nft_id) {
+			ExpiryQueue::<T>::remove(expires_at, (collection_id, nft_id));
+		}
 
-		let _multi_removal_results = Resources::<T>::clear_prefix(
+		Self::clear_priorities(collection_id, nft_id);
+		Self::clear_context_priorities(collection_id, nft_id, T::MaxPriorityContexts::get());
+		ResourcesCount::<T>::remove(collection_id, nft_id);
+		NextResourceId::<T>::remove(collection_id, nft_id);
+		let _multi_removal_results = PendingResourceExpiry::<T>::clear_prefix(
 			(collection_id, nft_id),
-			T::MaxResourcesOnMint::get(),
+			T::MaxResourcesPerNft::get(),
 			None,
 		);
+		// Whatever is left past the limits is removed later on
+		if Self::has_leftover_storage(collection_id, nft_id) {
+			Self::queue_cleanup(collection_id, nft_id);
+		}
 
 		for ((child_collection_id, child_nft_id), _) in
 			Children::<T>::drain_prefix((collection_id, nft_id))
 		{
+			Self::release_storage_deposit(&Children::<T>::hashed_key_for(
+				(collection_id, nft_id),
+				(child_collection_id, child_nft_id),
+			));
 			ensure!(budget.consume() != false, Error::<T>::TooManyRecursions);
 			Self::nft_burn(owner.clone(), child_collection_id, child_nft_id, budget)?;
 		}
@@ -607,12 +851,14 @@ impl<T: Config>
 
 		// Call pallet uniques to ensure NFT is burned
 		pallet_uniques::Pallet::<T>::do_burn(collection_id, nft_id, |_, _| Ok(()))?;
+		T::NftHooks::post_burn(&owner, &collection_id, &nft_id)?;
 
 		Self::deposit_event(Event::NFTBurned { owner, nft_id, collection_id });
 
 		Ok(Some(<T as pallet::Config>::WeightInfo::burn_nft(
 			budget.get_budget_consumed_value(),
 			T::PropertiesLimit::get(),
+			resources,
 		))
 		.into())
 	}
@@ -623,118 +869,8 @@ impl<T: Config>
 		nft_id: T::ItemId,
 		new_owner: AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
 	) -> Result<(T::AccountId, bool), DispatchError> {
-		// Get current owner for child removal later
-		let parent = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id);
-		// Check if parent returns None which indicates the NFT is not available
-		ensure!(parent.is_some(), Error::<T>::NoAvailableNftId); // <- is this error wrong?
-
-		let budget = budget::Value::new(T::NestingBudget::get());
-		let (root_owner, _root_nft) =
-			Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
-		// Check ownership
-		ensure!(sender == root_owner, Error::<T>::NoPermission);
-		// Get NFT info
-		let mut sending_nft =
-			Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
-
-		// Defaults to true, but can be implemented downstream for custom logic
-		ensure!(
-			T::TransferHooks::pre_check(&sender, &collection_id, &nft_id),
-			Error::<T>::FailedTransferHooksPreCheck
-		);
-
-		// Check NFT is transferable
-		Self::check_is_transferable(&sending_nft)?;
-
-		// NFT cannot be sent if it is equipped
-		Self::check_is_not_equipped(&sending_nft)?;
-
-		// Needs to be pending if the sending to an account or to a non-owned NFT
-		let mut approval_required = true;
-
-		// Prepare transfer
-		let new_owner_account = match new_owner.clone() {
-			AccountIdOrCollectionNftTuple::AccountId(id) => {
-				approval_required = false;
-				id
-			},
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) => {
-				// Check if NFT target exists
-				ensure!(Nfts::<T>::contains_key(cid, nid), Error::<T>::NoAvailableNftId);
-				// Check if sending to self
-				ensure!(
-					(collection_id, nft_id) != (cid, nid),
-					Error::<T>::CannotSendToDescendentOrSelf
-				);
-				// Check if collection_id & nft_id are descendent of cid & nid
-				ensure!(
-					!Pallet::<T>::is_x_descendent_of_y(cid, nid, collection_id, nft_id),
-					Error::<T>::CannotSendToDescendentOrSelf
-				);
-				let budget = budget::Value::new(T::NestingBudget::get().saturating_sub(One::one()));
-				let (recipient_root_owner, _root_nft) =
-					Pallet::<T>::lookup_root_owner(cid, nid, &budget)?;
-				if recipient_root_owner == root_owner {
-					approval_required = false;
-				}
-
-				// Convert to virtual account
-				Pallet::<T>::nft_to_account_id::<T::AccountId>(cid, nid)
-			},
-		};
-
-		sending_nft.owner = new_owner.clone();
-
-		if approval_required {
-			Nfts::<T>::try_mutate_exists(collection_id, nft_id, |nft| -> DispatchResult {
-				if let Some(nft) = nft {
-					nft.pending = true;
-					nft.owner = new_owner.clone();
-				}
-				Ok(())
-			})?;
-		} else {
-			Nfts::<T>::insert(collection_id, nft_id, sending_nft);
-		}
-
-		if let Some(current_owner) = parent {
-			// Handle Children StorageMap for NFTs
-			let current_owner_cid_nid =
-				Pallet::<T>::decode_nft_account_id::<T::AccountId>(current_owner);
-			if let Some(current_owner_cid_nid) = current_owner_cid_nid {
-				// Remove child from parent
-				Pallet::<T>::remove_child(current_owner_cid_nid, (collection_id, nft_id));
-			}
-		}
-
-		// add child to new parent if NFT virtual address
-		let new_owner_cid_nid =
-			Pallet::<T>::decode_nft_account_id::<T::AccountId>(new_owner_account.clone());
-		if let Some(new_owner_cid_nid) = new_owner_cid_nid {
-			Pallet::<T>::add_child(new_owner_cid_nid, (collection_id, nft_id));
-		}
-
-		pallet_uniques::Pallet::<T>::do_transfer(
-			collection_id,
-			nft_id,
-			new_owner_account.clone(),
-			|_class_details, _details| Ok(()),
-		)?;
-
-		// Defaults to true, but can be implemented downstream for custom logic
-		ensure!(
-			T::TransferHooks::post_transfer(&sender, &new_owner_account, &collection_id, &nft_id),
-			Error::<T>::FailedTransferHooksPostTransfer
-		);
-
-		Self::deposit_event(Event::NFTSent {
-			sender,
-			recipient: new_owner,
-			collection_id,
-			nft_id,
-			approval_required,
-		});
-
+		let (new_owner_account, approval_required, _) =
+			Self::do_nft_send(sender, collection_id, nft_id, new_owner)?;
 		Ok((new_owner_account, approval_required))
 	}
 
@@ -759,6 +895,8 @@ impl<T: Config>
 			}
 			Ok(())
 		})?;
+		PendingTransfers::<T>::remove((collection_id, nft_id));
+		T::NftHooks::post_accept(&sender, &collection_id, &nft_id)?;
 
 		Self::deposit_event(Event::NFTAccepted {
 			sender,
@@ -774,6 +912,7 @@ impl<T: Config>
 		sender: T::AccountId,
 		collection_id: T::CollectionId,
 		nft_id: T::ItemId,
+		burn: bool,
 	) -> DispatchResultWithPostInfo {
 		// Look up root owner in Uniques to ensure permissions
 		let budget = budget::Value::new(T::NestingBudget::get());
@@ -791,6 +930,24 @@ impl<T: Config>
 		// Check ownership
 		ensure!(sender == root_owner, Error::<T>::CannotRejectNonOwnedNft);
 
+		if !burn {
+			// Send the NFT and its children back to where it came from
+			Self::nft_return_pending(collection_id, nft_id)?;
+			T::NftHooks::post_reject(&sender, &collection_id, &nft_id, false)?;
+
+			Self::deposit_event(Event::NFTRejected {
+				sender,
+				collection_id,
+				nft_id,
+				burned: false,
+			});
+
+			return Ok(Some(<T as pallet::Config>::WeightInfo::reject_nft(
+				budget.get_budget_consumed_value(),
+			))
+			.into())
+		}
+
 		// Get current owner, which we will use to remove the Children storage
 		if let Some(parent_account_id) = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id) {
 			// Decode the parent_account_id to extract the parent (T::CollectionId, T::ItemId)
@@ -806,38 +963,316 @@ impl<T: Config>
 		let _rejecting_nft =
 			Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
 
-		let result = Self::nft_burn(sender.clone(), collection_id, nft_id, &budget);
+		let post_info = Self::nft_burn(sender.clone(), collection_id, nft_id, &budget)?;
+		T::NftHooks::post_reject(&sender, &collection_id, &nft_id, true)?;
 
-		Self::deposit_event(Event::NFTRejected { sender: sender.clone(), collection_id, nft_id });
+		Self::deposit_event(Event::NFTRejected {
+			sender: sender.clone(),
+			collection_id,
+			nft_id,
+			burned: true,
+		});
 
-		result
+		Ok(post_info)
 	}
 }
 
 impl<T: Config> Locker<T::CollectionId, T::ItemId> for Pallet<T> {
 	fn is_locked(collection_id: T::CollectionId, nft_id: T::ItemId) -> bool {
-		Lock::<T>::get((collection_id, nft_id))
+		Locks::<T>::contains_key((collection_id, nft_id))
 	}
 }
 
-impl<T: Config> Pallet<T> {
-	pub fn iterate_nft_children(
-		collection_id: T::CollectionId,
-		nft_id: T::ItemId,
-	) -> impl Iterator<Item = NftChild<T::CollectionId, T::ItemId>> {
-		Children::<T>::iter_key_prefix((collection_id, nft_id)).into_iter().map(
-			|(collection_id, nft_id)| NftChild::<T::CollectionId, T::ItemId> {
-				collection_id,
-				nft_id,
-			},
-		)
+impl<T: Config> PausableCall<T::CollectionId> for Call<T> {
+	fn is_paused(&self, is_paused: impl Fn(CallGroup, Option<&T::CollectionId>) -> bool) -> bool {
+		match self {
+			Call::mint_nft { collection_id, .. } |
+			Call::mint_nft_directly_to_nft { collection_id, .. } |
+			Call::create_collection { collection_id, .. } =>
+				is_paused(CallGroup::Minting, Some(collection_id)),
+			Call::burn_nft { collection_id, .. } |
+			Call::revoke { collection_id, .. } |
+			Call::destroy_collection { collection_id } |
+			Call::start_destroy_collection { collection_id } |
+			Call::destroy_collection_items { collection_id, .. } =>
+				is_paused(CallGroup::Burning, Some(collection_id)),
+			Call::send { collection_id, .. } |
+			Call::accept_nft { collection_id, .. } |
+			Call::reject_nft { collection_id, .. } |
+			Call::approve_transfer { collection_id, .. } |
+			Call::cancel_approval { collection_id, .. } |
+			Call::set_transferable { collection_id, .. } |
+			Call::set_pending_expiry { collection_id, maybe_resource_id: None, .. } |
+			Call::expire_pending { collection_id, maybe_resource_id: None, .. } =>
+				is_paused(CallGroup::Transfers, Some(collection_id)),
+			Call::send_batch { items } =>
+				is_paused(CallGroup::Transfers, None) ||
+					items.iter().any(|(collection_id, ..)| {
+						is_paused(CallGroup::Transfers, Some(collection_id))
+					}),
+			Call::set_operator { .. } => is_paused(CallGroup::Transfers, None),
+			Call::add_basic_resource { collection_id, .. } |
+			Call::add_composable_resource { collection_id, .. } |
+			Call::add_slot_resource { collection_id, .. } |
+			Call::add_media_resource { collection_id, .. } |
+			Call::replace_resource { collection_id, .. } |
+			Call::accept_resource { collection_id, .. } |
+			Call::remove_resource { collection_id, .. } |
+			Call::accept_resource_removal { collection_id, .. } |
+			Call::add_collection_resource { collection_id, .. } |
+			Call::add_resource_to_many { collection_id, .. } |
+			Call::remove_collection_resource { collection_id, .. } |
+			Call::propose_resource { collection_id, .. } |
+			Call::approve_resource_proposal { collection_id, .. } |
+			Call::reject_resource_proposal { collection_id, .. } |
+			Call::accept_resource_replacement { collection_id, .. } |
+			Call::reject_resource_replacement { collection_id, .. } |
+			Call::set_pending_expiry { collection_id, maybe_resource_id: Some(_), .. } |
+			Call::expire_pending { collection_id, maybe_resource_id: Some(_), .. } |
+			Call::set_priority { collection_id, .. } |
+			Call::set_priority_for_context { collection_id, .. } |
+			Call::move_priority { collection_id, .. } |
+			Call::swap_priorities { collection_id, .. } =>
+				is_paused(CallGroup::Resources, Some(collection_id)),
+			Call::set_property { collection_id, .. } =>
+				is_paused(CallGroup::Properties, Some(collection_id)),
+			// The administration of collections and the calls of `ProtocolOrigin`, which are
+			// used to deal with an incident, stay available
+			Call::lock_collection { .. } |
+			Call::unlock_collection { .. } |
+			Call::set_collection_max { .. } |
+			Call::propose_issuer { .. } |
+			Call::accept_issuer { .. } |
+			Call::cancel_issuer_proposal { .. } |
+			Call::force_burn { .. } |
+			Call::force_transfer { .. } |
+			Call::force_unlock { .. } |
+			Call::force_collection_issuer { .. } |
+			Call::force_remove_resource { .. } |
+			Call::pause_calls { .. } |
+			Call::unpause_calls { .. } => false,
+			_ => false,
+		}
 	}
+}
 
-	pub fn iterate_resources(
-		collection_id: T::CollectionId,
-		nft_id: T::ItemId,
-	) -> impl Iterator<Item = ResourceInfoOf<T>> {
-		Resources::<T>::iter_prefix_values((collection_id, nft_id))
+impl<T: Config, C: PausableCall<T::CollectionId>> Contains<C> for CallsNotPaused<T> {
+	fn contains(call: &C) -> bool {
+		!call.is_paused(Pallet::<T>::is_paused)
+	}
+}
+
+#[allow(deprecated)]
+impl<T: Config, H: TransferHooks<T::AccountId, T::CollectionId, T::ItemId>>
+	NftHooks<T::AccountId, T::CollectionId, T::ItemId> for TransferHooksAdapter<T, H>
+{
+	fn pre_transfer(
+		sender: &T::AccountId,
+		collection_id: &T::CollectionId,
+		nft_id: &T::ItemId,
+	) -> DispatchResult {
+		ensure!(
+			H::pre_check(sender, collection_id, nft_id),
+			Error::<T>::FailedTransferHooksPreCheck
+		);
+		Ok(())
+	}
+
+	fn post_transfer(
+		sender: &T::AccountId,
+		recipient: &T::AccountId,
+		collection_id: &T::CollectionId,
+		nft_id: &T::ItemId,
+	) -> DispatchResult {
+		ensure!(
+			H::post_transfer(sender, recipient, collection_id, nft_id),
+			Error::<T>::FailedTransferHooksPostTransfer
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether a call group is paused globally, or for the given collection
+	pub fn is_paused(group: CallGroup, maybe_collection_id: Option<&T::CollectionId>) -> bool {
+		PausedCalls::<T>::contains_key(group, None::<T::CollectionId>) ||
+			maybe_collection_id.map_or(false, |collection_id| {
+				PausedCalls::<T>::contains_key(group, Some(*collection_id))
+			})
+	}
+
+	pub fn iterate_nft_children(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+	) -> impl Iterator<Item = NftChild<T::CollectionId, T::ItemId>> {
+		Children::<T>::iter_key_prefix((collection_id, nft_id)).into_iter().map(
+			|(collection_id, nft_id)| NftChild::<T::CollectionId, T::ItemId> {
+				collection_id,
+				nft_id,
+			},
+		)
+	}
+
+	/// Get the next free ID for a resource of an NFT, skipping the IDs of the resources shared
+	/// by its collection so that they aren't overridden by accident.
+	pub fn get_next_resource_id(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+	) -> Result<ResourceId, Error<T>> {
+		let mut resource_id = NextResourceId::<T>::get(collection_id, nft_id);
+		while CollectionResources::<T>::contains_key(collection_id, resource_id) ||
+			Resources::<T>::contains_key((collection_id, nft_id, resource_id))
+		{
+			resource_id = resource_id.checked_add(1).ok_or(Error::<T>::NoAvailableResourceId)?;
+		}
+		Ok(resource_id)
+	}
+
+	/// Iterate through the resources of an NFT, including the resources shared by its
+	/// collection that it doesn't override.
+	pub fn iterate_resources(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+	) -> impl Iterator<Item = ResourceInfoOf<T>> {
+		Resources::<T>::iter_prefix_values((collection_id, nft_id)).chain(
+			CollectionResources::<T>::iter_prefix_values(collection_id)
+				.filter(move |res| !Resources::<T>::contains_key((collection_id, nft_id, res.id))),
+		)
+	}
+
+	/// Get a resource of an NFT, falling back to the resource shared by its collection
+	/// if the NFT doesn't override it.
+	pub fn resolve_resource(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) -> Option<ResourceInfoOf<T>> {
+		Resources::<T>::get((collection_id, nft_id, resource_id))
+			.or_else(|| CollectionResources::<T>::get(collection_id, resource_id))
+	}
+
+	/// Whether an NFT has a composable resource for a base, either its own or one shared by
+	/// its collection.
+	pub fn has_equippable_base(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		base_id: BaseId,
+	) -> bool {
+		EquippableBases::<T>::contains_key((collection_id, nft_id, base_id)) ||
+			CollectionEquippableBases::<T>::iter_key_prefix((collection_id, base_id))
+				.take(T::MaxCollectionResources::get() as usize)
+				.any(|resource_id| {
+					!Resources::<T>::contains_key((collection_id, nft_id, resource_id))
+				})
+	}
+
+	/// Whether a resource of an NFT, either its own or one shared by its collection, can be
+	/// equipped into a slot of a base.
+	pub fn is_equippable_into_slot(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+		base_id: BaseId,
+		slot_id: SlotId,
+	) -> bool {
+		if Resources::<T>::contains_key((collection_id, nft_id, resource_id)) {
+			return EquippableSlots::<T>::contains_key((
+				collection_id,
+				nft_id,
+				resource_id,
+				base_id,
+				slot_id,
+			))
+		}
+		match CollectionResources::<T>::get(collection_id, resource_id).map(|res| res.resource) {
+			Some(ResourceTypes::Slot(r)) => r.base == base_id && r.slot == slot_id,
+			Some(ResourceTypes::Composable(r)) => r.slot == Some((base_id, slot_id)),
+			_ => false,
+		}
+	}
+
+	/// Add a resource shared by all of the NFTs of a collection
+	pub fn collection_resource_add(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		resource: ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		ensure!(
+			!CollectionResources::<T>::contains_key(collection_id, resource_id),
+			Error::<T>::ResourceAlreadyExists
+		);
+		ensure!(
+			(CollectionResources::<T>::iter_key_prefix(collection_id)
+				.take(T::MaxCollectionResources::get() as usize)
+				.count() as u32) < T::MaxCollectionResources::get(),
+			Error::<T>::TooManyCollectionResources
+		);
+
+		if let ResourceTypes::Composable(r) = &resource {
+			CollectionEquippableBases::<T>::insert((collection_id, r.base, resource_id), ());
+		}
+
+		let res = ResourceInfoOf::<T> {
+			id: resource_id,
+			pending: false,
+			pending_removal: false,
+			resource,
+		};
+		Self::reserve_storage_deposit(
+			&sender,
+			&CollectionResources::<T>::hashed_key_for(collection_id, resource_id),
+			res.encoded_size(),
+		)?;
+		CollectionResources::<T>::insert(collection_id, resource_id, res);
+
+		Self::deposit_event(Event::CollectionResourceAdded { collection_id, resource_id });
+		Ok(())
+	}
+
+	/// Remove a resource shared by all of the NFTs of a collection. The NFTs not overriding it
+	/// go through the same hooks and priority cleanup as for the removal of their own resources.
+	pub fn collection_resource_remove(
+		collection_id: T::CollectionId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		ensure!(
+			CollectionResources::<T>::contains_key(collection_id, resource_id),
+			Error::<T>::ResourceDoesntExist
+		);
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(
+			collection.nfts_count <= T::MaxCollectionResourceNfts::get(),
+			Error::<T>::TooManyNftsForResourceRemoval
+		);
+
+		let nft_ids = Nfts::<T>::iter_key_prefix(collection_id)
+			.take(T::MaxCollectionResourceNfts::get() as usize)
+			.filter(|nft_id| !Resources::<T>::contains_key((collection_id, *nft_id, resource_id)))
+			.collect::<Vec<_>>();
+		// The hooks run while the resource can still be resolved
+		for nft_id in nft_ids.iter() {
+			T::NftHooks::pre_resource_remove(&collection_id, nft_id, &resource_id)?;
+			Self::remove_priority(collection_id, *nft_id, resource_id);
+		}
+
+		let res = CollectionResources::<T>::take(collection_id, resource_id)
+			.ok_or(Error::<T>::ResourceDoesntExist)?;
+
+		if let ResourceTypes::Composable(r) = res.resource {
+			CollectionEquippableBases::<T>::remove((collection_id, r.base, resource_id));
+		}
+		Self::release_storage_deposit(&CollectionResources::<T>::hashed_key_for(
+			collection_id,
+			resource_id,
+		));
+
+		for nft_id in nft_ids.iter() {
+			T::NftHooks::post_resource_remove(&collection_id, nft_id, &resource_id)?;
+		}
+
+		Self::deposit_event(Event::CollectionResourceRemoved { collection_id, resource_id });
+		Ok(())
 	}
 
 	pub fn iterate_collections() -> impl Iterator<Item = T::CollectionId> {
@@ -921,6 +1356,13 @@ impl<T: Config> Pallet<T> {
 		nft_id: T::ItemId,
 		budget: &dyn Budget,
 	) -> Result<(T::AccountId, (T::CollectionId, T::ItemId)), DispatchError> {
+		if let Some(RootNft { root, depth }) = RootOwner::<T>::get((collection_id, nft_id)) {
+			// The budget is consumed as if the tree was walked up to the root NFT
+			ensure!(budget.consume_custom(depth), Error::<T>::TooManyRecursions);
+			let root_owner = pallet_uniques::Pallet::<T>::owner(root.0, root.1)
+				.ok_or(Error::<T>::NoAvailableNftId)?;
+			return Ok((root_owner, root))
+		}
 		// Check if parent returns None which indicates the NFT is not available
 		if let Some(parent) = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id) {
 			match Self::decode_nft_account_id::<T::AccountId>(parent.clone()) {
@@ -935,16 +1377,94 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Update the `RootOwner` entries of an NFT moved into `new_parent`, or to an account with
+	/// `None`, and of all of its descendants. Each descendant consumes the budget.
+	pub fn update_root_owner(
+		nft: (T::CollectionId, T::ItemId),
+		new_parent: Option<(T::CollectionId, T::ItemId)>,
+		budget: &dyn Budget,
+	) -> DispatchResult {
+		let root_nft = match new_parent {
+			Some(parent) => {
+				let parent_root_nft = Self::root_nft_of(parent, budget)?;
+				let root_nft = RootNft {
+					root: parent_root_nft.root,
+					depth: parent_root_nft.depth.saturating_add(1),
+				};
+				RootOwner::<T>::insert(nft, root_nft);
+				root_nft
+			},
+			None => {
+				RootOwner::<T>::remove(nft);
+				RootNft { root: nft, depth: 0 }
+			},
+		};
+		Self::update_descendants_root_owner(nft, root_nft, budget)
+	}
+
+	/// Get the root NFT of an NFT, which is the NFT itself when it is owned by an account.
+	/// A nested NFT without a `RootOwner` entry gets one by walking up the tree, each parent
+	/// consuming the budget.
+	pub fn root_nft_of(
+		nft: (T::CollectionId, T::ItemId),
+		budget: &dyn Budget,
+	) -> Result<RootNftOf<T>, DispatchError> {
+		if let Some(root_nft) = RootOwner::<T>::get(nft) {
+			return Ok(root_nft)
+		}
+		let owner =
+			pallet_uniques::Pallet::<T>::owner(nft.0, nft.1).ok_or(Error::<T>::NoAvailableNftId)?;
+		match Self::decode_nft_account_id::<T::AccountId>(owner) {
+			None => Ok(RootNft { root: nft, depth: 0 }),
+			Some(parent) => {
+				ensure!(budget.consume(), Error::<T>::TooManyRecursions);
+				let parent_root_nft = Self::root_nft_of(parent, budget)?;
+				let root_nft = RootNft {
+					root: parent_root_nft.root,
+					depth: parent_root_nft.depth.saturating_add(1),
+				};
+				RootOwner::<T>::insert(nft, root_nft);
+				Ok(root_nft)
+			},
+		}
+	}
+
+	fn update_descendants_root_owner(
+		nft: (T::CollectionId, T::ItemId),
+		root_nft: RootNftOf<T>,
+		budget: &dyn Budget,
+	) -> DispatchResult {
+		let child_root_nft =
+			RootNft { root: root_nft.root, depth: root_nft.depth.saturating_add(1) };
+		for child in Children::<T>::iter_key_prefix(nft).collect::<Vec<_>>() {
+			ensure!(budget.consume(), Error::<T>::TooManyRecursions);
+			RootOwner::<T>::insert(child, child_root_nft);
+			Self::update_descendants_root_owner(child, child_root_nft, budget)?;
+		}
+		Ok(())
+	}
+
 	/// Add a child to a parent NFT
 	///
 	/// Parameters:
 	/// - `parent`: Tuple of (T::CollectionId,  T::ItemId) of the parent NFT
 	/// - `child`: Tuple of (T::CollectionId,  T::ItemId) of the child NFT to be added
+	/// - `depositor`: Account the storage deposit of the child entry is reserved from
 	///
 	/// Output:
 	/// - Adding a `child` to the Children StorageMap of the `parent`
-	pub fn add_child(parent: (T::CollectionId, T::ItemId), child: (T::CollectionId, T::ItemId)) {
+	pub fn add_child(
+		parent: (T::CollectionId, T::ItemId),
+		child: (T::CollectionId, T::ItemId),
+		depositor: &T::AccountId,
+	) -> DispatchResult {
+		Self::reserve_storage_deposit(
+			depositor,
+			&Children::<T>::hashed_key_for((parent.0, parent.1), (child.0, child.1)),
+			0,
+		)?;
 		Children::<T>::insert((parent.0, parent.1), (child.0, child.1), ());
+		Ok(())
 	}
 
 	/// Remove a child from a parent NFT
@@ -956,9 +1476,291 @@ impl<T: Config> Pallet<T> {
 	/// Output:
 	/// - Removing a `child` from the Children StorageMap of the `parent`
 	pub fn remove_child(parent: (T::CollectionId, T::ItemId), child: (T::CollectionId, T::ItemId)) {
+		Self::release_storage_deposit(&Children::<T>::hashed_key_for(
+			(parent.0, parent.1),
+			(child.0, child.1),
+		));
 		Children::<T>::remove((parent.0, parent.1), (child.0, child.1));
 	}
 
+	/// Deposit required to store a value of `value_len` bytes under a storage key of `key_len`
+	/// bytes
+	pub fn storage_deposit(key_len: usize, value_len: usize) -> BalanceOf<T> {
+		let bytes = key_len.saturating_add(value_len) as u32;
+		T::StorageDepositPerByte::get().saturating_mul(bytes.into())
+	}
+
+	/// Reserve the deposit of a storage entry from `depositor`. The deposit previously held for
+	/// the entry, if any, is refunded first.
+	///
+	/// Parameters:
+	/// - `depositor`: Account the deposit is reserved from
+	/// - `storage_key`: Final storage key of the entry
+	/// - `value_len`: Encoded length of the stored value
+	pub fn reserve_storage_deposit(
+		depositor: &T::AccountId,
+		storage_key: &[u8],
+		value_len: usize,
+	) -> DispatchResult {
+		Self::release_storage_deposit(storage_key);
+		let deposit = Self::storage_deposit(storage_key.len(), value_len);
+		if !deposit.is_zero() {
+			<T as pallet_uniques::Config>::Currency::reserve(depositor, deposit)?;
+			StorageDeposits::<T>::insert(
+				T::Hashing::hash(storage_key),
+				(depositor.clone(), deposit),
+			);
+		}
+		Ok(())
+	}
+
+	/// Refund the deposit held for a storage entry to the account it was reserved from
+	///
+	/// Parameters:
+	/// - `storage_key`: Final storage key of the entry
+	pub fn release_storage_deposit(storage_key: &[u8]) {
+		if let Some((depositor, deposit)) =
+			StorageDeposits::<T>::take(T::Hashing::hash(storage_key))
+		{
+			<T as pallet_uniques::Config>::Currency::unreserve(&depositor, deposit);
+		}
+	}
+
+	/// Move the deposit held for a storage entry to another entry, e.g. when a value moves to
+	/// its final place. The deposit previously held for the other entry is refunded.
+	///
+	/// Parameters:
+	/// - `from`: Final storage key of the entry the deposit was held for
+	/// - `to`: Final storage key of the entry the deposit is now held for
+	pub fn move_storage_deposit(from: &[u8], to: &[u8]) {
+		Self::release_storage_deposit(to);
+		if let Some(deposit) = StorageDeposits::<T>::take(T::Hashing::hash(from)) {
+			StorageDeposits::<T>::insert(T::Hashing::hash(to), deposit);
+		}
+	}
+
+	/// Remove up to `limit` properties of a collection or NFT, refunding their deposits
+	///
+	/// Output:
+	/// - The number of removed properties
+	pub fn clear_properties(
+		collection_id: T::CollectionId,
+		maybe_nft_id: Option<T::ItemId>,
+		limit: u32,
+	) -> u32 {
+		let keys = Properties::<T>::iter_key_prefix((collection_id, maybe_nft_id))
+			.take(limit as usize)
+			.collect::<Vec<_>>();
+		for key in keys.iter() {
+			Self::release_storage_deposit(&Properties::<T>::hashed_key_for((
+				&collection_id,
+				maybe_nft_id,
+				key,
+			)));
+			Properties::<T>::remove((&collection_id, maybe_nft_id, key));
+		}
+		keys.len() as u32
+	}
+
+	/// Remove the priorities of an NFT, refunding their deposits
+	pub fn clear_priorities(collection_id: T::CollectionId, nft_id: T::ItemId) {
+		for resource_id in Priorities::<T>::iter_key_prefix((collection_id, nft_id))
+			.take(T::MaxPriorities::get() as usize)
+			.collect::<Vec<_>>()
+		{
+			Self::release_storage_deposit(&Priorities::<T>::hashed_key_for((
+				collection_id,
+				nft_id,
+				resource_id,
+			)));
+			Priorities::<T>::remove((collection_id, nft_id, resource_id));
+		}
+	}
+
+	/// Ensure that every resource of a priority list exists on the NFT, isn't pending and only
+	/// appears once
+	pub fn ensure_valid_priorities(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		priorities: &[ResourceId],
+	) -> DispatchResult {
+		let mut seen = BTreeSet::new();
+		for resource_id in priorities {
+			ensure!(seen.insert(resource_id), Error::<T>::DuplicatePriority);
+			let resource = Self::resolve_resource(collection_id, nft_id, *resource_id)
+				.ok_or(Error::<T>::ResourceDoesntExist)?;
+			ensure!(!resource.pending, Error::<T>::ResourcePending);
+		}
+		Ok(())
+	}
+
+	/// Move a resource to another index of the priorities of an NFT
+	pub fn priority_move(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+		index: u32,
+	) -> DispatchResult {
+		let current_index = Priorities::<T>::get((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::ResourceNotInPriorities)?;
+		let priorities = Priorities::<T>::iter_prefix((collection_id, nft_id)).collect::<Vec<_>>();
+		ensure!((index as usize) < priorities.len(), Error::<T>::InvalidPriorityIndex);
+
+		for (other_resource_id, other_index) in priorities {
+			let new_index = if other_resource_id == resource_id {
+				index
+			} else if current_index < other_index && other_index <= index {
+				other_index - 1
+			} else if index <= other_index && other_index < current_index {
+				other_index + 1
+			} else {
+				continue
+			};
+			Priorities::<T>::insert((collection_id, nft_id, other_resource_id), new_index);
+		}
+
+		Self::deposit_event(Event::PrioritySet { collection_id, nft_id });
+		Ok(())
+	}
+
+	/// Swap the indexes of two resources in the priorities of an NFT
+	pub fn priority_swap(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+		other_resource_id: ResourceId,
+	) -> DispatchResult {
+		let index = Priorities::<T>::get((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::ResourceNotInPriorities)?;
+		let other_index = Priorities::<T>::get((collection_id, nft_id, other_resource_id))
+			.ok_or(Error::<T>::ResourceNotInPriorities)?;
+		Priorities::<T>::insert((collection_id, nft_id, resource_id), other_index);
+		Priorities::<T>::insert((collection_id, nft_id, other_resource_id), index);
+
+		Self::deposit_event(Event::PrioritySet { collection_id, nft_id });
+		Ok(())
+	}
+
+	/// Set or, with an empty list, remove the priority list of an NFT for a context
+	pub fn context_priority_set(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		context: KeyLimitOf<T>,
+		priorities: BoundedVec<ResourceId, T::MaxPriorities>,
+	) -> DispatchResult {
+		Self::ensure_valid_priorities(collection_id, nft_id, &priorities)?;
+		let storage_key = ContextPriorities::<T>::hashed_key_for((collection_id, nft_id, &context));
+		if !priorities.is_empty() &&
+			!ContextPriorities::<T>::contains_key((collection_id, nft_id, &context))
+		{
+			let contexts = ContextPriorities::<T>::iter_key_prefix((collection_id, nft_id))
+				.take(T::MaxPriorityContexts::get() as usize)
+				.count() as u32;
+			ensure!(contexts < T::MaxPriorityContexts::get(), Error::<T>::TooManyPriorityContexts);
+		}
+		if priorities.is_empty() {
+			Self::release_storage_deposit(&storage_key);
+			ContextPriorities::<T>::remove((collection_id, nft_id, &context));
+		} else {
+			Self::reserve_storage_deposit(&sender, &storage_key, priorities.encoded_size())?;
+			ContextPriorities::<T>::insert((collection_id, nft_id, &context), priorities);
+		}
+
+		Self::deposit_event(Event::ContextPrioritySet { collection_id, nft_id, context });
+		Ok(())
+	}
+
+	/// Remove up to `limit` context priority lists of an NFT, refunding their deposits
+	pub fn clear_context_priorities(collection_id: T::CollectionId, nft_id: T::ItemId, limit: u32) {
+		for context in ContextPriorities::<T>::iter_key_prefix((collection_id, nft_id))
+			.take(limit as usize)
+			.collect::<Vec<_>>()
+		{
+			Self::release_storage_deposit(&ContextPriorities::<T>::hashed_key_for((
+				collection_id,
+				nft_id,
+				&context,
+			)));
+			ContextPriorities::<T>::remove((collection_id, nft_id, &context));
+		}
+	}
+
+	/// Get the resource of an NFT to show in a context: the first accepted resource of the
+	/// priority list of the context, else of the list set with `set_priority`, else the first
+	/// accepted resource of the NFT.
+	pub fn resource_for_context(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		context: Option<&KeyLimitOf<T>>,
+	) -> Option<ResourceInfoOf<T>> {
+		let accepted = |resource_id: ResourceId| {
+			Self::resolve_resource(collection_id, nft_id, resource_id).filter(|res| !res.pending)
+		};
+		if let Some(priorities) = context
+			.and_then(|context| ContextPriorities::<T>::get((collection_id, nft_id, context)))
+		{
+			if let Some(res) = priorities.into_iter().find_map(accepted) {
+				return Some(res)
+			}
+		}
+		let mut priorities =
+			Priorities::<T>::iter_prefix((collection_id, nft_id)).collect::<Vec<_>>();
+		priorities.sort_by_key(|(_, index)| *index);
+		priorities
+			.into_iter()
+			.find_map(|(resource_id, _)| accepted(resource_id))
+			.or_else(|| Self::iterate_resources(collection_id, nft_id).find(|res| !res.pending))
+	}
+
+	/// Remove a resource from the priorities of an NFT, moving up the resources ranked after it,
+	/// and from its context priority lists. A context list left empty is removed.
+	pub fn remove_priority(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) {
+		for (context, mut priorities) in
+			ContextPriorities::<T>::iter_prefix((collection_id, nft_id))
+				.take(T::MaxPriorityContexts::get() as usize)
+				.collect::<Vec<_>>()
+		{
+			if !priorities.contains(&resource_id) {
+				continue
+			}
+			priorities.retain(|id| *id != resource_id);
+			if priorities.is_empty() {
+				Self::release_storage_deposit(&ContextPriorities::<T>::hashed_key_for((
+					collection_id,
+					nft_id,
+					&context,
+				)));
+				ContextPriorities::<T>::remove((collection_id, nft_id, &context));
+			} else {
+				// The deposit of the longer list stays reserved until the list is removed
+				ContextPriorities::<T>::insert((collection_id, nft_id, &context), priorities);
+			}
+		}
+
+		let removed_index = match Priorities::<T>::take((collection_id, nft_id, resource_id)) {
+			Some(index) => index,
+			None => return,
+		};
+		Self::release_storage_deposit(&Priorities::<T>::hashed_key_for((
+			collection_id,
+			nft_id,
+			resource_id,
+		)));
+		for (other_resource_id, index) in Priorities::<T>::iter_prefix((collection_id, nft_id))
+			.take(T::MaxPriorities::get() as usize)
+			.collect::<Vec<_>>()
+		{
+			if index > removed_index {
+				Priorities::<T>::insert((collection_id, nft_id, other_resource_id), index - 1);
+			}
+		}
+	}
+
 	/// Check whether a NFT is descends from a suspected parent NFT
 	/// and return a `bool` if NFT is or not
 	///
@@ -977,6 +1779,25 @@ impl<T: Config> Pallet<T> {
 	) -> bool {
 		let mut found_child = false;
 
+		// An NFT can only descend from an NFT of the same tree. A nested NFT without a
+		// `RootOwner` entry has an unknown root, in which case the tree is walked.
+		let root_nft_of = |nft: (T::CollectionId, T::ItemId)| match RootOwner::<T>::get(nft) {
+			Some(root_nft) => Some(root_nft.root),
+			None => pallet_uniques::Pallet::<T>::owner(nft.0, nft.1)
+				.filter(|owner| {
+					Self::decode_nft_account_id::<T::AccountId>(owner.clone()).is_none()
+				})
+				.map(|_| nft),
+		};
+		if let (Some(child_root), Some(parent_root)) = (
+			root_nft_of((child_collection_id, child_nft_id)),
+			root_nft_of((parent_collection_id, parent_nft_id)),
+		) {
+			if child_root != parent_root {
+				return found_child
+			}
+		}
+
 		// Check if parent returns None which indicates the NFT is not available
 		let parent = match pallet_uniques::Pallet::<T>::owner(child_collection_id, child_nft_id) {
 			Some(parent) => parent,
@@ -1001,16 +1822,27 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	pub fn set_lock(nft: (T::CollectionId, T::ItemId), lock_status: bool) -> bool {
-		if lock_status {
-			Lock::<T>::mutate(nft, |lock| {
-				*lock = lock_status;
-				*lock
-			});
-		} else {
-			Lock::<T>::remove(nft);
-		}
-		lock_status
+	/// Lock an NFT for the reason `id`. The NFT stays locked until every lock is removed, so
+	/// each pallet should use its own `LockIdentifier`. Setting an existing lock is a no-op.
+	pub fn set_lock(id: LockIdentifier, nft: (T::CollectionId, T::ItemId)) -> DispatchResult {
+		Locks::<T>::try_mutate(nft, |locks| -> DispatchResult {
+			if !locks.contains(&id) {
+				locks.try_push(id).map_err(|_| Error::<T>::TooManyLocks)?;
+			}
+			Ok(())
+		})
+	}
+
+	/// Remove the lock for the reason `id` from an NFT, other locks are kept
+	pub fn remove_lock(id: LockIdentifier, nft: (T::CollectionId, T::ItemId)) {
+		Locks::<T>::mutate_exists(nft, |maybe_locks| {
+			if let Some(locks) = maybe_locks {
+				locks.retain(|lock| *lock != id);
+				if locks.is_empty() {
+					*maybe_locks = None;
+				}
+			}
+		});
 	}
 
 	// Check NFT is transferable
@@ -1025,9 +1857,1189 @@ impl<T: Config> Pallet<T> {
 		Nfts::<T>::get(item_collection_id, item_nft_id).is_some()
 	}
 
-	// Check NFT is not equipped
-	pub fn check_is_not_equipped(nft: &InstanceInfoOf<T>) -> DispatchResult {
-		ensure!(nft.equipped.is_none(), Error::<T>::CannotSendEquippedItem);
+	/// Helper function for checking that a collection is not being destroyed
+	pub fn ensure_not_destroying(collection_id: T::CollectionId) -> DispatchResult {
+		ensure!(
+			!DestroyingCollections::<T>::contains_key(collection_id),
+			Error::<T>::CollectionIsDestroying
+		);
+		Ok(())
+	}
+
+	/// Send an NFT like `nft_send`, also returning the nesting budget consumed by the root owner
+	/// lookups of the NFT and of its new parent
+	pub fn do_nft_send(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		new_owner: AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
+	) -> Result<(T::AccountId, bool, u32), DispatchError> {
+		// Get current owner for child removal later
+		let parent = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id);
+		// Check if parent returns None which indicates the NFT is not available
+		ensure!(parent.is_some(), Error::<T>::NoAvailableNftId); // <- is this error wrong?
+
+		let budget = budget::Value::new(T::NestingBudget::get());
+		let (root_owner, _root_nft) =
+			Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+		let mut budget_consumed = budget.get_budget_consumed_value();
+		// Check ownership or approval
+		ensure!(
+			Self::is_approved_sender(&sender, &root_owner, collection_id, nft_id),
+			Error::<T>::NoPermission
+		);
+		// Get NFT info
+		let mut sending_nft =
+			Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
+
+		// Does nothing by default, but can be implemented downstream for custom logic
+		T::NftHooks::pre_transfer(&sender, &collection_id, &nft_id)?;
+
+		// Check NFT is transferable
+		Self::check_is_transferable(&sending_nft)?;
+
+		// Expired NFTs can't be transferred
+		Self::ensure_not_expired(collection_id, nft_id)?;
+
+		// NFTs of a collection being destroyed can't be transferred
+		Self::ensure_not_destroying(collection_id)?;
+
+		// NFT cannot be sent if it is equipped
+		Self::check_is_not_equipped(&sending_nft)?;
+
+		// Needs to be pending if the sending to an account or to a non-owned NFT
+		let mut approval_required = true;
+
+		// Prepare transfer
+		let new_owner_account = match new_owner.clone() {
+			AccountIdOrCollectionNftTuple::AccountId(id) => {
+				approval_required = false;
+				id
+			},
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) => {
+				// Check if NFT target exists
+				ensure!(Nfts::<T>::contains_key(cid, nid), Error::<T>::NoAvailableNftId);
+				// NFTs can't be sent to an NFT of a collection being destroyed
+				Self::ensure_not_destroying(cid)?;
+				// NFTs can't be sent to an expired NFT
+				Self::ensure_not_expired(cid, nid)?;
+				// Check if sending to self
+				ensure!(
+					(collection_id, nft_id) != (cid, nid),
+					Error::<T>::CannotSendToDescendentOrSelf
+				);
+				// Check if collection_id & nft_id are descendent of cid & nid
+				ensure!(
+					!Pallet::<T>::is_x_descendent_of_y(cid, nid, collection_id, nft_id),
+					Error::<T>::CannotSendToDescendentOrSelf
+				);
+				let budget = budget::Value::new(T::NestingBudget::get().saturating_sub(One::one()));
+				let (recipient_root_owner, _root_nft) =
+					Pallet::<T>::lookup_root_owner(cid, nid, &budget)?;
+				budget_consumed.saturating_accrue(budget.get_budget_consumed_value());
+				if recipient_root_owner == root_owner {
+					approval_required = false;
+				}
+
+				// Convert to virtual account
+				Pallet::<T>::nft_to_account_id::<T::AccountId>(cid, nid)
+			},
+		};
+
+		let previous_owner = sending_nft.owner.clone();
+		sending_nft.owner = new_owner.clone();
+
+		if approval_required {
+			Nfts::<T>::try_mutate_exists(collection_id, nft_id, |nft| -> DispatchResult {
+				if let Some(nft) = nft {
+					nft.pending = true;
+					nft.owner = new_owner.clone();
+				}
+				Ok(())
+			})?;
+			PendingTransfers::<T>::insert(
+				(collection_id, nft_id),
+				PendingTransfer { sender: root_owner.clone(), previous_owner, expires: None },
+			);
+		} else {
+			Nfts::<T>::insert(collection_id, nft_id, sending_nft);
+			PendingTransfers::<T>::remove((collection_id, nft_id));
+		}
+
+		if let Some(current_owner) = parent {
+			// Handle Children StorageMap for NFTs
+			let current_owner_cid_nid =
+				Pallet::<T>::decode_nft_account_id::<T::AccountId>(current_owner);
+			if let Some(current_owner_cid_nid) = current_owner_cid_nid {
+				// Remove child from parent
+				Pallet::<T>::remove_child(current_owner_cid_nid, (collection_id, nft_id));
+			}
+		}
+
+		// add child to new parent if NFT virtual address
+		let new_owner_cid_nid =
+			Pallet::<T>::decode_nft_account_id::<T::AccountId>(new_owner_account.clone());
+		if let Some(new_owner_cid_nid) = new_owner_cid_nid {
+			Pallet::<T>::add_child(new_owner_cid_nid, (collection_id, nft_id), &sender)?;
+		}
+		// The NFT moves along with its children
+		let budget = budget::Value::new(T::NestingBudget::get());
+		Self::update_root_owner((collection_id, nft_id), new_owner_cid_nid, &budget)?;
+
+		pallet_uniques::Pallet::<T>::do_transfer(
+			collection_id,
+			nft_id,
+			new_owner_account.clone(),
+			|_class_details, _details| Ok(()),
+		)?;
+
+		// Neither do approvals and the replacements waiting for the acceptance of the owner
+		Approvals::<T>::remove((collection_id, nft_id));
+		Self::clear_pending_replacements(collection_id, nft_id);
+
+		// Does nothing by default, but can be implemented downstream for custom logic
+		T::NftHooks::post_transfer(&sender, &new_owner_account, &collection_id, &nft_id)?;
+
+		Self::deposit_event(Event::NFTSent {
+			sender,
+			recipient: new_owner,
+			collection_id,
+			nft_id,
+			approval_required,
+		});
+
+		Ok((new_owner_account, approval_required, budget_consumed))
+	}
+
+	/// Sends a batch of NFTs in order and returns the total nesting budget consumed by the root
+	/// owner lookups of the sent NFTs and of their new parents, as reported by `do_nft_send`.
+	/// Every item goes through `do_nft_send`, so a cycle created by an earlier item of the batch
+	/// is caught by the descendant check of a later one.
+	pub fn nft_send_batch(
+		sender: T::AccountId,
+		items: BoundedSendBatchOf<T>,
+	) -> Result<u32, DispatchError> {
+		let mut budget_consumed = 0u32;
+		for (collection_id, nft_id, new_owner) in items {
+			let (_, _, consumed) =
+				Self::do_nft_send(sender.clone(), collection_id, nft_id, new_owner)?;
+			budget_consumed.saturating_accrue(consumed);
+		}
+		Ok(budget_consumed)
+	}
+
+	/// Add a resource to each NFT of a batch, pending on the NFTs not owned by the sender.
+	/// Returns the budget consumed by the root owner lookups.
+	pub fn resource_add_batch(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		resource: ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
+		maybe_resource_id: Option<ResourceId>,
+		nft_ids: BoundedResourceBatchOf<T>,
+	) -> Result<u32, DispatchError> {
+		let mut budget_consumed = 0u32;
+		for nft_id in nft_ids {
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id, &budget)?;
+			budget_consumed.saturating_accrue(budget.get_budget_consumed_value());
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			let pending = root_owner != sender;
+			let resource_id = match maybe_resource_id {
+				Some(resource_id) => resource_id,
+				None => Self::get_next_resource_id(collection_id, nft_id)?,
+			};
+			Self::resource_add(
+				sender.clone(),
+				collection_id,
+				nft_id,
+				resource.clone(),
+				pending,
+				resource_id,
+			)?;
+		}
+		Ok(budget_consumed)
+	}
+
+	/// Add a pending resource proposed by the root owner of an NFT, to be approved or rejected
+	/// by the collection issuer
+	pub fn resource_propose(
+		proposer: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource: ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		let proposals = ProposedResources::<T>::iter_key_prefix((collection_id, nft_id))
+			.take(T::MaxProposalsPerNft::get() as usize)
+			.count() as u32;
+		ensure!(proposals < T::MaxProposalsPerNft::get(), Error::<T>::TooManyProposals);
+		Self::resource_add(proposer.clone(), collection_id, nft_id, resource, true, resource_id)?;
+		ProposedResources::<T>::insert((collection_id, nft_id, resource_id), &proposer);
+
+		Self::deposit_event(Event::ResourceProposed {
+			proposer,
+			nft_id,
+			resource_id,
+			collection_id,
+		});
+		Ok(())
+	}
+
+	/// Accept a resource proposed by the root owner of an NFT
+	pub fn resource_proposal_approve(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		ensure!(
+			ProposedResources::<T>::take((collection_id, nft_id, resource_id)).is_some(),
+			Error::<T>::ResourceNotProposed
+		);
+		Self::accept(sender, collection_id, nft_id, resource_id)
+	}
+
+	/// Remove a resource proposed by the root owner of an NFT, refunding its deposit
+	pub fn resource_proposal_reject(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		ensure!(
+			ProposedResources::<T>::contains_key((collection_id, nft_id, resource_id)),
+			Error::<T>::ResourceNotProposed
+		);
+		let resource = Resources::<T>::get((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::ResourceDoesntExist)?;
+		Self::do_remove_resource_from_bases_and_equippable_slots(
+			resource,
+			collection_id,
+			nft_id,
+			resource_id,
+		)?;
+		PendingResourceExpiry::<T>::remove((collection_id, nft_id, resource_id));
+		T::NftHooks::post_resource_remove(&collection_id, &nft_id, &resource_id)?;
+
+		Self::deposit_event(Event::ResourceProposalRejected { nft_id, resource_id, collection_id });
+		Ok(())
+	}
+
+	/// Store the replacement of a resource of an NFT not owned by the collection issuer, to be
+	/// accepted or rejected by the root owner. A previous pending replacement is overwritten.
+	pub fn resource_replacement_propose(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource: ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		let current = Resources::<T>::get((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::ResourceDoesntExist)?;
+		if !PendingReplacements::<T>::contains_key((collection_id, nft_id, resource_id)) {
+			let replacements = PendingReplacements::<T>::iter_key_prefix((collection_id, nft_id))
+				.take(T::MaxReplacementsPerNft::get() as usize)
+				.count() as u32;
+			ensure!(
+				replacements < T::MaxReplacementsPerNft::get(),
+				Error::<T>::TooManyReplacements
+			);
+		}
+		// The deposit covers the replaced resource, to which it moves once accepted
+		let replaced = ResourceInfoOf::<T> { resource: resource.clone(), ..current };
+		Self::reserve_storage_deposit(
+			&sender,
+			&PendingReplacements::<T>::hashed_key_for((collection_id, nft_id, resource_id)),
+			replaced.encoded_size(),
+		)?;
+		PendingReplacements::<T>::insert((collection_id, nft_id, resource_id), resource);
+
+		Self::deposit_event(Event::ResourceReplacementProposed {
+			nft_id,
+			resource_id,
+			collection_id,
+		});
+		Ok(())
+	}
+
+	/// Replace a resource with its pending replacement. The deposit reserved from the proposer
+	/// of the replacement becomes the deposit of the resource.
+	pub fn resource_replacement_accept(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		let resource = PendingReplacements::<T>::take((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::NoPendingReplacement)?;
+		Resources::<T>::try_mutate(
+			(collection_id, nft_id, resource_id),
+			|res| -> DispatchResult {
+				let res = res.as_mut().ok_or(Error::<T>::ResourceDoesntExist)?;
+				Self::remove_equippable_entries(collection_id, nft_id, resource_id, &res.resource);
+				Self::insert_equippable_entries(collection_id, nft_id, resource_id, &resource);
+				res.resource = resource;
+				Ok(())
+			},
+		)?;
+		Self::move_storage_deposit(
+			&PendingReplacements::<T>::hashed_key_for((collection_id, nft_id, resource_id)),
+			&Resources::<T>::hashed_key_for((collection_id, nft_id, resource_id)),
+		);
+
+		Self::deposit_event(Event::ResourceReplaced { nft_id, resource_id, collection_id });
+
+		Self::deposit_event(Event::ResourceReplacementAccepted {
+			nft_id,
+			resource_id,
+			collection_id,
+		});
+		Ok(())
+	}
+
+	/// Drop the pending replacement of a resource, which keeps its current value
+	pub fn resource_replacement_reject(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		ensure!(
+			Self::take_pending_replacement(collection_id, nft_id, resource_id).is_some(),
+			Error::<T>::NoPendingReplacement
+		);
+
+		Self::deposit_event(Event::ResourceReplacementRejected {
+			nft_id,
+			resource_id,
+			collection_id,
+		});
+		Ok(())
+	}
+
+	/// Drop the pending replacements of the resources of an NFT, refunding their deposits, e.g.
+	/// when the NFT changes hands
+	pub fn clear_pending_replacements(collection_id: T::CollectionId, nft_id: T::ItemId) {
+		for resource_id in PendingReplacements::<T>::iter_key_prefix((collection_id, nft_id))
+			.take(T::MaxReplacementsPerNft::get() as usize)
+			.collect::<Vec<_>>()
+		{
+			Self::take_pending_replacement(collection_id, nft_id, resource_id);
+		}
+	}
+
+	/// Remove the pending replacement of a resource, refunding its deposit
+	fn take_pending_replacement(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) -> Option<ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>> {
+		Self::release_storage_deposit(&PendingReplacements::<T>::hashed_key_for((
+			collection_id,
+			nft_id,
+			resource_id,
+		)));
+		PendingReplacements::<T>::take((collection_id, nft_id, resource_id))
+	}
+
+	/// Set the block from which a pending NFT or a pending resource can be expired
+	pub fn do_set_pending_expiry(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		maybe_resource_id: Option<ResourceId>,
+		expires: T::BlockNumber,
+	) -> DispatchResult {
+		ensure!(expires > <frame_system::Pallet<T>>::block_number(), Error::<T>::InvalidExpiry);
+		match maybe_resource_id {
+			None => {
+				PendingTransfers::<T>::try_mutate(
+					(collection_id, nft_id),
+					|pending_transfer| -> DispatchResult {
+						let pending_transfer =
+							pending_transfer.as_mut().ok_or(Error::<T>::NftNotPending)?;
+						ensure!(pending_transfer.sender == sender, Error::<T>::NoPermission);
+						pending_transfer.expires = Some(expires);
+						Ok(())
+					},
+				)?;
+			},
+			Some(resource_id) => {
+				let collection =
+					Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+				ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+				let resource = Resources::<T>::get((collection_id, nft_id, resource_id))
+					.ok_or(Error::<T>::ResourceDoesntExist)?;
+				ensure!(
+					resource.pending || resource.pending_removal,
+					Error::<T>::ResourceNotPending
+				);
+				PendingResourceExpiry::<T>::insert((collection_id, nft_id, resource_id), expires);
+			},
+		}
+
+		Self::deposit_event(Event::PendingExpirySet {
+			collection_id,
+			nft_id,
+			maybe_resource_id,
+			expires,
+		});
+		Ok(())
+	}
+
+	/// Expire a pending NFT or a pending resource whose expiry has been reached
+	pub fn do_expire_pending(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		maybe_resource_id: Option<ResourceId>,
+	) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		match maybe_resource_id {
+			None => {
+				let pending_transfer = PendingTransfers::<T>::get((collection_id, nft_id))
+					.ok_or(Error::<T>::NftNotPending)?;
+				let expires = pending_transfer.expires.ok_or(Error::<T>::NoPendingExpiry)?;
+				ensure!(expires <= now, Error::<T>::PendingNotExpired);
+				let nft =
+					Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
+				ensure!(nft.pending, Error::<T>::NftNotPending);
+
+				let returned_to = Self::nft_return_pending(collection_id, nft_id)?;
+
+				Self::deposit_event(Event::PendingNftExpired {
+					collection_id,
+					nft_id,
+					returned_to,
+				});
+			},
+			Some(resource_id) => {
+				let expires = PendingResourceExpiry::<T>::get((collection_id, nft_id, resource_id))
+					.ok_or(Error::<T>::NoPendingExpiry)?;
+				ensure!(expires <= now, Error::<T>::PendingNotExpired);
+				let resource = Resources::<T>::get((collection_id, nft_id, resource_id))
+					.ok_or(Error::<T>::ResourceDoesntExist)?;
+				if resource.pending {
+					// Drop the proposed resource
+					Self::do_remove_resource_from_bases_and_equippable_slots(
+						resource,
+						collection_id,
+						nft_id,
+						resource_id,
+					)?;
+					T::NftHooks::post_resource_remove(&collection_id, &nft_id, &resource_id)?;
+				} else {
+					// Drop the proposed removal, the resource stays
+					ensure!(resource.pending_removal, Error::<T>::ResourceNotPending);
+					Resources::<T>::mutate((collection_id, nft_id, resource_id), |resource| {
+						if let Some(res) = resource {
+							res.pending_removal = false;
+						}
+					});
+				}
+				PendingResourceExpiry::<T>::remove((collection_id, nft_id, resource_id));
+
+				Self::deposit_event(Event::PendingResourceExpired {
+					collection_id,
+					nft_id,
+					resource_id,
+				});
+			},
+		}
+		Ok(())
+	}
+
+	/// Send a pending NFT, along with its children, back to its previous owner. The NFT goes
+	/// back into its previous parent NFT only while the sender is still the root owner of that
+	/// NFT, otherwise it goes back to the sender account.
+	///
+	/// Output:
+	/// - The owner the NFT was returned to
+	pub fn nft_return_pending(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+	) -> Result<
+		AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
+		DispatchError,
+	> {
+		let pending_transfer = PendingTransfers::<T>::take((collection_id, nft_id))
+			.ok_or(Error::<T>::UnknownPreviousOwner)?;
+
+		let returned_to = match pending_transfer.previous_owner {
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid)
+				if Self::can_return_into(
+					&pending_transfer.sender,
+					(cid, nid),
+					(collection_id, nft_id),
+				) =>
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid),
+			_ => AccountIdOrCollectionNftTuple::AccountId(pending_transfer.sender.clone()),
+		};
+
+		// Remove the NFT from the children of the NFT it was sent to
+		if let Some(parent_account_id) = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id) {
+			if let Some(parent) =
+				Pallet::<T>::decode_nft_account_id::<T::AccountId>(parent_account_id)
+			{
+				Self::remove_child(parent, (collection_id, nft_id));
+			}
+		}
+
+		Nfts::<T>::try_mutate(collection_id, nft_id, |nft| -> DispatchResult {
+			let nft = nft.as_mut().ok_or(Error::<T>::NoAvailableNftId)?;
+			nft.owner = returned_to.clone();
+			nft.pending = false;
+			Ok(())
+		})?;
+		Approvals::<T>::remove((collection_id, nft_id));
+
+		let (returned_to_account, returned_to_parent) = match returned_to.clone() {
+			AccountIdOrCollectionNftTuple::AccountId(account) => (account, None),
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) => {
+				Self::add_child((cid, nid), (collection_id, nft_id), &pending_transfer.sender)?;
+				(Self::nft_to_account_id(cid, nid), Some((cid, nid)))
+			},
+		};
+		let budget = budget::Value::new(T::NestingBudget::get());
+		Self::update_root_owner((collection_id, nft_id), returned_to_parent, &budget)?;
+		pallet_uniques::Pallet::<T>::do_transfer(
+			collection_id,
+			nft_id,
+			returned_to_account,
+			|_, _| Ok(()),
+		)?;
+
+		Ok(returned_to)
+	}
+
+	/// Prepare an NFT of a collection being destroyed to be burned. The NFT and its descendants
+	/// of the same collection must not be locked, and its descendants of other collections are
+	/// moved out to `root_owner` instead of being burned along with it. Each descendant
+	/// consumes the budget.
+	pub fn release_foreign_children(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		root_owner: &T::AccountId,
+		budget: &dyn Budget,
+	) -> DispatchResult {
+		ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+		for (child_collection_id, child_nft_id) in
+			Children::<T>::iter_key_prefix((collection_id, nft_id)).collect::<Vec<_>>()
+		{
+			ensure!(budget.consume(), Error::<T>::TooManyRecursions);
+			if child_collection_id == collection_id {
+				Self::release_foreign_children(
+					child_collection_id,
+					child_nft_id,
+					root_owner,
+					budget,
+				)?;
+			} else {
+				let returned_to =
+					Self::nft_move_to_account(child_collection_id, child_nft_id, root_owner)?;
+				Self::deposit_event(Event::NFTSent {
+					sender: root_owner.clone(),
+					recipient: returned_to,
+					collection_id: child_collection_id,
+					nft_id: child_nft_id,
+					approval_required: false,
+				});
+			}
+		}
+		Ok(())
+	}
+
+	/// Move an NFT, along with its children, out of its parent NFT into the account of `owner`,
+	/// without any of the checks of a regular transfer. An equipped NFT is unequipped first,
+	/// and a pending NFT goes back to its previous owner instead.
+	///
+	/// Output:
+	/// - The owner the NFT was moved to
+	pub fn nft_move_to_account(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		owner: &T::AccountId,
+	) -> Result<
+		AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
+		DispatchError,
+	> {
+		let nft = Self::nfts(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
+		if nft.pending {
+			return Self::nft_return_pending(collection_id, nft_id)
+		}
+		let new_owner = AccountIdOrCollectionNftTuple::AccountId(owner.clone());
+		Self::nft_force_send(collection_id, nft_id, new_owner.clone())?;
+		Ok(new_owner)
+	}
+
+	/// Move an NFT, along with its children, to `new_owner` without any of the checks of a
+	/// regular transfer and without the approval of the new owner. A pending transfer of the
+	/// NFT is dropped, an equipped NFT is unequipped first, and the locks of the NFT are kept.
+	/// The deposit of the child entry is paid by the root owner of the new parent NFT.
+	///
+	/// Output:
+	/// - The account the NFT was transferred to in `pallet_uniques`
+	pub fn nft_force_send(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		new_owner: AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
+	) -> Result<T::AccountId, DispatchError> {
+		let nft = Self::nfts(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
+
+		let (new_owner_account, new_parent) = match new_owner {
+			AccountIdOrCollectionNftTuple::AccountId(ref account) => (account.clone(), None),
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) => {
+				ensure!(Nfts::<T>::contains_key(cid, nid), Error::<T>::NoAvailableNftId);
+				ensure!(
+					(collection_id, nft_id) != (cid, nid) &&
+						!Self::is_x_descendent_of_y(cid, nid, collection_id, nft_id),
+					Error::<T>::CannotSendToDescendentOrSelf
+				);
+				(Self::nft_to_account_id::<T::AccountId>(cid, nid), Some((cid, nid)))
+			},
+		};
+
+		// Let the equip pallet clear the slot the NFT is equipped into
+		if let Some((resource_id, _)) = nft.equipped {
+			T::NftHooks::pre_resource_remove(&collection_id, &nft_id, &resource_id)?;
+		}
+		if let AccountIdOrCollectionNftTuple::CollectionAndNftTuple(parent_cid, parent_nid) =
+			nft.owner
+		{
+			Self::remove_child((parent_cid, parent_nid), (collection_id, nft_id));
+		}
+		if let Some((parent_cid, parent_nid)) = new_parent {
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (parent_root_owner, _) = Self::lookup_root_owner(parent_cid, parent_nid, &budget)?;
+			Self::add_child((parent_cid, parent_nid), (collection_id, nft_id), &parent_root_owner)?;
+		}
+
+		Nfts::<T>::try_mutate(collection_id, nft_id, |nft| -> DispatchResult {
+			let nft = nft.as_mut().ok_or(Error::<T>::NoAvailableNftId)?;
+			nft.owner = new_owner.clone();
+			nft.pending = false;
+			nft.equipped = None;
+			Ok(())
+		})?;
+		PendingTransfers::<T>::remove((collection_id, nft_id));
+		Approvals::<T>::remove((collection_id, nft_id));
+		Self::clear_pending_replacements(collection_id, nft_id);
+
+		let budget = budget::Value::new(T::NestingBudget::get());
+		Self::update_root_owner((collection_id, nft_id), new_parent, &budget)?;
+
+		// `pallet_uniques` refuses to transfer a locked NFT, the locks are put back afterwards
+		let locks = Locks::<T>::take((collection_id, nft_id));
+		pallet_uniques::Pallet::<T>::do_transfer(
+			collection_id,
+			nft_id,
+			new_owner_account.clone(),
+			|_, _| Ok(()),
+		)?;
+		if !locks.is_empty() {
+			Locks::<T>::insert((collection_id, nft_id), locks);
+		}
+
+		Ok(new_owner_account)
+	}
+
+	/// Check whether a returned NFT can go back into its previous parent NFT: the parent must
+	/// still exist, still be owned by the sender, and not be a descendant of the returned NFT.
+	/// The sender must also be able to pay the deposit of the child entry.
+	fn can_return_into(
+		sender: &T::AccountId,
+		parent: (T::CollectionId, T::ItemId),
+		nft: (T::CollectionId, T::ItemId),
+	) -> bool {
+		if parent == nft ||
+			Self::is_x_descendent_of_y(parent.0, parent.1, nft.0, nft.1) ||
+			DestroyingCollections::<T>::contains_key(parent.0)
+		{
+			return false
+		}
+		let deposit = Self::storage_deposit(Children::<T>::hashed_key_for(parent, nft).len(), 0);
+		if !<T as pallet_uniques::Config>::Currency::can_reserve(sender, deposit) {
+			return false
+		}
+		let budget = budget::Value::new(T::NestingBudget::get());
+		match Self::lookup_root_owner(parent.0, parent.1, &budget) {
+			Ok((root_owner, _)) => root_owner == *sender,
+			Err(_) => false,
+		}
+	}
+
+	/// Transfer the issuership of a collection to `new_issuer` in both RMRK storage and
+	/// `pallet_uniques`, along with the collection deposit. A pending issuer proposal is
+	/// removed.
+	///
+	/// Output:
+	/// - The previous issuer of the collection
+	pub fn do_transfer_issuer(
+		collection_id: T::CollectionId,
+		new_issuer: T::AccountId,
+	) -> Result<T::AccountId, DispatchError> {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		PendingIssuer::<T>::remove(collection_id);
+
+		// Keep the pallet_uniques collection owner in sync with the RMRK issuer
+		let old_issuer = collection.issuer;
+		if old_issuer != new_issuer {
+			let old_issuer_origin =
+				T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(old_issuer.clone()));
+			let new_issuer_origin =
+				T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(new_issuer.clone()));
+			pallet_uniques::Pallet::<T>::set_accept_ownership(
+				new_issuer_origin,
+				Some(collection_id),
+			)?;
+			pallet_uniques::Pallet::<T>::transfer_ownership(
+				old_issuer_origin,
+				collection_id,
+				T::Lookup::unlookup(new_issuer.clone()),
+			)?;
+		}
+
+		Self::collection_change_issuer(collection_id, new_issuer)?;
+		Ok(old_issuer)
+	}
+
+	/// Approve `delegate` to transfer an NFT on behalf of its root owner
+	pub fn nft_approve_transfer(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		delegate: T::AccountId,
+		expires: Option<T::BlockNumber>,
+	) -> DispatchResult {
+		let budget = budget::Value::new(T::NestingBudget::get());
+		let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id, &budget)?;
+		ensure!(sender == root_owner, Error::<T>::NoPermission);
+		if let Some(expires) = expires {
+			ensure!(
+				expires > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::ApprovalExpired
+			);
+		}
+
+		Approvals::<T>::insert(
+			(collection_id, nft_id),
+			TransferApproval { owner: root_owner, delegate: delegate.clone(), expires },
+		);
+
+		Self::deposit_event(Event::TransferApproved {
+			owner: sender,
+			collection_id,
+			nft_id,
+			delegate,
+			expires,
+		});
+		Ok(())
+	}
+
+	/// Cancel the transfer approval of an NFT, either by its root owner or by the delegate
+	pub fn nft_cancel_approval(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+	) -> DispatchResult {
+		let approval =
+			Approvals::<T>::get((collection_id, nft_id)).ok_or(Error::<T>::NoApproval)?;
+		let budget = budget::Value::new(T::NestingBudget::get());
+		let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id, &budget)?;
+		ensure!(sender == root_owner || sender == approval.delegate, Error::<T>::NoPermission);
+
+		Approvals::<T>::remove((collection_id, nft_id));
+
+		Self::deposit_event(Event::ApprovalCancelled {
+			owner: root_owner,
+			collection_id,
+			nft_id,
+			delegate: approval.delegate,
+		});
+		Ok(())
+	}
+
+	/// Check whether `sender` can transfer an NFT of `root_owner`, either as the root owner
+	/// itself, as an operator of the root owner or as the approved delegate of the NFT
+	pub fn is_approved_sender(
+		sender: &T::AccountId,
+		root_owner: &T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+	) -> bool {
+		if sender == root_owner || Operators::<T>::contains_key(root_owner, sender) {
+			return true
+		}
+		match Approvals::<T>::get((collection_id, nft_id)) {
+			// Approvals given by a previous root owner are void
+			Some(approval) =>
+				approval.owner == *root_owner &&
+					approval.delegate == *sender &&
+					approval.expires.map_or(true, |expires| {
+						expires > <frame_system::Pallet<T>>::block_number()
+					}),
+			None => false,
+		}
+	}
+
+	/// Change the transferability of an NFT, by the collection issuer if the collection policy
+	/// allows it
+	pub fn nft_set_transferable(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		transferable: bool,
+	) -> DispatchResult {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+		ensure!(collection.policy.transferability_mutable, Error::<T>::TransferabilityImmutable);
+
+		Nfts::<T>::try_mutate(collection_id, nft_id, |nft| -> DispatchResult {
+			let nft = nft.as_mut().ok_or(Error::<T>::NoAvailableNftId)?;
+			nft.transferable = transferable;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::NFTTransferabilitySet {
+			issuer: sender,
+			collection_id,
+			nft_id,
+			transferable,
+		});
+		Ok(())
+	}
+
+	/// Burn a non-transferable NFT and its children, by the collection issuer if the
+	/// collection policy allows it
+	pub fn nft_revoke(
+		sender: T::AccountId,
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+	) -> DispatchResultWithPostInfo {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+		ensure!(collection.policy.revocable, Error::<T>::CollectionNotRevocable);
+		let nft = Self::nfts(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
+		ensure!(!nft.transferable, Error::<T>::CannotRevokeTransferable);
+
+		let budget = budget::Value::new(T::NestingBudget::get());
+		let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id, &budget)?;
+		Self::nft_burn(root_owner, collection_id, nft_id, &budget)?;
+
+		Self::deposit_event(Event::NFTRevoked { issuer: sender, collection_id, nft_id });
+
+		Ok(Some(<T as pallet::Config>::WeightInfo::revoke(
+			budget.get_budget_consumed_value(),
+			T::PropertiesLimit::get(),
+		))
+		.into())
+	}
+
+	/// Set the block at which an NFT expires, the NFT is burned in `on_idle` once expired
+	pub fn set_nft_expiry(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		expires_at: T::BlockNumber,
+	) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(expires_at > now, Error::<T>::InvalidExpiry);
+		NftExpiry::<T>::insert(collection_id, nft_id, expires_at);
+		ExpiryQueue::<T>::insert(expires_at, (collection_id, nft_id), ());
+		// Start processing the queue from the first expiring NFT
+		if !ExpiryCursor::<T>::exists() {
+			ExpiryCursor::<T>::put(now);
+		}
+		Ok(())
+	}
+
+	/// Check whether an NFT has reached its expiry
+	pub fn is_expired(collection_id: T::CollectionId, nft_id: T::ItemId) -> bool {
+		NftExpiry::<T>::get(collection_id, nft_id)
+			.map_or(false, |expires_at| expires_at <= <frame_system::Pallet<T>>::block_number())
+	}
+
+	/// Helper function for checking that an NFT hasn't expired
+	pub fn ensure_not_expired(collection_id: T::CollectionId, nft_id: T::ItemId) -> DispatchResult {
+		ensure!(!Self::is_expired(collection_id, nft_id), Error::<T>::NftExpired);
+		Ok(())
+	}
+
+	/// Burn the expired NFTs of `ExpiryQueue`, from the block of `ExpiryCursor` up to `now`,
+	/// within `remaining_weight`. Blocks that couldn't be processed are resumed in the next
+	/// call.
+	///
+	/// Output:
+	/// - The weight consumed
+	pub fn burn_expired_nfts(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading and writing the cursor
+		let mut consumed_weight = db_weight.reads_writes(1, 1);
+		if consumed_weight.any_gt(remaining_weight) {
+			return Weight::zero()
+		}
+		let step_weight = db_weight.reads_writes(1, 1);
+		// Reading the locks, resources count and children of each NFT of the tree
+		let walk_weight =
+			db_weight.reads(3u64.saturating_mul(T::NestingBudget::get().saturating_add(1).into()));
+
+		let mut cursor = match ExpiryCursor::<T>::get() {
+			Some(cursor) => cursor,
+			// No NFT has ever been given an expiry
+			None => return db_weight.reads(1),
+		};
+		while cursor <= now {
+			if consumed_weight.saturating_add(step_weight).any_gt(remaining_weight) {
+				break
+			}
+			consumed_weight.saturating_accrue(step_weight);
+			match ExpiryQueue::<T>::iter_key_prefix(cursor).next() {
+				Some((collection_id, nft_id)) => {
+					if consumed_weight.saturating_add(walk_weight).any_gt(remaining_weight) {
+						break
+					}
+					consumed_weight.saturating_accrue(walk_weight);
+					let budget = budget::Value::new(T::NestingBudget::get());
+					let burned = if !Self::expired_at(collection_id, nft_id, now) {
+						// The NFT was burned by its owner since it was queued
+						true
+					} else if let Some(resources) =
+						Self::unlocked_tree_resources((collection_id, nft_id), &budget)
+					{
+						let burn_weight = <T as pallet::Config>::WeightInfo::burn_nft(
+							T::NestingBudget::get(),
+							T::PropertiesLimit::get(),
+							resources,
+						);
+						if consumed_weight.saturating_add(burn_weight).any_gt(remaining_weight) {
+							break
+						}
+						consumed_weight.saturating_accrue(burn_weight);
+						Self::burn_expired_nft(collection_id, nft_id)
+					} else {
+						// Locked NFTs, e.g. listed on the market, are kept until they are unlocked
+						false
+					};
+					ExpiryQueue::<T>::remove(cursor, (collection_id, nft_id));
+					if !burned {
+						// Retry from the next block, e.g. once the NFT is unlocked or nested less
+						// deep
+						ExpiryQueue::<T>::insert(
+							now.saturating_add(One::one()),
+							(collection_id, nft_id),
+							(),
+						);
+					}
+				},
+				None => cursor.saturating_inc(),
+			}
+		}
+		ExpiryCursor::<T>::put(cursor);
+
+		consumed_weight
+	}
+
+	/// Remove a resource of a burned NFT, along with its equippable bases and slots, calling
+	/// the resource removal hooks around it
+	fn remove_burned_resource(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		T::NftHooks::pre_resource_remove(&collection_id, &nft_id, &resource_id)?;
+		Self::clear_burned_resource(collection_id, nft_id, resource_id);
+		T::NftHooks::post_resource_remove(&collection_id, &nft_id, &resource_id)
+	}
+
+	/// Remove the storage of a resource of a burned NFT, along with its equippable bases and
+	/// slots
+	fn clear_burned_resource(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+	) {
+		Self::release_storage_deposit(&Resources::<T>::hashed_key_for((
+			collection_id,
+			nft_id,
+			resource_id,
+		)));
+		ProposedResources::<T>::remove((collection_id, nft_id, resource_id));
+		Self::take_pending_replacement(collection_id, nft_id, resource_id);
+		if let Some(res) = Resources::<T>::take((collection_id, nft_id, resource_id)) {
+			Self::remove_equippable_entries(collection_id, nft_id, resource_id, &res.resource);
+		}
+	}
+
+	/// Whether a burned NFT still has storage to be removed
+	fn has_leftover_storage(collection_id: T::CollectionId, nft_id: T::ItemId) -> bool {
+		Properties::<T>::iter_key_prefix((collection_id, Some(nft_id))).next().is_some() ||
+			Resources::<T>::iter_key_prefix((collection_id, nft_id)).next().is_some() ||
+			EquippableBases::<T>::iter_key_prefix((collection_id, nft_id)).next().is_some() ||
+			EquippableSlots::<T>::iter_key_prefix((collection_id, nft_id)).next().is_some() ||
+			Priorities::<T>::iter_key_prefix((collection_id, nft_id)).next().is_some() ||
+			ContextPriorities::<T>::iter_key_prefix((collection_id, nft_id))
+				.next()
+				.is_some() || PendingResourceExpiry::<T>::iter_key_prefix((collection_id, nft_id))
+			.next()
+			.is_some()
+	}
+
+	/// Queue a burned NFT for its remaining storage to be removed in `on_idle`
+	fn queue_cleanup(collection_id: T::CollectionId, nft_id: T::ItemId) {
+		CleanupCursor::<T>::mutate(|(_, tail)| {
+			CleanupQueue::<T>::insert(*tail, (collection_id, nft_id));
+			tail.saturating_inc();
+		});
+		PendingCleanup::<T>::insert(collection_id, nft_id, ());
+	}
+
+	/// Remove a single storage entry left by a burned NFT, refunding its deposit.
+	/// Returns `false` once there is nothing left to remove.
+	pub fn cleanup_burned_nft_entry(collection_id: T::CollectionId, nft_id: T::ItemId) -> bool {
+		if let Some(key) = Properties::<T>::iter_key_prefix((collection_id, Some(nft_id))).next() {
+			Self::release_storage_deposit(&Properties::<T>::hashed_key_for((
+				&collection_id,
+				Some(nft_id),
+				&key,
+			)));
+			Properties::<T>::remove((&collection_id, Some(nft_id), &key));
+		} else if let Some(resource_id) =
+			Resources::<T>::iter_key_prefix((collection_id, nft_id)).next()
+		{
+			// A failing hook can't keep the storage of a burned NFT around
+			if with_storage_layer(|| {
+				Self::remove_burned_resource(collection_id, nft_id, resource_id)
+			})
+			.is_err()
+			{
+				Self::clear_burned_resource(collection_id, nft_id, resource_id);
+			}
+		} else if let Some(base_id) =
+			EquippableBases::<T>::iter_key_prefix((collection_id, nft_id)).next()
+		{
+			EquippableBases::<T>::remove((collection_id, nft_id, base_id));
+		} else if let Some((resource_id, base_id, slot_id)) =
+			EquippableSlots::<T>::iter_key_prefix((collection_id, nft_id)).next()
+		{
+			EquippableSlots::<T>::remove((collection_id, nft_id, resource_id, base_id, slot_id));
+		} else if let Some(resource_id) =
+			Priorities::<T>::iter_key_prefix((collection_id, nft_id)).next()
+		{
+			Self::release_storage_deposit(&Priorities::<T>::hashed_key_for((
+				collection_id,
+				nft_id,
+				resource_id,
+			)));
+			Priorities::<T>::remove((collection_id, nft_id, resource_id));
+		} else if let Some(context) =
+			ContextPriorities::<T>::iter_key_prefix((collection_id, nft_id)).next()
+		{
+			Self::release_storage_deposit(&ContextPriorities::<T>::hashed_key_for((
+				collection_id,
+				nft_id,
+				&context,
+			)));
+			ContextPriorities::<T>::remove((collection_id, nft_id, &context));
+		} else if let Some(resource_id) =
+			PendingResourceExpiry::<T>::iter_key_prefix((collection_id, nft_id)).next()
+		{
+			PendingResourceExpiry::<T>::remove((collection_id, nft_id, resource_id));
+		} else {
+			return false
+		}
+		true
+	}
+
+	/// Remove the storage left by burned NFTs, one entry at a time, in the order they were
+	/// burned. Returns the consumed weight, which never exceeds `remaining_weight`.
+	pub fn cleanup_burned_nfts(remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading and writing the cursor
+		let mut consumed_weight = db_weight.reads_writes(1, 1);
+		if consumed_weight.any_gt(remaining_weight) {
+			return Weight::zero()
+		}
+		let (mut head, tail) = CleanupCursor::<T>::get();
+		if head == tail {
+			// Nothing is queued
+			return db_weight.reads(1)
+		}
+		let step_weight = db_weight.reads_writes(1, 1);
+		let entry_weight = <T as pallet::Config>::WeightInfo::cleanup_burned_nft_entry();
+
+		while head < tail {
+			if consumed_weight.saturating_add(step_weight).any_gt(remaining_weight) {
+				break
+			}
+			consumed_weight.saturating_accrue(step_weight);
+			let (collection_id, nft_id) = match CleanupQueue::<T>::get(head) {
+				Some(nft) => nft,
+				None => {
+					head.saturating_inc();
+					continue
+				},
+			};
+			loop {
+				if consumed_weight.saturating_add(entry_weight).any_gt(remaining_weight) {
+					CleanupCursor::<T>::put((head, tail));
+					return consumed_weight
+				}
+				consumed_weight.saturating_accrue(entry_weight);
+				if !Self::cleanup_burned_nft_entry(collection_id, nft_id) {
+					break
+				}
+			}
+			CleanupQueue::<T>::remove(head);
+			PendingCleanup::<T>::remove(collection_id, nft_id);
+			Self::deposit_event(Event::BurnedNftCleanedUp { collection_id, nft_id });
+			head.saturating_inc();
+		}
+		CleanupCursor::<T>::put((head, tail));
+
+		consumed_weight
+	}
+
+	/// Count the resources of an NFT and of its descendants, to weigh burning them. `None` if one
+	/// of them is locked, or if the tree is larger than the budget.
+	fn unlocked_tree_resources(
+		nft: (T::CollectionId, T::ItemId),
+		budget: &dyn Budget,
+	) -> Option<u32> {
+		if Self::is_locked(nft.0, nft.1) {
+			return None
+		}
+		let mut resources =
+			ResourcesCount::<T>::get(nft.0, nft.1).min(T::MaxResourcesPerNft::get());
+		for child in Children::<T>::iter_key_prefix(nft) {
+			if !budget.consume() {
+				return None
+			}
+			resources = resources.saturating_add(Self::unlocked_tree_resources(child, budget)?);
+		}
+		Some(resources)
+	}
+
+	/// Whether an NFT has an expiry reached at `now`
+	fn expired_at(collection_id: T::CollectionId, nft_id: T::ItemId, now: T::BlockNumber) -> bool {
+		NftExpiry::<T>::get(collection_id, nft_id).map_or(false, |expires_at| expires_at <= now)
+	}
+
+	/// Burn an expired NFT along with its children. Nothing is changed if the burn fails, e.g.
+	/// because the NFT is nested too deep.
+	///
+	/// Output:
+	/// - `false` if the burn failed and must be retried
+	fn burn_expired_nft(collection_id: T::CollectionId, nft_id: T::ItemId) -> bool {
+		with_storage_layer(|| -> DispatchResult {
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (owner, _) = Self::lookup_root_owner(collection_id, nft_id, &budget)?;
+			Self::nft_burn(owner.clone(), collection_id, nft_id, &budget).map_err(|e| e.error)?;
+			Self::deposit_event(Event::NFTExpired { owner, collection_id, nft_id });
+			Ok(())
+		})
+		.is_ok()
+	}
+
+	// Check NFT is not equipped
+	pub fn check_is_not_equipped(nft: &InstanceInfoOf<T>) -> DispatchResult {
+		ensure!(nft.equipped.is_none(), Error::<T>::CannotSendEquippedItem);
 		Ok(())
 	}
 
@@ -1036,21 +3048,69 @@ impl<T: Config> Pallet<T> {
 		collection_id: T::CollectionId,
 		nft_id: T::ItemId,
 		resource_id: ResourceId,
-	) -> () {
-		match res.resource {
-			ResourceTypes::Basic(_r) => {
-				Resources::<T>::remove((collection_id, nft_id, resource_id));
+	) -> DispatchResult {
+		// Lets other pallets, e.g. rmrk-equip, clean up their state while the resource exists
+		T::NftHooks::pre_resource_remove(&collection_id, &nft_id, &resource_id)?;
+		Self::remove_priority(collection_id, nft_id, resource_id);
+		ProposedResources::<T>::remove((collection_id, nft_id, resource_id));
+		Self::take_pending_replacement(collection_id, nft_id, resource_id);
+		ResourcesCount::<T>::mutate(collection_id, nft_id, |count| {
+			*count = count.saturating_sub(1)
+		});
+		Self::release_storage_deposit(&Resources::<T>::hashed_key_for((
+			collection_id,
+			nft_id,
+			resource_id,
+		)));
+		Self::remove_equippable_entries(collection_id, nft_id, resource_id, &res.resource);
+		Resources::<T>::remove((collection_id, nft_id, resource_id));
+		Ok(())
+	}
+
+	/// Index the bases and slots a resource can be equipped into
+	fn insert_equippable_entries(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+		resource: &ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
+	) {
+		match resource {
+			ResourceTypes::Basic(_) | ResourceTypes::Media(_) => (),
+			ResourceTypes::Composable(r) => {
+				EquippableBases::<T>::insert((collection_id, nft_id, r.base), ());
+				if let Some((base, slot)) = r.slot {
+					EquippableSlots::<T>::insert(
+						(collection_id, nft_id, resource_id, base, slot),
+						(),
+					);
+				}
+			},
+			ResourceTypes::Slot(r) => {
+				EquippableSlots::<T>::insert(
+					(collection_id, nft_id, resource_id, r.base, r.slot),
+					(),
+				);
 			},
+		}
+	}
+
+	/// Drop the bases and slots of a resource from the equippable indexes
+	fn remove_equippable_entries(
+		collection_id: T::CollectionId,
+		nft_id: T::ItemId,
+		resource_id: ResourceId,
+		resource: &ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
+	) {
+		match resource {
+			ResourceTypes::Basic(_) | ResourceTypes::Media(_) => (),
 			ResourceTypes::Slot(r) => {
 				EquippableSlots::<T>::remove((collection_id, nft_id, resource_id, r.base, r.slot));
-				Resources::<T>::remove((collection_id, nft_id, resource_id));
 			},
 			ResourceTypes::Composable(r) => {
 				EquippableBases::<T>::remove((collection_id, nft_id, r.base));
 				if let Some((base, slot)) = r.slot {
 					EquippableSlots::<T>::remove((collection_id, nft_id, resource_id, base, slot));
 				};
-				Resources::<T>::remove((collection_id, nft_id, resource_id));
 			},
 		}
 	}
@@ -9,7 +9,10 @@
 use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo},
 	ensure,
-	traits::tokens::{nonfungibles::*, Locker},
+	traits::{
+		tokens::{nonfungibles::*, Locker},
+		Currency, LockIdentifier,
+	},
 	transactional, BoundedVec,
 };
 use frame_system::ensure_signed;
@@ -19,15 +22,17 @@ use sp_std::convert::TryInto;
 
 use rmrk_traits::{
 	budget,
-	misc::TransferHooks,
+	misc::NftHooks,
 	primitives::{BaseId, PartId, ResourceId, SlotId},
-	AccountIdOrCollectionNftTuple, BasicResource, Collection, CollectionInfo, ComposableResource,
-	Nft, NftChild, NftInfo, PhantomType, Priority, Property, PropertyInfo, Resource, ResourceInfo,
-	ResourceInfoMin, ResourceTypes, RoyaltyInfo, SlotResource,
+	AccountIdOrCollectionNftTuple, BasicResource, CallGroup, Collection, CollectionInfo,
+	CollectionPolicy, ComposableResource, MediaResource, Nft, NftChild, NftInfo, PausableCall,
+	PhantomType, Priority, Property, PropertyInfo, Resource, ResourceInfo, ResourceInfoMin,
+	ResourceTypes, RoyaltyInfo, SlotResource,
 };
 use sp_std::result::Result;
 
 mod functions;
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -86,7 +91,52 @@ pub type BoundedResourceInfoTypeOf<T> = BoundedVec<
 
 pub type PropertyInfoOf<T> = PropertyInfo<KeyLimitOf<T>, ValueLimitOf<T>>;
 
+pub type BoundedSendBatchOf<T> = BoundedVec<
+	(
+		<T as pallet_uniques::Config>::CollectionId,
+		<T as pallet_uniques::Config>::ItemId,
+		AccountIdOrCollectionNftTuple<
+			<T as frame_system::Config>::AccountId,
+			<T as pallet_uniques::Config>::CollectionId,
+			<T as pallet_uniques::Config>::ItemId,
+		>,
+	),
+	<T as Config>::MaxBatchSends,
+>;
+
+pub type BoundedResourceBatchOf<T> =
+	BoundedVec<<T as pallet_uniques::Config>::ItemId, <T as Config>::MaxBatchResources>;
+
+pub type TransferApprovalOf<T> = TransferApproval<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+pub type BalanceOf<T> = <<T as pallet_uniques::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+pub type PendingTransferOf<T> = PendingTransfer<
+	<T as frame_system::Config>::AccountId,
+	<T as pallet_uniques::Config>::CollectionId,
+	<T as pallet_uniques::Config>::ItemId,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+pub type RootNftOf<T> =
+	RootNft<<T as pallet_uniques::Config>::CollectionId, <T as pallet_uniques::Config>::ItemId>;
+
 pub mod types;
+use types::{PendingTransfer, RootNft, TransferApproval};
+
+/// Call filter rejecting the calls paused with `pause_calls`. Meant to be chained with the
+/// runtime's `BaseCallFilter`, for a runtime call implementing `PausableCall`.
+pub struct CallsNotPaused<T>(sp_std::marker::PhantomData<T>);
+
+/// Adapter using an implementation of the deprecated `TransferHooks` as `NftHooks`, e.g.
+/// `type NftHooks = TransferHooksAdapter<Runtime, MyTransferHooks>;`. A failed check fails the
+/// transfer with `FailedTransferHooksPreCheck` or `FailedTransferHooksPostTransfer`.
+pub struct TransferHooksAdapter<T, H>(sp_std::marker::PhantomData<(T, H)>);
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
@@ -136,6 +186,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxPriorities: Get<u32>;
 
+		/// The maximum number of contexts an NFT can have a priority list for
+		#[pallet::constant]
+		type MaxPriorityContexts: Get<u32>;
+
 		/// The maximum number of properties each can have
 		#[pallet::constant]
 		type PropertiesLimit: Get<u32>;
@@ -144,17 +198,64 @@ pub mod pallet {
 		#[pallet::constant]
 		type NestingBudget: Get<u32>;
 
+		/// The maximum number of NFTs that can be included in a send_batch extrinsic
+		#[pallet::constant]
+		type MaxBatchSends: Get<u32>;
+
+		/// The maximum number of NFTs that can be included in an add_resource_to_many extrinsic
+		#[pallet::constant]
+		type MaxBatchResources: Get<u32>;
+
+		/// The maximum number of NFTs that can be burned by a destroy_collection_items extrinsic
+		#[pallet::constant]
+		type MaxDestroyItems: Get<u32>;
+
+		/// The maximum number of locks an NFT can have at the same time
+		#[pallet::constant]
+		type MaxLocks: Get<u32>;
+
 		type CollectionSymbolLimit: Get<u32>;
 
 		type MaxResourcesOnMint: Get<u32>;
 
+		/// The maximum number of resources an NFT can have, not counting the resources shared
+		/// by its collection
+		#[pallet::constant]
+		type MaxResourcesPerNft: Get<u32>;
+
+		/// The maximum number of resources proposed by the root owner of an NFT that can wait for
+		/// the approval of the collection issuer at the same time. Proposed resources count
+		/// towards `MaxResourcesPerNft` as well.
+		#[pallet::constant]
+		type MaxProposalsPerNft: Get<u32>;
+
+		/// The maximum number of resources of an NFT that can have a replacement waiting for the
+		/// acceptance of the root owner at the same time
+		#[pallet::constant]
+		type MaxReplacementsPerNft: Get<u32>;
+
+		/// The maximum number of resources a collection can share with its NFTs
+		#[pallet::constant]
+		type MaxCollectionResources: Get<u32>;
+
+		/// The maximum number of NFTs a collection can have for one of its shared resources to
+		/// be removed, as the resource is removed from each of its NFTs
+		#[pallet::constant]
+		type MaxCollectionResourceNfts: Get<u32>;
+
+		/// The deposit reserved per byte of storage taken by resources, properties, children
+		/// and priorities. The deposit is refunded when the entry is removed.
+		#[pallet::constant]
+		type StorageDepositPerByte: Get<BalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
 		#[cfg(feature = "runtime-benchmarks")]
 		type Helper: BenchmarkHelper<Self::CollectionId, Self::ItemId>;
 
-		type TransferHooks: TransferHooks<Self::AccountId, Self::CollectionId, Self::ItemId>;
+		/// Hooks called along the lifecycle of NFTs, e.g. on mint, transfer or burn
+		type NftHooks: NftHooks<Self::AccountId, Self::CollectionId, Self::ItemId>;
 	}
 
 	#[pallet::storage]
@@ -205,6 +306,13 @@ pub mod pallet {
 		(),
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn root_owner)]
+	/// Stores the root NFT of every nested NFT, along with its depth in the tree.
+	/// The root owner of a nested NFT is the owner of its root NFT.
+	pub type RootOwner<T: Config> =
+		StorageMap<_, Twox64Concat, (T::CollectionId, T::ItemId), RootNftOf<T>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn resources)]
 	/// Stores resource info
@@ -219,6 +327,32 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn next_resource_id)]
+	/// Stores the next ID assigned to a resource of an NFT when none is provided.
+	pub type NextResourceId<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::CollectionId,
+		Twox64Concat,
+		T::ItemId,
+		ResourceId,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn resources_count)]
+	/// Stores the number of resources of an NFT, bounded by `MaxResourcesPerNft`.
+	pub type ResourcesCount<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::CollectionId,
+		Twox64Concat,
+		T::ItemId,
+		u32,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn equippable_bases)]
 	/// Stores the existence of a base for a particular NFT
@@ -267,10 +401,219 @@ pub mod pallet {
 	>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn lock)]
-	/// Lock for NFTs
-	pub type Lock<T: Config> =
-		StorageMap<_, Twox64Concat, (T::CollectionId, T::ItemId), bool, ValueQuery>;
+	#[pallet::getter(fn locks)]
+	/// Locks of NFTs, one per lock reason. An NFT is locked while it has any lock.
+	pub type Locks<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		(T::CollectionId, T::ItemId),
+		BoundedVec<LockIdentifier, T::MaxLocks>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_issuer)]
+	/// Stores the account proposed as the next issuer of a collection.
+	/// The proposed account must accept before issuership is transferred.
+	pub type PendingIssuer<T: Config> =
+		StorageMap<_, Twox64Concat, T::CollectionId, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn destroying_collections)]
+	/// Stores the collections that are being destroyed.
+	/// NFTs of these collections can't be minted or transferred.
+	pub type DestroyingCollections<T: Config> =
+		StorageMap<_, Twox64Concat, T::CollectionId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn approvals)]
+	/// Stores the account approved to transfer an NFT on behalf of its root owner.
+	/// The approval is cleared when the NFT is transferred or burned.
+	pub type Approvals<T: Config> =
+		StorageMap<_, Twox64Concat, (T::CollectionId, T::ItemId), TransferApprovalOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_transfers)]
+	/// Stores the sender of NFTs waiting to be accepted and the optional block
+	/// from which the pending transfer can be expired.
+	pub type PendingTransfers<T: Config> =
+		StorageMap<_, Twox64Concat, (T::CollectionId, T::ItemId), PendingTransferOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_resource_expiry)]
+	/// Stores the block from which a pending resource addition or removal can be expired.
+	pub type PendingResourceExpiry<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, ResourceId>,
+		),
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn proposed_resources)]
+	/// Stores the account that proposed a pending resource, for resources proposed by the
+	/// root owner of an NFT and waiting for the approval of the collection issuer.
+	pub type ProposedResources<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, ResourceId>,
+		),
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_replacements)]
+	/// Stores the replacement proposed by the collection issuer for a resource of an NFT it
+	/// doesn't own, until the root owner of the NFT accepts or rejects it.
+	pub type PendingReplacements<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, ResourceId>,
+		),
+		ResourceTypes<StringLimitOf<T>, BoundedVec<PartId, T::PartsLimit>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn operators)]
+	/// Stores the operators allowed to transfer all of the NFTs of an account.
+	pub type Operators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn nft_expiry)]
+	/// Stores the block at which a time-limited NFT expires.
+	pub type NftExpiry<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::CollectionId,
+		Twox64Concat,
+		T::ItemId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Stores the time-limited NFTs by expiry block, for the NFTs to be burned in `on_idle`.
+	/// NFTs that fail to be burned are queued again for the next block.
+	pub type ExpiryQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Twox64Concat,
+		(T::CollectionId, T::ItemId),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Stores the next block of `ExpiryQueue` to be processed in `on_idle`.
+	pub type ExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn storage_deposits)]
+	/// Stores the account and amount of the deposit held for a storage entry, by hash of the
+	/// storage key of the entry.
+	pub type StorageDeposits<T: Config> =
+		StorageMap<_, Identity, T::Hash, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn paused_calls)]
+	/// Stores the paused call groups, globally with `None` or for a single collection.
+	/// Paused calls are rejected by the `CallsNotPaused` call filter.
+	pub type PausedCalls<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CallGroup,
+		Twox64Concat,
+		Option<T::CollectionId>,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_resources)]
+	/// Stores the resources shared by all of the NFTs of a collection.
+	/// A resource of an NFT in `Resources` with the same ID overrides the shared one.
+	pub type CollectionResources<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		ResourceId,
+		ResourceInfoOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_equippable_bases)]
+	/// Stores the shared composable resources of a collection by base, so that the bases of
+	/// an NFT can be found without iterating through all of the shared resources.
+	pub type CollectionEquippableBases<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, BaseId>,
+			NMapKey<Blake2_128Concat, ResourceId>,
+		),
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn context_priorities)]
+	/// Stores the priority lists of an NFT for named contexts, e.g. "thumbnail" or a theme
+	/// name. Contexts without a list fall back to the order stored in `Priorities`.
+	pub type ContextPriorities<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, KeyLimitOf<T>>,
+		),
+		BoundedVec<ResourceId, T::MaxPriorities>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Stores the burned NFTs that had too much storage to be removed in the burn, in burn
+	/// order. Their remaining storage is removed in `on_idle`.
+	pub type CleanupQueue<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (T::CollectionId, T::ItemId), OptionQuery>;
+
+	#[pallet::storage]
+	/// Stores the index of the next NFT of `CleanupQueue` to be cleaned up, and the index the
+	/// next burned NFT is queued at.
+	pub type CleanupCursor<T: Config> = StorageValue<_, (u32, u32), ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_cleanup)]
+	/// Stores the burned NFTs waiting in `CleanupQueue`. They can't be minted again until
+	/// their storage is removed.
+	pub type PendingCleanup<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::CollectionId,
+		Twox64Concat,
+		T::ItemId,
+		(),
+		OptionQuery,
+	>;
 
 	/// This storage is not used by the chain.
 	/// It is need only for PolkadotJS types generation.
@@ -284,10 +627,26 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Burn the NFTs that expired, as long as there is weight left in the block
+		/// Then remove the remaining storage of burned NFTs with the weight left.
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let consumed_weight = Self::burn_expired_nfts(now, remaining_weight);
+			consumed_weight.saturating_add(Self::cleanup_burned_nfts(
+				remaining_weight.saturating_sub(consumed_weight),
+			))
+		}
+	}
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
 	#[pallet::event]
@@ -311,6 +670,15 @@ pub mod pallet {
 			issuer: T::AccountId,
 			collection_id: T::CollectionId,
 		},
+		CollectionDestroyStarted {
+			issuer: T::AccountId,
+			collection_id: T::CollectionId,
+		},
+		CollectionItemsDestroyed {
+			collection_id: T::CollectionId,
+			destroyed: u32,
+			remaining: u32,
+		},
 		NFTSent {
 			sender: T::AccountId,
 			recipient: AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
@@ -328,12 +696,23 @@ pub mod pallet {
 			sender: T::AccountId,
 			collection_id: T::CollectionId,
 			nft_id: T::ItemId,
+			burned: bool,
 		},
 		IssuerChanged {
 			old_issuer: T::AccountId,
 			new_issuer: T::AccountId,
 			collection_id: T::CollectionId,
 		},
+		IssuerProposed {
+			issuer: T::AccountId,
+			proposed_issuer: T::AccountId,
+			collection_id: T::CollectionId,
+		},
+		IssuerProposalCancelled {
+			issuer: T::AccountId,
+			proposed_issuer: T::AccountId,
+			collection_id: T::CollectionId,
+		},
 		PropertySet {
 			collection_id: T::CollectionId,
 			maybe_nft_id: Option<T::ItemId>,
@@ -353,6 +732,15 @@ pub mod pallet {
 			issuer: T::AccountId,
 			collection_id: T::CollectionId,
 		},
+		CollectionUnlocked {
+			issuer: T::AccountId,
+			collection_id: T::CollectionId,
+		},
+		CollectionMaxSet {
+			issuer: T::AccountId,
+			collection_id: T::CollectionId,
+			max: Option<u32>,
+		},
 		ResourceAdded {
 			nft_id: T::ItemId,
 			resource_id: ResourceId,
@@ -363,11 +751,37 @@ pub mod pallet {
 			resource_id: ResourceId,
 			collection_id: T::CollectionId,
 		},
+		ResourceReplacementProposed {
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+			collection_id: T::CollectionId,
+		},
+		ResourceReplacementAccepted {
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+			collection_id: T::CollectionId,
+		},
+		ResourceReplacementRejected {
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+			collection_id: T::CollectionId,
+		},
 		ResourceAccepted {
 			nft_id: T::ItemId,
 			resource_id: ResourceId,
 			collection_id: T::CollectionId,
 		},
+		ResourceProposed {
+			proposer: T::AccountId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+			collection_id: T::CollectionId,
+		},
+		ResourceProposalRejected {
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+			collection_id: T::CollectionId,
+		},
 		ResourceRemoval {
 			nft_id: T::ItemId,
 			resource_id: ResourceId,
@@ -382,48 +796,203 @@ pub mod pallet {
 			collection_id: T::CollectionId,
 			nft_id: T::ItemId,
 		},
-	}
-
-	// Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Error names should be descriptive.
-		NoneValue,
-		/// Errors should have helpful documentation associated with them.
-		StorageOverflow,
-		TooLong,
-		NoAvailableCollectionId,
-		NoAvailableResourceId,
-		MetadataNotSet,
-		RecipientNotSet,
-		NoAvailableNftId,
-		NotInRange,
-		RoyaltyNotSet,
-		CollectionUnknown,
-		NoPermission,
-		NoWitness,
-		CollectionNotEmpty,
-		CollectionFullOrLocked,
-		CannotSendToDescendentOrSelf,
-		ResourceAlreadyExists,
-		NftAlreadyExists,
-		EmptyResource,
-		/// The recursion limit has been reached.
-		TooManyRecursions,
-		NftIsLocked,
-		CannotAcceptNonOwnedNft,
-		CannotRejectNonOwnedNft,
-		CannotRejectNonPendingNft,
-		ResourceDoesntExist,
-		/// Accepting a resource that is not pending should fail
-		ResourceNotPending,
-		NonTransferable,
-		// Must unequip an item before sending (this only applies to the
-		// rmrk-equip pallet but the send operation lives in rmrk-core)
+		TransferApproved {
+			owner: T::AccountId,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			delegate: T::AccountId,
+			expires: Option<T::BlockNumber>,
+		},
+		ApprovalCancelled {
+			owner: T::AccountId,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			delegate: T::AccountId,
+		},
+		OperatorSet {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			approved: bool,
+		},
+		PendingExpirySet {
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			maybe_resource_id: Option<ResourceId>,
+			expires: T::BlockNumber,
+		},
+		PendingNftExpired {
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			returned_to: AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
+		},
+		PendingResourceExpired {
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+		},
+		NFTTransferabilitySet {
+			issuer: T::AccountId,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			transferable: bool,
+		},
+		NFTRevoked {
+			issuer: T::AccountId,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+		},
+		NFTExpired {
+			owner: T::AccountId,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+		},
+		NFTUnlocked {
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			id: LockIdentifier,
+		},
+		CallsPaused {
+			group: CallGroup,
+			collection_id: Option<T::CollectionId>,
+		},
+		CallsUnpaused {
+			group: CallGroup,
+			collection_id: Option<T::CollectionId>,
+		},
+		CollectionResourceAdded {
+			collection_id: T::CollectionId,
+			resource_id: ResourceId,
+		},
+		CollectionResourceRemoved {
+			collection_id: T::CollectionId,
+			resource_id: ResourceId,
+		},
+		BurnedNftCleanedUp {
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+		},
+		ContextPrioritySet {
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			context: KeyLimitOf<T>,
+		},
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Error names should be descriptive.
+		NoneValue,
+		/// Errors should have helpful documentation associated with them.
+		StorageOverflow,
+		TooLong,
+		NoAvailableCollectionId,
+		NoAvailableResourceId,
+		MetadataNotSet,
+		RecipientNotSet,
+		NoAvailableNftId,
+		NotInRange,
+		RoyaltyNotSet,
+		CollectionUnknown,
+		NoPermission,
+		NoWitness,
+		CollectionNotEmpty,
+		CollectionFullOrLocked,
+		CannotSendToDescendentOrSelf,
+		ResourceAlreadyExists,
+		NftAlreadyExists,
+		EmptyResource,
+		/// The recursion limit has been reached.
+		TooManyRecursions,
+		NftIsLocked,
+		CannotAcceptNonOwnedNft,
+		CannotRejectNonOwnedNft,
+		CannotRejectNonPendingNft,
+		ResourceDoesntExist,
+		/// Accepting a resource that is not pending should fail
+		ResourceNotPending,
+		NonTransferable,
+		// Must unequip an item before sending (this only applies to the
+		// rmrk-equip pallet but the send operation lives in rmrk-core)
 		CannotSendEquippedItem,
 		CannotAcceptToNewOwner,
+		/// The pre-check of `TransferHooksAdapter` rejected the transfer
 		FailedTransferHooksPreCheck,
+		/// The post-transfer check of `TransferHooksAdapter` rejected the transfer
 		FailedTransferHooksPostTransfer,
+		/// There is no pending issuer proposal for this collection
+		NoPendingIssuer,
+		/// Collection max can't be lower than the number of NFTs in the collection
+		MaxLowerThanNftsCount,
+		/// The collection is being destroyed
+		CollectionIsDestroying,
+		/// Destruction of the collection has not been started
+		CollectionNotDestroying,
+		/// There is no transfer approval for this NFT
+		NoApproval,
+		/// The expiry of the approval is in the past
+		ApprovalExpired,
+		/// The NFT is not waiting to be accepted
+		NftNotPending,
+		/// No expiry was set for the pending NFT or resource
+		NoPendingExpiry,
+		/// The pending NFT or resource has not expired yet
+		PendingNotExpired,
+		/// The NFT has no known previous owner to go back to
+		UnknownPreviousOwner,
+		/// The NFT already has the maximum number of locks
+		TooManyLocks,
+		/// The collection policy doesn't allow changing the transferability of its NFTs
+		TransferabilityImmutable,
+		/// The collection policy doesn't allow revoking its NFTs
+		CollectionNotRevocable,
+		/// Only non-transferable NFTs can be revoked
+		CannotRevokeTransferable,
+		/// The expiry of an NFT, pending NFT or pending resource must be in the future
+		InvalidExpiry,
+		/// The NFT has expired and can only be burned
+		NftExpired,
+		/// The call group is already paused for this scope
+		CallsAlreadyPaused,
+		/// The call group is not paused for this scope
+		CallsNotPaused,
+		/// The collection still has shared resources, which must be removed first
+		CollectionHasResources,
+		/// The NFT already has the maximum number of resources
+		TooManyResources,
+		/// The storage of a previous NFT with this ID is still being removed
+		NftPendingCleanup,
+		/// A resource of the priority list is pending
+		ResourcePending,
+		/// A resource appears more than once in the priority list
+		DuplicatePriority,
+		/// The resource isn't in the priorities of the NFT
+		ResourceNotInPriorities,
+		/// The index is past the end of the priorities of the NFT
+		InvalidPriorityIndex,
+		/// The resource wasn't proposed by the owner of the NFT
+		ResourceNotProposed,
+		/// The resource was proposed by the owner of the NFT and must be approved by the issuer
+		ResourceAwaitingApproval,
+		/// The resource has no pending replacement
+		NoPendingReplacement,
+		/// The collection still has properties, which must be removed first with
+		/// `destroy_collection_items`
+		CollectionHasProperties,
+		/// The NFT already has a priority list for the maximum number of contexts
+		TooManyPriorityContexts,
+		/// The collection already shares the maximum number of resources
+		TooManyCollectionResources,
+		/// The collection has too many NFTs for its shared resources to be removed
+		TooManyNftsForResourceRemoval,
+		/// The NFT already has the maximum number of proposed resources
+		TooManyProposals,
+		/// The NFT already has the maximum number of pending resource replacements
+		TooManyReplacements,
+		/// The NFT isn't locked for the given reason
+		LockNotFound,
+		/// A collection can't be unlocked once an NFT was minted in it
+		CollectionAlreadyMinted,
 	}
 
 	#[pallet::call]
@@ -437,6 +1006,7 @@ pub mod pallet {
 		/// - `recipient`: Receiver of the royalty
 		/// - `royalty`: Permillage reward from each trade for the Recipient
 		/// - `metadata`: Arbitrary data about an nft, e.g. IPFS hash
+		/// - `expires_at`: Block at which the nft expires and gets burned, if any
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::mint_nft())]
 		#[transactional]
@@ -450,6 +1020,7 @@ pub mod pallet {
 			metadata: BoundedVec<u8, T::StringLimit>,
 			transferable: bool,
 			resources: Option<BoundedResourceInfoTypeOf<T>>,
+			expires_at: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			if let Some(collection_issuer) =
@@ -479,6 +1050,10 @@ pub mod pallet {
 				resources,
 			)?;
 
+			if let Some(expires_at) = expires_at {
+				Self::set_nft_expiry(collection_id, nft_id, expires_at)?;
+			}
+
 			Ok(())
 		}
 
@@ -491,6 +1066,7 @@ pub mod pallet {
 		/// - `recipient`: Receiver of the royalty
 		/// - `royalty`: Permillage reward from each trade for the Recipient
 		/// - `metadata`: Arbitrary data about an nft, e.g. IPFS hash
+		/// - `expires_at`: Block at which the nft expires and gets burned, if any
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::mint_nft_directly_to_nft(T::NestingBudget::get()))]
 		#[transactional]
@@ -504,6 +1080,7 @@ pub mod pallet {
 			metadata: BoundedVec<u8, T::StringLimit>,
 			transferable: bool,
 			resources: Option<BoundedResourceInfoTypeOf<T>>,
+			expires_at: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin.clone())?;
 
@@ -529,10 +1106,17 @@ pub mod pallet {
 				resources,
 			)?;
 
+			if let Some(expires_at) = expires_at {
+				Self::set_nft_expiry(collection_id, nft_id, expires_at)?;
+			}
+
 			Ok(())
 		}
 
 		/// Create a collection
+		///
+		/// The `policy` declares whether the issuer can later change the transferability of the
+		/// NFTs of the collection and revoke its non-transferable NFTs, it can't be changed.
 		#[pallet::call_index(2)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_collection())]
 		#[transactional]
@@ -542,17 +1126,18 @@ pub mod pallet {
 			metadata: BoundedVec<u8, T::StringLimit>,
 			max: Option<u32>,
 			symbol: BoundedCollectionSymbolOf<T>,
+			policy: CollectionPolicy,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			Self::collection_create(sender, collection_id, metadata, max, symbol)?;
+			Self::collection_create(sender, collection_id, metadata, max, symbol, policy)?;
 
 			Ok(())
 		}
 
 		/// burn nft
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::burn_nft(T::NestingBudget::get(), T::PropertiesLimit::get()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::burn_nft(T::NestingBudget::get(), T::PropertiesLimit::get(), T::MaxResourcesPerNft::get()))]
 		#[transactional]
 		pub fn burn_nft(
 			origin: OriginFor<T>,
@@ -568,8 +1153,11 @@ pub mod pallet {
 		}
 
 		/// destroy collection
+		///
+		/// The collection must have no NFTs left, collections with NFTs can be emptied with
+		/// `start_destroy_collection` and `destroy_collection_items`.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::destroy_collection())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::destroy_collection(T::PropertiesLimit::get()))]
 		#[transactional]
 		pub fn destroy_collection(
 			origin: OriginFor<T>,
@@ -585,25 +1173,34 @@ pub mod pallet {
 		/// Transfers a NFT from an Account or NFT A to another Account or NFT B
 		///
 		/// Parameters:
-		/// - `origin`: sender of the transaction
+		/// - `origin`: sender of the transaction, the root owner, one of its operators or the
+		///   approved delegate of the nft
 		/// - `collection_id`: collection id of the nft to be transferred
 		/// - `nft_id`: nft id of the nft to be transferred
 		/// - `new_owner`: new owner of the nft which can be either an account or a NFT
+		/// - `expires`: block from which the nft can be expired with `expire_pending` if it has to
+		///   be accepted by the new owner, ignored otherwise
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::send_to_account(T::NestingBudget::get()).max(<T as
-		pallet::Config>::WeightInfo::send_to_nft(T::NestingBudget::get())))]
+		pallet::Config>::WeightInfo::send_to_nft(T::NestingBudget::get()))
+		.saturating_add(<T as pallet::Config>::WeightInfo::set_pending_expiry()))]
 		#[transactional]
 		pub fn send(
 			origin: OriginFor<T>,
 			collection_id: T::CollectionId,
 			nft_id: T::ItemId,
 			new_owner: AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
+			expires: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			let (_new_owner_account, _approval_required) =
+			let (_new_owner_account, approval_required) =
 				Self::nft_send(sender.clone(), collection_id, nft_id, new_owner.clone())?;
 
+			if let (true, Some(expires)) = (approval_required, expires) {
+				Self::do_set_pending_expiry(sender, collection_id, nft_id, None, expires)?;
+			}
+
 			Ok(())
 		}
 
@@ -657,6 +1254,8 @@ pub mod pallet {
 		/// - `origin`: sender of the transaction
 		/// - `collection_id`: collection id of the nft to be accepted
 		/// - `nft_id`: nft id of the nft to be accepted
+		/// - `burn`: burn the nft and its children instead of sending them back to the previous
+		///   owner
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_nft(T::NestingBudget::get()))]
 		#[transactional]
@@ -664,48 +1263,15 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			collection_id: T::CollectionId,
 			nft_id: T::ItemId,
+			burn: bool,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
-			Self::nft_reject(sender, collection_id, nft_id)
+			Self::nft_reject(sender, collection_id, nft_id, burn)
 		}
 
-		/// Change the issuer of a collection
-		///
-		/// Parameters:
-		/// - `origin`: sender of the transaction
-		/// - `collection_id`: collection id of the nft to change issuer of
-		/// - `new_issuer`: Collection's new issuer
-		#[pallet::call_index(8)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::change_collection_issuer())]
-		pub fn change_collection_issuer(
-			origin: OriginFor<T>,
-			collection_id: T::CollectionId,
-			new_issuer: <T::Lookup as StaticLookup>::Source,
-		) -> DispatchResult {
-			let sender = ensure_signed(origin.clone())?;
-			let collection =
-				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
-			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
-			let new_owner = T::Lookup::lookup(new_issuer.clone())?;
-
-			ensure!(
-				Collections::<T>::contains_key(collection_id),
-				Error::<T>::NoAvailableCollectionId
-			);
-
-			let (new_owner, collection_id) =
-				Self::collection_change_issuer(collection_id, new_owner)?;
-
-			pallet_uniques::Pallet::<T>::transfer_ownership(origin, collection_id, new_issuer)?;
-
-			Self::deposit_event(Event::IssuerChanged {
-				old_issuer: sender,
-				new_issuer: new_owner,
-				collection_id,
-			});
-			Ok(())
-		}
+		// Call index 8 was used by `change_collection_issuer`, which is replaced by
+		// `propose_issuer` and `accept_issuer`, and must not be reused.
 
 		/// set a custom value on an NFT
 		#[pallet::call_index(9)]
@@ -743,16 +1309,20 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Create basic resource
+		/// Create basic resource. The next free ID of the NFT is used if `maybe_resource_id`
+		/// is `None`. A resource pending the acceptance of the root owner can be given an
+		/// `expires` block from which it can be expired with `expire_pending`.
 		#[pallet::call_index(11)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_basic_resource(T::NestingBudget::get()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_basic_resource(T::NestingBudget::get())
+			.saturating_add(<T as pallet::Config>::WeightInfo::set_pending_expiry()))]
 		#[transactional]
 		pub fn add_basic_resource(
 			origin: OriginFor<T>,
 			collection_id: T::CollectionId,
 			nft_id: T::ItemId,
 			resource: BasicResource<StringLimitOf<T>>,
-			resource_id: ResourceId,
+			maybe_resource_id: Option<ResourceId>,
+			expires: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let collection =
@@ -766,11 +1336,16 @@ pub mod pallet {
 				!Pallet::<T>::is_locked(collection_id, nft_id),
 				pallet_uniques::Error::<T>::Locked
 			);
+			Pallet::<T>::ensure_not_expired(collection_id, nft_id)?;
 
 			let pending = root_owner != sender;
+			let resource_id = match maybe_resource_id {
+				Some(resource_id) => resource_id,
+				None => Self::get_next_resource_id(collection_id, nft_id)?,
+			};
 
 			Self::resource_add(
-				sender,
+				sender.clone(),
 				collection_id,
 				nft_id,
 				ResourceTypes::Basic(resource),
@@ -778,19 +1353,33 @@ pub mod pallet {
 				resource_id,
 			)?;
 
+			if let (true, Some(expires)) = (pending, expires) {
+				Self::do_set_pending_expiry(
+					sender,
+					collection_id,
+					nft_id,
+					Some(resource_id),
+					expires,
+				)?;
+			}
+
 			Ok(())
 		}
 
-		/// Create composable resource
+		/// Create composable resource. The next free ID of the NFT is used if `maybe_resource_id`
+		/// is `None`. A resource pending the acceptance of the root owner can be given an
+		/// `expires` block from which it can be expired with `expire_pending`.
 		#[pallet::call_index(12)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_composable_resource(T::NestingBudget::get()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_composable_resource(T::NestingBudget::get())
+			.saturating_add(<T as pallet::Config>::WeightInfo::set_pending_expiry()))]
 		#[transactional]
 		pub fn add_composable_resource(
 			origin: OriginFor<T>,
 			collection_id: T::CollectionId,
 			nft_id: T::ItemId,
 			resource: ComposableResource<StringLimitOf<T>, BoundedVec<PartId, T::PartsLimit>>,
-			resource_id: ResourceId,
+			maybe_resource_id: Option<ResourceId>,
+			expires: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
@@ -805,11 +1394,16 @@ pub mod pallet {
 				!Pallet::<T>::is_locked(collection_id, nft_id),
 				pallet_uniques::Error::<T>::Locked
 			);
+			Pallet::<T>::ensure_not_expired(collection_id, nft_id)?;
 
 			let pending = root_owner != sender;
+			let resource_id = match maybe_resource_id {
+				Some(resource_id) => resource_id,
+				None => Self::get_next_resource_id(collection_id, nft_id)?,
+			};
 
 			Self::resource_add(
-				sender,
+				sender.clone(),
 				collection_id,
 				nft_id,
 				ResourceTypes::Composable(resource),
@@ -817,19 +1411,33 @@ pub mod pallet {
 				resource_id,
 			)?;
 
+			if let (true, Some(expires)) = (pending, expires) {
+				Self::do_set_pending_expiry(
+					sender,
+					collection_id,
+					nft_id,
+					Some(resource_id),
+					expires,
+				)?;
+			}
+
 			Ok(())
 		}
 
-		/// Create slot resource
+		/// Create slot resource. The next free ID of the NFT is used if `maybe_resource_id`
+		/// is `None`. A resource pending the acceptance of the root owner can be given an
+		/// `expires` block from which it can be expired with `expire_pending`.
 		#[pallet::call_index(13)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_slot_resource(T::NestingBudget::get()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_slot_resource(T::NestingBudget::get())
+			.saturating_add(<T as pallet::Config>::WeightInfo::set_pending_expiry()))]
 		#[transactional]
 		pub fn add_slot_resource(
 			origin: OriginFor<T>,
 			collection_id: T::CollectionId,
 			nft_id: T::ItemId,
 			resource: SlotResource<StringLimitOf<T>>,
-			resource_id: ResourceId,
+			maybe_resource_id: Option<ResourceId>,
+			expires: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let collection =
@@ -843,11 +1451,16 @@ pub mod pallet {
 				!Pallet::<T>::is_locked(collection_id, nft_id),
 				pallet_uniques::Error::<T>::Locked
 			);
+			Pallet::<T>::ensure_not_expired(collection_id, nft_id)?;
 
 			let pending = root_owner != sender;
+			let resource_id = match maybe_resource_id {
+				Some(resource_id) => resource_id,
+				None => Self::get_next_resource_id(collection_id, nft_id)?,
+			};
 
 			Self::resource_add(
-				sender,
+				sender.clone(),
 				collection_id,
 				nft_id,
 				ResourceTypes::Slot(resource),
@@ -855,12 +1468,23 @@ pub mod pallet {
 				resource_id,
 			)?;
 
+			if let (true, Some(expires)) = (pending, expires) {
+				Self::do_set_pending_expiry(
+					sender,
+					collection_id,
+					nft_id,
+					Some(resource_id),
+					expires,
+				)?;
+			}
+
 			Ok(())
 		}
 
-		/// Replace resource by id
+		/// Replace resource by id. On an NFT not owned by the collection issuer, the replacement
+		/// stays pending until the root owner accepts or rejects it.
 		#[pallet::call_index(14)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::replace_resource())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::replace_resource(T::NestingBudget::get()))]
 		#[transactional]
 		pub fn replace_resource(
 			origin: OriginFor<T>,
@@ -868,11 +1492,33 @@ pub mod pallet {
 			nft_id: T::ItemId,
 			resource: ResourceTypes<StringLimitOf<T>, BoundedVec<PartId, T::PartsLimit>>,
 			resource_id: ResourceId,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			Self::resource_replace(sender, collection_id, nft_id, resource, resource_id)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
 
-			Ok(())
+			if root_owner == sender {
+				Self::resource_replace(sender, collection_id, nft_id, resource, resource_id)?;
+			} else {
+				Self::resource_replacement_propose(
+					sender,
+					collection_id,
+					nft_id,
+					resource,
+					resource_id,
+				)?;
+			}
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::replace_resource(
+				budget.get_budget_consumed_value(),
+			))
+			.into())
 		}
 
 		/// accept the addition of a new resource to an existing NFT
@@ -891,6 +1537,12 @@ pub mod pallet {
 			ensure!(owner == sender, Error::<T>::NoPermission);
 			// Check NFT lock status
 			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+			// Resources proposed by the owner are accepted by the issuer instead
+			ensure!(
+				!ProposedResources::<T>::contains_key((collection_id, nft_id, resource_id)),
+				Error::<T>::ResourceAwaitingApproval
+			);
 
 			Self::accept(sender, collection_id, nft_id, resource_id)?;
 
@@ -899,7 +1551,7 @@ pub mod pallet {
 
 		/// remove resource
 		#[pallet::call_index(16)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_resource(T::NestingBudget::get()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_resource(T::NestingBudget::get(), T::MaxPriorities::get(), T::MaxPriorityContexts::get()))]
 		#[transactional]
 		pub fn remove_resource(
 			origin: OriginFor<T>,
@@ -924,7 +1576,7 @@ pub mod pallet {
 
 		/// accept the removal of a resource of an existing NFT
 		#[pallet::call_index(17)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_resource_removal(T::NestingBudget::get()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_resource_removal(T::NestingBudget::get(), T::MaxPriorities::get(), T::MaxPriorityContexts::get()))]
 		#[transactional]
 		pub fn accept_resource_removal(
 			origin: OriginFor<T>,
@@ -958,8 +1610,1005 @@ pub mod pallet {
 			ensure!(sender == root_owner, Error::<T>::NoPermission);
 			// Check NFT lock status
 			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
 
 			Self::priority_set(sender, collection_id, nft_id, priorities)
 		}
+
+		/// Accept a pending issuer proposal. Transfers the issuership of the collection in both
+		/// RMRK storage and `pallet_uniques`.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the proposed issuer
+		/// - `collection_id`: collection id of the collection to become issuer of
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_issuer())]
+		#[transactional]
+		pub fn accept_issuer(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(Self::collections(collection_id).is_some(), Error::<T>::CollectionUnknown);
+			ensure!(
+				!DestroyingCollections::<T>::contains_key(collection_id),
+				Error::<T>::CollectionIsDestroying
+			);
+			let proposed_issuer =
+				Self::pending_issuer(collection_id).ok_or(Error::<T>::NoPendingIssuer)?;
+			ensure!(proposed_issuer == sender, Error::<T>::NoPermission);
+
+			let old_issuer = Self::do_transfer_issuer(collection_id, sender.clone())?;
+
+			Self::deposit_event(Event::IssuerChanged {
+				old_issuer,
+				new_issuer: sender,
+				collection_id,
+			});
+			Ok(())
+		}
+
+		/// Cancel a pending issuer proposal.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the current collection issuer
+		/// - `collection_id`: collection id of the pending proposal
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_issuer_proposal())]
+		pub fn cancel_issuer_proposal(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			let proposed_issuer =
+				PendingIssuer::<T>::take(collection_id).ok_or(Error::<T>::NoPendingIssuer)?;
+
+			Self::deposit_event(Event::IssuerProposalCancelled {
+				issuer: sender,
+				proposed_issuer,
+				collection_id,
+			});
+			Ok(())
+		}
+
+		/// Unlock a locked collection. Only possible before the first NFT of the collection is
+		/// minted, even if all of its NFTs were burned since.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unlock_collection())]
+		#[transactional]
+		pub fn unlock_collection(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			let _collection_id = Self::collection_unlock(sender, collection_id)?;
+
+			Ok(())
+		}
+
+		/// Set the maximum number of NFTs in a collection.
+		/// The max can be lowered down to the current number of NFTs at any time, but it can only
+		/// be raised (or removed) while the collection is not locked.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection id of the collection to update
+		/// - `max`: new maximum number of NFTs, `None` for unlimited
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_collection_max())]
+		#[transactional]
+		pub fn set_collection_max(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			max: Option<u32>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			let _collection_id = Self::collection_set_max(sender, collection_id, max)?;
+
+			Ok(())
+		}
+
+		/// Start the destruction of a collection. Once started, NFTs of the collection can no
+		/// longer be minted or transferred. The remaining NFTs are burned with
+		/// `destroy_collection_items`, then the collection is removed with `destroy_collection`.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection id of the collection to destroy
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::start_destroy_collection())]
+		#[transactional]
+		pub fn start_destroy_collection(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			Self::collection_start_destroy(sender, collection_id)?;
+
+			Ok(())
+		}
+
+		/// Burn up to `limit` NFTs of a collection being destroyed, along with their children of
+		/// the same collection, resources and properties. Children of other collections are
+		/// moved out to the root owner, and locked NFTs must be unlocked first. Once the
+		/// collection has no NFTs left, its own properties are removed. Can be called by anyone
+		/// once the destruction has started.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction
+		/// - `collection_id`: collection id of the collection being destroyed
+		/// - `limit`: maximum number of NFTs to burn, capped at `MaxDestroyItems`
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::destroy_collection_items(
+			(*limit).min(T::MaxDestroyItems::get()),
+			T::NestingBudget::get(),
+			T::PropertiesLimit::get(),
+		))]
+		#[transactional]
+		pub fn destroy_collection_items(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let (destroyed, cleared_properties) =
+				Self::collection_destroy_items(collection_id, limit)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::destroy_collection_items(
+				destroyed,
+				T::NestingBudget::get(),
+				cleared_properties,
+			))
+			.into())
+		}
+
+		/// Approve a delegate to transfer an NFT on behalf of its root owner. A new approval
+		/// overrides the previous one. The approval is cleared once the NFT is transferred.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `delegate`: account allowed to transfer the nft
+		/// - `expires`: optional block number after which the approval can't be used
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_transfer(T::NestingBudget::get()))]
+		#[transactional]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			expires: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			Self::nft_approve_transfer(sender, collection_id, nft_id, delegate, expires)
+		}
+
+		/// Cancel the transfer approval of an NFT.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the root owner of the NFT or the approved
+		///   delegate
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_approval(T::NestingBudget::get()))]
+		#[transactional]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::nft_cancel_approval(sender, collection_id, nft_id)
+		}
+
+		/// Allow or disallow an operator to transfer all of the NFTs of the sender.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction
+		/// - `operator`: account to allow or disallow
+		/// - `approved`: whether the operator is allowed
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_operator())]
+		pub fn set_operator(
+			origin: OriginFor<T>,
+			operator: <T::Lookup as StaticLookup>::Source,
+			approved: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let operator = T::Lookup::lookup(operator)?;
+
+			if approved {
+				Operators::<T>::insert(&sender, &operator, ());
+			} else {
+				Operators::<T>::remove(&sender, &operator);
+			}
+
+			Self::deposit_event(Event::OperatorSet { owner: sender, operator, approved });
+			Ok(())
+		}
+
+		/// Transfers multiple NFTs, each to an Account or to another NFT. Either all of the NFTs
+		/// are transferred or none of them. NFTs are sent in order, so an item can be sent into
+		/// an NFT sent earlier in the same batch, but never into its own descendant.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction
+		/// - `items`: list of `(collection_id, nft_id, new_owner)` to transfer
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::send_batch(
+			items.len() as u32,
+			(items.len() as u32).saturating_mul(T::NestingBudget::get()).saturating_mul(2),
+		))]
+		#[transactional]
+		pub fn send_batch(
+			origin: OriginFor<T>,
+			items: BoundedSendBatchOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let items_count = items.len() as u32;
+
+			let budget_consumed = Self::nft_send_batch(sender, items)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::send_batch(items_count, budget_consumed))
+				.into())
+		}
+
+		/// Set the block from which a pending NFT or a pending resource can be expired with
+		/// `expire_pending`.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the sender of the pending NFT or the
+		///   collection issuer for a pending resource
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the pending nft, or of the nft holding the pending resource
+		/// - `maybe_resource_id`: id of the pending resource, `None` for the nft itself
+		/// - `expires`: block from which the pending nft or resource can be expired
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pending_expiry())]
+		#[transactional]
+		pub fn set_pending_expiry(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			maybe_resource_id: Option<ResourceId>,
+			expires: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::do_set_pending_expiry(sender, collection_id, nft_id, maybe_resource_id, expires)
+		}
+
+		/// Expire a pending NFT or a pending resource once its expiry is reached. A pending NFT
+		/// is sent back to its sender along with its children, a pending resource addition is
+		/// removed and a pending resource removal is dropped.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, can be anyone
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the pending nft, or of the nft holding the pending resource
+		/// - `maybe_resource_id`: id of the pending resource, `None` for the nft itself
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::expire_pending())]
+		#[transactional]
+		pub fn expire_pending(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			maybe_resource_id: Option<ResourceId>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_expire_pending(collection_id, nft_id, maybe_resource_id)
+		}
+
+		/// Change whether an NFT can be transferred, allowed only if the collection policy has
+		/// `transferability_mutable` set.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `transferable`: new transferability of the nft
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_transferable())]
+		#[transactional]
+		pub fn set_transferable(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			transferable: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::nft_set_transferable(sender, collection_id, nft_id, transferable)
+		}
+
+		/// Burn a non-transferable NFT and its children on behalf of its root owner, allowed
+		/// only if the collection policy has `revocable` set.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft to revoke
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke(T::NestingBudget::get(), T::PropertiesLimit::get()))]
+		#[transactional]
+		pub fn revoke(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			Self::nft_revoke(sender, collection_id, nft_id)
+		}
+
+		/// Burn an NFT and its children on behalf of its root owner, to take down stolen or
+		/// illegal content.
+		///
+		/// Parameters:
+		/// - `origin`: must be `ProtocolOrigin`
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft to burn
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::burn_nft(T::NestingBudget::get(), T::PropertiesLimit::get(), T::MaxResourcesPerNft::get()))]
+		#[transactional]
+		pub fn force_burn(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+		) -> DispatchResultWithPostInfo {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			Self::nft_burn(root_owner, collection_id, nft_id, &budget)
+		}
+
+		/// Transfer an NFT on behalf of its root owner, e.g. to give a stolen NFT back. None of
+		/// the checks of a regular transfer apply and the new owner doesn't have to accept the
+		/// NFT: a pending transfer is dropped and an equipped NFT is unequipped. The locks of the
+		/// NFT are kept, they can be removed with `force_unlock`.
+		///
+		/// Parameters:
+		/// - `origin`: must be `ProtocolOrigin`
+		/// - `collection_id`: collection id of the nft to be transferred
+		/// - `nft_id`: nft id of the nft to be transferred
+		/// - `new_owner`: new owner of the nft which can be either an account or a NFT
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::send_to_account(T::NestingBudget::get()).max(<T as
+		pallet::Config>::WeightInfo::send_to_nft(T::NestingBudget::get())))]
+		#[transactional]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			new_owner: AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+
+			Self::nft_force_send(collection_id, nft_id, new_owner.clone())?;
+
+			Self::deposit_event(Event::NFTSent {
+				sender: root_owner,
+				recipient: new_owner,
+				collection_id,
+				nft_id,
+				approval_required: false,
+			});
+			Ok(())
+		}
+
+		/// Remove the lock of an NFT for the reason `id`, other locks are kept. The pallet
+		/// holding the lock isn't notified, so locks with their own cleanup, e.g. the listings
+		/// of rmrk-market, should be removed through that pallet instead.
+		///
+		/// Parameters:
+		/// - `origin`: must be `ProtocolOrigin`
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft to unlock
+		/// - `id`: reason of the lock to remove
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_unlock())]
+		pub fn force_unlock(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			id: LockIdentifier,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			ensure!(Self::nft_exists((collection_id, nft_id)), Error::<T>::NoAvailableNftId);
+			ensure!(
+				Locks::<T>::get((collection_id, nft_id)).contains(&id),
+				Error::<T>::LockNotFound
+			);
+
+			Self::remove_lock(id, (collection_id, nft_id));
+
+			Self::deposit_event(Event::NFTUnlocked { collection_id, nft_id, id });
+			Ok(())
+		}
+
+		/// Transfer the issuership of a collection without the acceptance of the new issuer,
+		/// in both RMRK storage and `pallet_uniques`. A pending issuer proposal is cancelled.
+		///
+		/// Parameters:
+		/// - `origin`: must be `ProtocolOrigin`
+		/// - `collection_id`: collection id of the collection to change the issuer of
+		/// - `new_issuer`: new issuer of the collection
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_collection_issuer())]
+		#[transactional]
+		pub fn force_collection_issuer(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			new_issuer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			let new_issuer = T::Lookup::lookup(new_issuer)?;
+
+			let old_issuer = Self::do_transfer_issuer(collection_id, new_issuer.clone())?;
+
+			Self::deposit_event(Event::IssuerChanged { old_issuer, new_issuer, collection_id });
+			Ok(())
+		}
+
+		/// Remove a resource from an NFT without the approval of its root owner.
+		///
+		/// Parameters:
+		/// - `origin`: must be `ProtocolOrigin`
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `resource_id`: id of the resource to remove
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_resource(T::NestingBudget::get(), T::MaxPriorities::get(), T::MaxPriorityContexts::get()))]
+		#[transactional]
+		pub fn force_remove_resource(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+
+			Self::resource_remove(root_owner, collection_id, nft_id, resource_id, false)
+		}
+
+		/// Pause a group of calls of the RMRK pallets, either globally or for a single
+		/// collection. Paused calls are rejected by the `CallsNotPaused` call filter.
+		///
+		/// Parameters:
+		/// - `origin`: must be `ProtocolOrigin`
+		/// - `group`: group of calls to pause
+		/// - `maybe_collection_id`: collection to pause the calls for, `None` to pause them for
+		///   every collection
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pause_calls())]
+		pub fn pause_calls(
+			origin: OriginFor<T>,
+			group: CallGroup,
+			maybe_collection_id: Option<T::CollectionId>,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			ensure!(
+				!PausedCalls::<T>::contains_key(group, maybe_collection_id),
+				Error::<T>::CallsAlreadyPaused
+			);
+
+			PausedCalls::<T>::insert(group, maybe_collection_id, ());
+
+			Self::deposit_event(Event::CallsPaused { group, collection_id: maybe_collection_id });
+			Ok(())
+		}
+
+		/// Unpause a group of calls previously paused with `pause_calls`, for the same scope.
+		///
+		/// Parameters:
+		/// - `origin`: must be `ProtocolOrigin`
+		/// - `group`: group of calls to unpause
+		/// - `maybe_collection_id`: collection the calls were paused for, `None` if they were
+		///   paused globally
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unpause_calls())]
+		pub fn unpause_calls(
+			origin: OriginFor<T>,
+			group: CallGroup,
+			maybe_collection_id: Option<T::CollectionId>,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			ensure!(
+				PausedCalls::<T>::contains_key(group, maybe_collection_id),
+				Error::<T>::CallsNotPaused
+			);
+
+			PausedCalls::<T>::remove(group, maybe_collection_id);
+
+			Self::deposit_event(Event::CallsUnpaused { group, collection_id: maybe_collection_id });
+			Ok(())
+		}
+
+		/// Add a resource shared by all of the NFTs of a collection, existing and future ones.
+		/// An NFT can override it by having its own resource with the same ID.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection the resource is added to
+		/// - `resource`: resource to share
+		/// - `resource_id`: ID of the resource
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_collection_resource())]
+		#[transactional]
+		pub fn add_collection_resource(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			resource: ResourceTypes<StringLimitOf<T>, BoundedVec<PartId, T::PartsLimit>>,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			Self::collection_resource_add(sender, collection_id, resource, resource_id)
+		}
+
+		/// Remove a resource shared by all of the NFTs of a collection. The resources of the
+		/// NFTs overriding it are kept. The other NFTs are unequipped from the resource and
+		/// lose it from their priorities, which requires the collection to have at most
+		/// `MaxCollectionResourceNfts` NFTs.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection the resource is removed from
+		/// - `resource_id`: ID of the resource
+		#[pallet::call_index(41)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_collection_resource(T::MaxCollectionResourceNfts::get()))]
+		#[transactional]
+		pub fn remove_collection_resource(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			Self::collection_resource_remove(collection_id, resource_id)
+		}
+
+		/// Add the same resource to multiple NFTs of a collection. The resource is pending on
+		/// the NFTs not owned by the sender, as with `add_basic_resource`. Either the resource is
+		/// added to all of the NFTs or to none of them.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection of the NFTs
+		/// - `resource`: resource to add
+		/// - `maybe_resource_id`: ID of the resource on each NFT, `None` to use the next free ID of
+		///   each NFT
+		/// - `nft_ids`: NFTs to add the resource to
+		#[pallet::call_index(42)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_resource_to_many(
+			nft_ids.len() as u32,
+			(nft_ids.len() as u32).saturating_mul(T::NestingBudget::get()),
+		))]
+		#[transactional]
+		pub fn add_resource_to_many(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			resource: ResourceTypes<StringLimitOf<T>, BoundedVec<PartId, T::PartsLimit>>,
+			maybe_resource_id: Option<ResourceId>,
+			nft_ids: BoundedResourceBatchOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let nfts_count = nft_ids.len() as u32;
+
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			let budget_consumed = Self::resource_add_batch(
+				sender,
+				collection_id,
+				resource,
+				maybe_resource_id,
+				nft_ids,
+			)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::add_resource_to_many(
+				nfts_count,
+				budget_consumed,
+			))
+			.into())
+		}
+
+		/// Create a media resource, describing the type, hash and license of its asset. The next
+		/// free ID of the NFT is used if `maybe_resource_id` is `None`. A resource pending the
+		/// acceptance of the root owner can be given an `expires` block from which it can be
+		/// expired with `expire_pending`.
+		#[pallet::call_index(43)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_media_resource(T::NestingBudget::get())
+			.saturating_add(<T as pallet::Config>::WeightInfo::set_pending_expiry()))]
+		#[transactional]
+		pub fn add_media_resource(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource: MediaResource<StringLimitOf<T>>,
+			maybe_resource_id: Option<ResourceId>,
+			expires: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			// Check NFT lock status
+			ensure!(
+				!Pallet::<T>::is_locked(collection_id, nft_id),
+				pallet_uniques::Error::<T>::Locked
+			);
+			Pallet::<T>::ensure_not_expired(collection_id, nft_id)?;
+
+			let pending = root_owner != sender;
+			let resource_id = match maybe_resource_id {
+				Some(resource_id) => resource_id,
+				None => Self::get_next_resource_id(collection_id, nft_id)?,
+			};
+
+			Self::resource_add(
+				sender.clone(),
+				collection_id,
+				nft_id,
+				ResourceTypes::Media(resource),
+				pending,
+				resource_id,
+			)?;
+
+			if let (true, Some(expires)) = (pending, expires) {
+				Self::do_set_pending_expiry(
+					sender,
+					collection_id,
+					nft_id,
+					Some(resource_id),
+					expires,
+				)?;
+			}
+
+			Ok(())
+		}
+
+		/// Set the priority list of resources of an NFT for a context, e.g. "thumbnail", "3d"
+		/// or a theme name. An empty list removes the context, which then falls back to the
+		/// list set with `set_priority`.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `context`: name of the context
+		/// - `priorities`: resources in order of priority for the context
+		#[pallet::call_index(44)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_priority_for_context(
+			priorities.len() as u32,
+			T::NestingBudget::get(),
+			T::MaxPriorityContexts::get(),
+		))]
+		pub fn set_priority_for_context(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			context: KeyLimitOf<T>,
+			priorities: BoundedVec<ResourceId, T::MaxPriorities>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			ensure!(sender == root_owner, Error::<T>::NoPermission);
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			let priorities_count = priorities.len() as u32;
+			Self::context_priority_set(sender, collection_id, nft_id, context, priorities)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_priority_for_context(
+				priorities_count,
+				budget.get_budget_consumed_value(),
+				T::MaxPriorityContexts::get(),
+			))
+			.into())
+		}
+
+		/// Move a resource to another index of the priorities of an NFT, shifting the resources
+		/// in between by one.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `resource_id`: resource to move
+		/// - `index`: new index of the resource
+		#[pallet::call_index(45)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::move_priority(
+			T::MaxPriorities::get(),
+			T::NestingBudget::get(),
+		))]
+		pub fn move_priority(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+			index: u32,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			ensure!(sender == root_owner, Error::<T>::NoPermission);
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			Self::priority_move(collection_id, nft_id, resource_id, index)
+		}
+
+		/// Swap the indexes of two resources in the priorities of an NFT.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `resource_id`: first resource to swap
+		/// - `other_resource_id`: second resource to swap
+		#[pallet::call_index(46)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::swap_priorities(T::NestingBudget::get()))]
+		pub fn swap_priorities(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+			other_resource_id: ResourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			ensure!(sender == root_owner, Error::<T>::NoPermission);
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			Self::priority_swap(collection_id, nft_id, resource_id, other_resource_id)
+		}
+
+		/// Propose a resource for an NFT, e.g. a community-made variant. The resource stays
+		/// pending until the collection issuer approves or rejects it. The next free ID of the
+		/// NFT is used if `maybe_resource_id` is `None`. An NFT has at most `MaxProposalsPerNft`
+		/// proposed resources at a time.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `resource`: the proposed resource
+		/// - `maybe_resource_id`: optional id of the resource
+		#[pallet::call_index(47)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_resource(T::NestingBudget::get()))]
+		#[transactional]
+		pub fn propose_resource(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource: ResourceTypes<StringLimitOf<T>, BoundedVec<PartId, T::PartsLimit>>,
+			maybe_resource_id: Option<ResourceId>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(Self::collections(collection_id).is_some(), Error::<T>::CollectionUnknown);
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			ensure!(sender == root_owner, Error::<T>::NoPermission);
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			let resource_id = match maybe_resource_id {
+				Some(resource_id) => resource_id,
+				None => Self::get_next_resource_id(collection_id, nft_id)?,
+			};
+
+			Self::resource_propose(sender, collection_id, nft_id, resource, resource_id)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::propose_resource(
+				budget.get_budget_consumed_value(),
+			))
+			.into())
+		}
+
+		/// Approve a resource proposed by the owner of an NFT, which is then accepted.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `resource_id`: id of the proposed resource
+		#[pallet::call_index(48)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_resource_proposal())]
+		#[transactional]
+		pub fn approve_resource_proposal(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			Self::resource_proposal_approve(sender, collection_id, nft_id, resource_id)
+		}
+
+		/// Reject a resource proposed by the owner of an NFT, which is removed and its deposit
+		/// refunded to the proposer.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the collection issuer
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `resource_id`: id of the proposed resource
+		#[pallet::call_index(49)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_resource_proposal())]
+		#[transactional]
+		pub fn reject_resource_proposal(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			Self::resource_proposal_reject(collection_id, nft_id, resource_id)
+		}
+
+		/// Accept the replacement proposed by the collection issuer for a resource of an NFT,
+		/// which then replaces the current resource.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `resource_id`: id of the replaced resource
+		#[pallet::call_index(50)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_resource_replacement(
+			T::NestingBudget::get()
+		))]
+		#[transactional]
+		pub fn accept_resource_replacement(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			ensure!(sender == root_owner, Error::<T>::NoPermission);
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			Self::resource_replacement_accept(collection_id, nft_id, resource_id)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::accept_resource_replacement(
+				budget.get_budget_consumed_value(),
+			))
+			.into())
+		}
+
+		/// Reject the replacement proposed by the collection issuer for a resource of an NFT,
+		/// which keeps its current resource.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `resource_id`: id of the replaced resource
+		#[pallet::call_index(51)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_resource_replacement(
+			T::NestingBudget::get()
+		))]
+		pub fn reject_resource_replacement(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+			resource_id: ResourceId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let budget = budget::Value::new(T::NestingBudget::get());
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id, &budget)?;
+			ensure!(sender == root_owner, Error::<T>::NoPermission);
+			// Check NFT lock status
+			ensure!(!Self::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+			Self::ensure_not_expired(collection_id, nft_id)?;
+
+			Self::resource_replacement_reject(collection_id, nft_id, resource_id)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::reject_resource_replacement(
+				budget.get_budget_consumed_value(),
+			))
+			.into())
+		}
+
+		/// Propose a new issuer for a collection. Issuership is only transferred once the
+		/// proposed account calls `accept_issuer`. A new proposal overrides the previous one.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, must be the current collection issuer
+		/// - `collection_id`: collection id of the nft to change issuer of
+		/// - `new_issuer`: Collection's proposed new issuer
+		#[pallet::call_index(52)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_issuer())]
+		pub fn propose_issuer(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			new_issuer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			ensure!(
+				!DestroyingCollections::<T>::contains_key(collection_id),
+				Error::<T>::CollectionIsDestroying
+			);
+			let new_issuer = T::Lookup::lookup(new_issuer)?;
+
+			PendingIssuer::<T>::insert(collection_id, &new_issuer);
+
+			Self::deposit_event(Event::IssuerProposed {
+				issuer: sender,
+				proposed_issuer: new_issuer,
+				collection_id,
+			});
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,152 @@
+// Copyright (C) 2021-2022 RMRK
+// This file is part of rmrk-core.
+// License: Apache 2.0 modified by RMRK, see LICENSE.md
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use rmrk_traits::budget::Budget;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Migrates the storage of the pallet from version 0 to version 1
+pub mod v1 {
+	use super::*;
+
+	/// Collection info before collections could be locked and given a soulbound policy
+	#[derive(Encode, Decode)]
+	pub struct OldCollectionInfo<BoundedString, BoundedSymbol, AccountId> {
+		pub issuer: AccountId,
+		pub metadata: BoundedString,
+		pub max: Option<u32>,
+		pub symbol: BoundedSymbol,
+		pub nfts_count: u32,
+	}
+
+	pub type OldCollectionInfoOf<T> = OldCollectionInfo<
+		StringLimitOf<T>,
+		BoundedCollectionSymbolOf<T>,
+		<T as frame_system::Config>::AccountId,
+	>;
+
+	/// Lock identifier of rmrk-market, the only pallet locking NFTs before `Lock` was replaced
+	/// by `Locks`
+	pub const MARKET_LOCK_ID: LockIdentifier = *b"rmrkmrkt";
+
+	/// Single lock flag of the NFTs, replaced by the list of lock reasons in `Locks`
+	#[frame_support::storage_alias]
+	pub type Lock<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		(<T as pallet_uniques::Config>::CollectionId, <T as pallet_uniques::Config>::ItemId),
+		bool,
+		ValueQuery,
+	>;
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let weight = migrate_collections::<T>()
+				.saturating_add(migrate_locks::<T>())
+				.saturating_add(migrate_resources_count::<T>())
+				.saturating_add(migrate_root_owners::<T>());
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"the storage version of rmrk-core must be 1 after the migration"
+			);
+			Ok(())
+		}
+	}
+
+	/// Add the `locked`, `ever_minted` and `policy` fields to the stored collections. Collections
+	/// locked by setting their `max` to 0 are marked as locked, and the ones with NFTs as minted.
+	pub fn migrate_collections<T: Config>() -> Weight {
+		let mut translated = 0u64;
+		Collections::<T>::translate::<OldCollectionInfoOf<T>, _>(|_, old| {
+			translated += 1;
+			Some(CollectionInfo {
+				issuer: old.issuer,
+				metadata: old.metadata,
+				max: old.max,
+				symbol: old.symbol,
+				nfts_count: old.nfts_count,
+				locked: old.max == Some(0),
+				ever_minted: old.nfts_count > 0,
+				policy: CollectionPolicy::default(),
+			})
+		});
+		T::DbWeight::get().reads_writes(translated, translated)
+	}
+
+	/// Move the NFTs locked in `Lock` to `Locks`, under the lock identifier of rmrk-market
+	pub fn migrate_locks<T: Config>() -> Weight {
+		let mut migrated = 0u64;
+		for (nft, locked) in Lock::<T>::drain() {
+			migrated += 1;
+			if locked {
+				Locks::<T>::mutate(nft, |locks| {
+					if !locks.contains(&MARKET_LOCK_ID) {
+						// A fresh list always has room for a first lock
+						let _ = locks.try_push(MARKET_LOCK_ID);
+					}
+				});
+			}
+		}
+		T::DbWeight::get().reads_writes(migrated.saturating_mul(2), migrated.saturating_mul(2))
+	}
+
+	/// Count the resources of the existing NFTs into `ResourcesCount`. An NFT which already has
+	/// more than `MaxResourcesPerNft` resources keeps them but can't get new ones.
+	pub fn migrate_resources_count<T: Config>() -> Weight {
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+		for (collection_id, nft_id) in Nfts::<T>::iter_keys() {
+			let count = Resources::<T>::iter_key_prefix((collection_id, nft_id)).count() as u32;
+			reads = reads.saturating_add(1).saturating_add(count.into());
+			if count > 0 {
+				ResourcesCount::<T>::insert(collection_id, nft_id, count);
+				writes += 1;
+			}
+		}
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Fill `RootOwner` for the nested NFTs, which had no entry before it was introduced.
+	/// The entries of the parents are filled along the way.
+	pub fn migrate_root_owners<T: Config>() -> Weight {
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+		for (collection_id, nft_id) in Nfts::<T>::iter_keys() {
+			reads = reads.saturating_add(2);
+			let nested = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
+				.and_then(Pallet::<T>::decode_nft_account_id::<T::AccountId>)
+				.is_some();
+			if nested && !RootOwner::<T>::contains_key((collection_id, nft_id)) {
+				let budget = budget::Value::new(T::NestingBudget::get());
+				if Pallet::<T>::root_nft_of((collection_id, nft_id), &budget).is_ok() {
+					let walked = budget.get_budget_consumed_value() as u64;
+					reads = reads.saturating_add(walked.saturating_mul(2));
+					writes = writes.saturating_add(walked.saturating_add(1));
+				}
+			}
+		}
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
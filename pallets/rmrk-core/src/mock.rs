@@ -49,10 +49,21 @@ parameter_types! {
 	pub const ResourceSymbolLimit: u32 = 10;
 	pub const PartsLimit: u32 = 50;
 	pub const MaxPriorities: u32 = 3;
+	pub const MaxPriorityContexts: u32 = 3;
 	pub const PropertiesLimit: u32 = 15;
 	pub const NestingBudget: u32 = 3;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const MaxResourcesOnMint: u32 = 3;
+	pub const MaxResourcesPerNft: u32 = 10;
+	pub const MaxProposalsPerNft: u32 = 3;
+	pub const MaxReplacementsPerNft: u32 = 3;
+	pub const MaxCollectionResources: u32 = 5;
+	pub const MaxCollectionResourceNfts: u32 = 5;
+	pub const MaxBatchSends: u32 = 5;
+	pub const MaxBatchResources: u32 = 5;
+	pub const MaxDestroyItems: u32 = 10;
+	pub const MaxLocks: u32 = 3;
+	pub const StorageDepositPerByte: Balance = RMRK;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -65,14 +76,80 @@ impl pallet_rmrk_core::Config for Test {
 	type ResourceSymbolLimit = ResourceSymbolLimit;
 	type PartsLimit = PartsLimit;
 	type MaxPriorities = MaxPriorities;
+	type MaxPriorityContexts = MaxPriorityContexts;
 	type CollectionSymbolLimit = CollectionSymbolLimit;
 	type MaxResourcesOnMint = MaxResourcesOnMint;
+	type MaxResourcesPerNft = MaxResourcesPerNft;
+	type MaxProposalsPerNft = MaxProposalsPerNft;
+	type MaxReplacementsPerNft = MaxReplacementsPerNft;
+	type MaxCollectionResources = MaxCollectionResources;
+	type MaxCollectionResourceNfts = MaxCollectionResourceNfts;
 	type PropertiesLimit = PropertiesLimit;
 	type NestingBudget = NestingBudget;
+	type MaxBatchSends = MaxBatchSends;
+	type MaxBatchResources = MaxBatchResources;
+	type MaxDestroyItems = MaxDestroyItems;
+	type MaxLocks = MaxLocks;
+	type StorageDepositPerByte = StorageDepositPerByte;
 	type WeightInfo = weights::SubstrateWeight<Test>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = RmrkBenchmark;
-	type TransferHooks = ();
+	type NftHooks = (TestHooks, ());
+}
+
+/// NFT whose transfers are denied by `TestHooks`
+pub const HOOK_DENIED_NFT_ID: <Test as pallet_uniques::Config>::ItemId = 42;
+
+parameter_types! {
+	/// Number of mints seen by `TestHooks`
+	pub static HookedMints: u32 = 0;
+	/// Whether `TestHooks` makes burns fail
+	pub static HookDeniesBurns: bool = false;
+	/// Number of resource removals seen by `TestHooks`, before and after the removal
+	pub static HookedResourceRemovals: (u32, u32) = (0, 0);
+}
+
+/// Hooks used to check that hooks are called and that their errors reach the caller
+pub struct TestHooks;
+
+impl rmrk_traits::NftHooks<AccountId, u32, u32> for TestHooks {
+	fn pre_transfer(_sender: &AccountId, _collection_id: &u32, nft_id: &u32) -> DispatchResult {
+		ensure!(*nft_id != HOOK_DENIED_NFT_ID, DispatchError::Other("Transfer denied by hook"));
+		Ok(())
+	}
+
+	fn post_mint(
+		_minter: &AccountId,
+		_owner: &AccountIdOrCollectionNftTuple<AccountId, u32, u32>,
+		_collection_id: &u32,
+		_nft_id: &u32,
+	) -> DispatchResult {
+		HookedMints::set(HookedMints::get() + 1);
+		Ok(())
+	}
+
+	fn post_burn(_owner: &AccountId, _collection_id: &u32, _nft_id: &u32) -> DispatchResult {
+		ensure!(!HookDeniesBurns::get(), DispatchError::Other("Burn denied by hook"));
+		Ok(())
+	}
+
+	fn pre_resource_remove(
+		_collection_id: &u32,
+		_nft_id: &u32,
+		_resource_id: &ResourceId,
+	) -> DispatchResult {
+		HookedResourceRemovals::mutate(|(pre, _)| *pre += 1);
+		Ok(())
+	}
+
+	fn post_resource_remove(
+		_collection_id: &u32,
+		_nft_id: &u32,
+		_resource_id: &ResourceId,
+	) -> DispatchResult {
+		HookedResourceRemovals::mutate(|(_, post)| *post += 1);
+		Ok(())
+	}
 }
 
 parameter_types! {
@@ -2,7 +2,11 @@
 // This file is part of rmrk-core.
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Contains, Hooks},
+	weights::Weight,
+};
 // use sp_runtime::AccountId32;
 use sp_runtime::Permill;
 // use crate::types::ClassType;
@@ -43,6 +47,7 @@ fn basic_collection() -> DispatchResult {
 		bvec![0u8; 20],
 		Some(5),
 		bvec![0u8; 15],
+		CollectionPolicy::default(),
 	)
 }
 
@@ -58,6 +63,7 @@ fn basic_mint(id: u32) -> DispatchResult {
 		bvec![0u8; 20],
 		true,
 		None,
+		None,
 	)
 }
 
@@ -92,7 +98,8 @@ fn create_collection_no_max_works() {
 			COLLECTION_ID_0,
 			bvec![0u8; 20],
 			None,
-			bvec![0u8; 15]
+			bvec![0u8; 15],
+			CollectionPolicy::default(),
 		));
 		// Creating collection should trigger CollectionCreated event
 		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionCreated {
@@ -142,6 +149,96 @@ fn lock_collection_works() {
 		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 3);
 		// After burning, we should still be unable to mint another NFT
 		assert_noop!(basic_mint(5), Error::<Test>::CollectionFullOrLocked);
+		// Locking keeps the real max of the collection
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().max, Some(5));
+		assert!(RMRKCore::collections(COLLECTION_ID_0).unwrap().locked);
+	});
+}
+
+/// Collection: Unlocking is only possible before NFTs are minted
+#[test]
+fn unlock_collection_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection (has 5 max)
+		assert_ok!(basic_collection());
+		// Lock collection
+		assert_ok!(RMRKCore::lock_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+		assert_noop!(basic_mint(0), Error::<Test>::CollectionFullOrLocked);
+		// BOB can't unlock the collection
+		assert_noop!(
+			RMRKCore::unlock_collection(Origin::signed(BOB), COLLECTION_ID_0),
+			Error::<Test>::NoPermission
+		);
+		// Unlock collection
+		assert_ok!(RMRKCore::unlock_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionUnlocked {
+			issuer: ALICE,
+			collection_id: COLLECTION_ID_0,
+		}));
+		// Minting works again
+		assert_ok!(basic_mint(0));
+		// Once an NFT is minted the collection can't be unlocked after locking
+		assert_ok!(RMRKCore::lock_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+		assert_noop!(
+			RMRKCore::unlock_collection(Origin::signed(ALICE), COLLECTION_ID_0),
+			Error::<Test>::CollectionAlreadyMinted
+		);
+		// Not even after all of its NFTs are burned
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 0);
+		assert_noop!(
+			RMRKCore::unlock_collection(Origin::signed(ALICE), COLLECTION_ID_0),
+			Error::<Test>::CollectionAlreadyMinted
+		);
+	});
+}
+
+/// Collection: Collection max can be lowered and raised
+#[test]
+fn set_collection_max_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection (has 5 max)
+		assert_ok!(basic_collection());
+		// Mint 3 NFTs
+		for id in 0..3 {
+			assert_ok!(basic_mint(id));
+		}
+		// BOB can't change the max
+		assert_noop!(
+			RMRKCore::set_collection_max(Origin::signed(BOB), COLLECTION_ID_0, Some(10)),
+			Error::<Test>::NoPermission
+		);
+		// Max can't be lowered below the number of NFTs
+		assert_noop!(
+			RMRKCore::set_collection_max(Origin::signed(ALICE), COLLECTION_ID_0, Some(2)),
+			Error::<Test>::MaxLowerThanNftsCount
+		);
+		// Max can be raised
+		assert_ok!(RMRKCore::set_collection_max(Origin::signed(ALICE), COLLECTION_ID_0, Some(10)));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionMaxSet {
+			issuer: ALICE,
+			collection_id: COLLECTION_ID_0,
+			max: Some(10),
+		}));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().max, Some(10));
+		// Max can be lowered down to the number of NFTs
+		assert_ok!(RMRKCore::set_collection_max(Origin::signed(ALICE), COLLECTION_ID_0, Some(3)));
+		assert_noop!(basic_mint(3), Error::<Test>::CollectionFullOrLocked);
+		// Lock collection
+		assert_ok!(RMRKCore::lock_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+		// Max can't be raised or removed while locked
+		assert_noop!(
+			RMRKCore::set_collection_max(Origin::signed(ALICE), COLLECTION_ID_0, Some(4)),
+			Error::<Test>::CollectionFullOrLocked
+		);
+		assert_noop!(
+			RMRKCore::set_collection_max(Origin::signed(ALICE), COLLECTION_ID_0, None),
+			Error::<Test>::CollectionFullOrLocked
+		);
+		// Burn an NFT, max can still be lowered while locked
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert_ok!(RMRKCore::set_collection_max(Origin::signed(ALICE), COLLECTION_ID_0, Some(2)));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().max, Some(2));
 	});
 }
 
@@ -170,30 +267,140 @@ fn destroy_collection_works() {
 	});
 }
 
-/// Collection: Change issuer tests (RMRK2.0 spec: CHANGEISSUER)=
+/// Collection: Destroying a non-empty collection in multiple steps
+#[test]
+fn destroy_collection_in_steps_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection (has 5 max)
+		assert_ok!(basic_collection());
+		// Mint 4 NFTs, NFT (0, 3) is owned by NFT (0, 2)
+		for id in 0..4 {
+			assert_ok!(basic_mint(id));
+		}
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			3,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 2),
+			None,
+		));
+		// Set a collection property and an NFT property
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			None,
+			stbk("test-key"),
+			stb("test-value")
+		));
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			Some(0),
+			stbk("test-key"),
+			stb("test-value")
+		));
+		// Items can't be destroyed before the destruction is started
+		assert_noop!(
+			RMRKCore::destroy_collection_items(Origin::signed(BOB), COLLECTION_ID_0, 10),
+			Error::<Test>::CollectionNotDestroying
+		);
+		// Only the issuer can start the destruction
+		assert_noop!(
+			RMRKCore::start_destroy_collection(Origin::signed(BOB), COLLECTION_ID_0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::start_destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionDestroyStarted {
+			issuer: ALICE,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert_noop!(
+			RMRKCore::start_destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0),
+			Error::<Test>::CollectionIsDestroying
+		);
+		// Minting and transfers are blocked
+		assert_noop!(basic_mint(4), Error::<Test>::CollectionIsDestroying);
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None,
+			),
+			Error::<Test>::CollectionIsDestroying
+		);
+		// Collection still can't be removed while it has NFTs
+		assert_noop!(
+			RMRKCore::destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0),
+			Error::<Test>::CollectionNotEmpty
+		);
+		// Anyone can burn the remaining NFTs in bounded steps
+		assert_ok!(RMRKCore::destroy_collection_items(Origin::signed(BOB), COLLECTION_ID_0, 1));
+		assert!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count < 4);
+		assert_ok!(RMRKCore::destroy_collection_items(Origin::signed(BOB), COLLECTION_ID_0, 10));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 0);
+		assert_eq!(Nfts::<Test>::iter_prefix(COLLECTION_ID_0).count(), 0);
+		assert_eq!(Children::<Test>::iter().count(), 0);
+		assert_eq!(RMRKCore::properties((COLLECTION_ID_0, Some(0), stbk("test-key"))), None);
+		// The empty collection can now be destroyed
+		assert_ok!(RMRKCore::destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionDestroyed {
+			issuer: ALICE,
+			collection_id: COLLECTION_ID_0,
+		}));
+		// Collection properties and destroying status are removed as well
+		assert_eq!(RMRKCore::properties((COLLECTION_ID_0, None::<u32>, stbk("test-key"))), None);
+		assert_eq!(RMRKCore::destroying_collections(COLLECTION_ID_0), None);
+	});
+}
+
+/// Collection: Change issuer tests (RMRK2.0 spec: CHANGEISSUER)
 #[test]
-fn change_collection_issuer_works() {
+fn propose_and_accept_issuer_works() {
 	ExtBuilder::build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// BOB can't change issuer because he is not the current issuer
+		// BOB can't propose an issuer because he is not the current issuer
+		assert_noop!(
+			RMRKCore::propose_issuer(Origin::signed(BOB), 0, BOB),
+			Error::<Test>::NoPermission
+		);
+		// Nothing to accept before a proposal is made
+		assert_noop!(
+			RMRKCore::accept_issuer(Origin::signed(BOB), 0),
+			Error::<Test>::NoPendingIssuer
+		);
+		// ALICE proposes BOB as the new issuer
+		assert_ok!(RMRKCore::propose_issuer(Origin::signed(ALICE), 0, BOB));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IssuerProposed {
+			issuer: ALICE,
+			proposed_issuer: BOB,
+			collection_id: 0,
+		}));
+		assert_eq!(RMRKCore::pending_issuer(0), Some(BOB));
+		// Issuer doesn't change until the proposal is accepted
+		assert_eq!(RMRKCore::collections(0).unwrap().issuer, ALICE);
+		// CHARLIE can't accept a proposal made to BOB
 		assert_noop!(
-			RMRKCore::change_collection_issuer(Origin::signed(BOB), 0, BOB),
+			RMRKCore::accept_issuer(Origin::signed(CHARLIE), 0),
 			Error::<Test>::NoPermission
 		);
-		// Must set ownership acceptance with BOB before transfer due to uniques dependency
-		assert_ok!(Uniques::set_accept_ownership(Origin::signed(BOB), Some(0)));
-		// Change issuer from ALICE to BOB
-		assert_ok!(RMRKCore::change_collection_issuer(Origin::signed(ALICE), 0, BOB));
+		// BOB accepts the issuership
+		assert_ok!(RMRKCore::accept_issuer(Origin::signed(BOB), 0));
 		// Changing issuer should trigger IssuerChanged event
 		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IssuerChanged {
 			old_issuer: ALICE,
 			new_issuer: BOB,
 			collection_id: 0,
 		}));
-		// New issuer should be Bob
+		// New issuer should be Bob, both in RMRK and Uniques
 		assert_eq!(RMRKCore::collections(0).unwrap().issuer, BOB);
-		// BOB can't change issuer if calls transfer_ownership in uniques
+		assert_eq!(UNQ::Pallet::<Test>::collection_owner(0), Some(BOB));
+		assert_eq!(RMRKCore::pending_issuer(0), None);
+		// ALICE is no longer able to mint
+		assert_noop!(basic_mint(0), Error::<Test>::NoPermission);
+		// ALICE can't transfer ownership back through uniques
 		assert_noop!(
 			UNQ::Pallet::<Test>::transfer_ownership(Origin::signed(ALICE), 0, ALICE),
 			UNQ::Error::<Test>::Unaccepted
@@ -201,6 +408,51 @@ fn change_collection_issuer_works() {
 	});
 }
 
+/// Collection: Issuer proposals can be cancelled by the current issuer
+#[test]
+fn cancel_issuer_proposal_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// ALICE proposes BOB as the new issuer
+		assert_ok!(RMRKCore::propose_issuer(Origin::signed(ALICE), 0, BOB));
+		// BOB can't cancel the proposal
+		assert_noop!(
+			RMRKCore::cancel_issuer_proposal(Origin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+		// ALICE cancels the proposal
+		assert_ok!(RMRKCore::cancel_issuer_proposal(Origin::signed(ALICE), 0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IssuerProposalCancelled {
+			issuer: ALICE,
+			proposed_issuer: BOB,
+			collection_id: 0,
+		}));
+		// BOB can no longer accept
+		assert_noop!(
+			RMRKCore::accept_issuer(Origin::signed(BOB), 0),
+			Error::<Test>::NoPendingIssuer
+		);
+		// Nothing left to cancel
+		assert_noop!(
+			RMRKCore::cancel_issuer_proposal(Origin::signed(ALICE), 0),
+			Error::<Test>::NoPendingIssuer
+		);
+		assert_eq!(RMRKCore::collections(0).unwrap().issuer, ALICE);
+		// Issuership can't be handed over while the collection is being destroyed
+		assert_ok!(RMRKCore::propose_issuer(Origin::signed(ALICE), 0, BOB));
+		assert_ok!(RMRKCore::start_destroy_collection(Origin::signed(ALICE), 0));
+		assert_noop!(
+			RMRKCore::propose_issuer(Origin::signed(ALICE), 0, CHARLIE),
+			Error::<Test>::CollectionIsDestroying
+		);
+		assert_noop!(
+			RMRKCore::accept_issuer(Origin::signed(BOB), 0),
+			Error::<Test>::CollectionIsDestroying
+		);
+	});
+}
+
 /// NFT: Basic Mint tests (RMRK2.0 spec: MINT)
 #[test]
 fn mint_nft_works() {
@@ -229,6 +481,7 @@ fn mint_nft_works() {
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
 		// BOB shouldn't be able to mint in ALICE's collection
 		assert_noop!(
@@ -242,6 +495,7 @@ fn mint_nft_works() {
 				bvec![0u8; 20],
 				true,
 				None,
+				None,
 			),
 			Error::<Test>::NoPermission
 		);
@@ -257,6 +511,7 @@ fn mint_nft_works() {
 				bvec![0u8; 20],
 				true,
 				None,
+				None,
 			),
 			Error::<Test>::CollectionUnknown
 		);
@@ -272,6 +527,7 @@ fn mint_nft_works() {
 				bvec![0u8; 20],
 				true,
 				None,
+				None,
 			),
 			Error::<Test>::NftAlreadyExists
 		);
@@ -297,6 +553,7 @@ fn mint_directly_to_nft() {
 				bvec![0u8; 20],
 				true,
 				None,
+				None,
 			),
 			Error::<Test>::NoAvailableNftId
 		);
@@ -312,6 +569,7 @@ fn mint_directly_to_nft() {
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
 
 		// BOB owns NFT (0, 0)
@@ -334,6 +592,7 @@ fn mint_directly_to_nft() {
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
 
 		// Minted NFT (0, 1) exists
@@ -386,6 +645,7 @@ fn mint_directly_to_nft_with_resources() {
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
 
 		// Compose a resource to add to an NFT
@@ -406,6 +666,7 @@ fn mint_directly_to_nft_with_resources() {
 			bvec![0u8; 20],
 			true,
 			Some(resources_to_add),
+			None,
 		));
 
 		// Created resource 0 on NFT (0, 1) should exist
@@ -452,6 +713,7 @@ fn royalty_recipient_default_works() {
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
 		// Royalty recipient should default to issuer (ALICE)
 		assert_eq!(RmrkCore::nfts(0, 0).unwrap().royalty.unwrap().recipient, ALICE);
@@ -466,6 +728,7 @@ fn royalty_recipient_default_works() {
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
 		// Royalty recipient should be BOB
 		assert_eq!(RmrkCore::nfts(0, 1).unwrap().royalty.unwrap().recipient, BOB);
@@ -480,6 +743,7 @@ fn royalty_recipient_default_works() {
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
 		// Royalty should not exist
 		assert!(RmrkCore::nfts(0, 2).unwrap().royalty.is_none());
@@ -494,6 +758,7 @@ fn royalty_recipient_default_works() {
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
 		// Royalty should not exist
 		assert!(RmrkCore::nfts(0, 3).unwrap().royalty.is_none());
@@ -516,6 +781,7 @@ fn send_nft_to_minted_nft_works() {
 			0,
 			0,
 			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
 		));
 		// Successful send triggers NFTSent event
 		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTSent {
@@ -531,6 +797,7 @@ fn send_nft_to_minted_nft_works() {
 			0,
 			1,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
 		));
 		// Successful send to NFT triggers NFTSent event
 		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTSent {
@@ -570,6 +837,7 @@ fn send_nft_to_minted_nft_works() {
 			0,
 			2,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			None,
 		));
 		// Successful send to NFT triggers NFTSent event
 		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTSent {
@@ -605,7 +873,8 @@ fn send_nft_to_minted_nft_works() {
 				Origin::signed(BOB),
 				0,
 				0,
-				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0)
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+				None
 			),
 			Error::<Test>::CannotSendToDescendentOrSelf
 		);
@@ -615,7 +884,8 @@ fn send_nft_to_minted_nft_works() {
 				Origin::signed(BOB),
 				0,
 				0,
-				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2)
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+				None
 			),
 			Error::<Test>::CannotSendToDescendentOrSelf
 		);
@@ -630,7 +900,8 @@ fn send_nft_to_minted_nft_works() {
 				Origin::signed(CHARLIE),
 				0,
 				0,
-				AccountIdOrCollectionNftTuple::AccountId(BOB)
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None
 			),
 			Error::<Test>::NoPermission
 		);
@@ -640,7 +911,8 @@ fn send_nft_to_minted_nft_works() {
 				Origin::signed(ALICE),
 				666,
 				666,
-				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0)
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+				None
 			),
 			Error::<Test>::NoAvailableNftId
 		);
@@ -653,7 +925,8 @@ fn send_nft_to_minted_nft_works() {
 			Origin::signed(BOB),
 			0,
 			1,
-			AccountIdOrCollectionNftTuple::AccountId(ALICE)
+			AccountIdOrCollectionNftTuple::AccountId(ALICE),
+			None
 		));
 		// Bob remains owner for NFT (0,0)
 		assert_eq!(UNQ::Pallet::<Test>::owner(0, 0), Some(BOB));
@@ -671,7 +944,8 @@ fn send_nft_to_minted_nft_works() {
 				Origin::signed(ALICE),
 				0,
 				1,
-				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(666, 666)
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(666, 666),
+				None
 			),
 			Error::<Test>::NoAvailableNftId
 		);
@@ -694,612 +968,777 @@ fn send_non_transferable_fail() {
 			bvec![0u8; 20],
 			false, // non-transferable
 			None,
+			None,
 		));
 		assert_noop!(
 			RMRKCore::send(
 				Origin::signed(ALICE),
 				0,
 				0,
-				AccountIdOrCollectionNftTuple::AccountId(BOB)
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None
 			),
 			Error::<Test>::NonTransferable
 		);
 	});
 }
 
-#[test]
-fn mint_non_transferrable_gem_on_to_nft_works() {
-	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
-		assert_ok!(basic_collection());
-
-		// Mint NFT (transferrable, will be the parent of a later-minted non-transferrable NFT)
-		assert_ok!(RMRKCore::mint_nft(
-			Origin::signed(ALICE),
-			Some(BOB),
-			NFT_ID_0,
-			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
-			bvec![0u8; 20],
-			true, // transferable
-			None,
-		));
-
-		// Mint non-transferable NFT *on to* Bob-owned NFT (0, 0)
-		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
-			Origin::signed(ALICE),
-			(0, 0),
-			1,
-			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
-			bvec![0u8; 20],
-			false, // non-transferable
-			None,
-		));
-
-		// NFT (0, 1) exists and is non-transferrable
-		assert!(!RMRKCore::nfts(0, 1).unwrap().transferable);
+/// Shortcut for a collection whose soulbound NFTs are controlled by the issuer
+fn soulbound_collection(policy: CollectionPolicy) -> DispatchResult {
+	RMRKCore::create_collection(
+		Origin::signed(ALICE),
+		COLLECTION_ID_0,
+		bvec![0u8; 20],
+		Some(5),
+		bvec![0u8; 15],
+		policy,
+	)
+}
 
-		// NFT (0, 1) is owned by BOB-owned NFT (0, 0)
-		assert_eq!(
-			RMRKCore::nfts(0, 1).unwrap().owner,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0)
-		);
+/// Shortcut for minting a non-transferable NFT to BOB, accepted by BOB
+fn soulbound_mint(id: u32) -> DispatchResult {
+	RMRKCore::mint_nft(
+		Origin::signed(ALICE),
+		Some(BOB),
+		id,
+		COLLECTION_ID_0,
+		Some(ALICE),
+		Some(Permill::from_float(1.525)),
+		bvec![0u8; 20],
+		false,
+		None,
+		None,
+	)?;
+	RMRKCore::accept_nft(
+		Origin::signed(BOB),
+		COLLECTION_ID_0,
+		id,
+		AccountIdOrCollectionNftTuple::AccountId(BOB),
+	)
+}
 
-		// BOB *cannot send* non-transferrable NFT (0, 1) to CHARLIE
+/// NFT: Issuer can change the transferability of NFTs if the collection policy allows it
+#[test]
+fn set_transferable_works() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(soulbound_collection(CollectionPolicy {
+			transferability_mutable: true,
+			revocable: false
+		}));
+		assert_ok!(soulbound_mint(NFT_ID_0));
 		assert_noop!(
 			RMRKCore::send(
 				Origin::signed(BOB),
-				0,
-				1,
-				AccountIdOrCollectionNftTuple::AccountId(CHARLIE)
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+				None
 			),
 			Error::<Test>::NonTransferable
 		);
-
-		// BOB *cannot send* non-transferrable NFT (0, 1) to BOB (his own root account)
+		// Only the issuer can change the transferability
 		assert_noop!(
-			RMRKCore::send(
-				Origin::signed(BOB),
-				0,
-				1,
-				AccountIdOrCollectionNftTuple::AccountId(BOB)
-			),
-			Error::<Test>::NonTransferable
+			RMRKCore::set_transferable(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, true),
+			Error::<Test>::NoPermission
 		);
-
-		// BOB *can* send NFT (0, 0) to CHARLIE
+		assert_ok!(RMRKCore::set_transferable(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			true
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTTransferabilitySet {
+			issuer: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			transferable: true,
+		}));
+		// BOB can now send the NFT
 		assert_ok!(RMRKCore::send(
 			Origin::signed(BOB),
-			0,
-			0,
-			AccountIdOrCollectionNftTuple::AccountId(CHARLIE)
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+			None
 		));
-
-		// CHARLIE now rootowns NFT (0, 1)
-		let budget = budget::Value::new(<Test as Config>::NestingBudget::get());
-		assert_eq!(RMRKCore::lookup_root_owner(0, 1, &budget).unwrap().0, CHARLIE);
+		// The issuer can freeze the NFT again
+		assert_ok!(RMRKCore::set_transferable(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			false
+		));
+		assert!(!RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).unwrap().transferable);
 	});
 }
 
+/// NFT: Transferability can't be changed if the collection policy doesn't allow it
 #[test]
-fn lookup_root_owner_nesting_budget_works() {
+fn set_transferable_fails_for_immutable_collection() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a collection with a minting limit of 10.
-		assert_ok!(RMRKCore::create_collection(
-			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			bvec![0u8; 20],
-			Some(10),
-			bvec![0u8; 15],
-		));
+		assert_ok!(soulbound_collection(CollectionPolicy::default()));
+		assert_ok!(soulbound_mint(NFT_ID_0));
+		assert_noop!(
+			RMRKCore::set_transferable(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, true),
+			Error::<Test>::TransferabilityImmutable
+		);
+	});
+}
 
-		// Mint NFT (transferrable, will be the parent of a later-minted non-transferrable NFT)
+/// NFT: Issuer can revoke non-transferable NFTs of revocable collections
+#[test]
+fn revoke_works() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(soulbound_collection(CollectionPolicy {
+			transferability_mutable: false,
+			revocable: true
+		}));
+		assert_ok!(soulbound_mint(NFT_ID_0));
+		// Transferable NFTs can't be revoked
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
-			NFT_ID_0,
+			NFT_ID_1,
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			true,
 			None,
+			None,
 		));
-
-		// Mint a bunch of nfts(Equal to the `NestingBudget`).
-		for i in 1..=<Test as Config>::NestingBudget::get() {
-			assert_ok!(RMRKCore::mint_nft_directly_to_nft(
-				Origin::signed(ALICE),
-				(COLLECTION_ID_0, i - 1),
-				i,
-				COLLECTION_ID_0,
-				None,
-				None,
-				bvec![0u8; 20],
-				true,
-				None,
-			));
-		}
-
-		// This should fail because the `mint_nft_directly_to_nft` function searches for the root
-		// owner, and it is nested too deep.
 		assert_noop!(
-			RMRKCore::mint_nft_directly_to_nft(
-				Origin::signed(ALICE),
-				(COLLECTION_ID_0, <Test as Config>::NestingBudget::get()),
-				<Test as Config>::NestingBudget::get() + 1,
-				COLLECTION_ID_0,
-				None,
-				None,
-				bvec![0u8; 20],
-				true,
-				None,
-			),
-			Error::<Test>::TooManyRecursions
+			RMRKCore::revoke(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_1),
+			Error::<Test>::CannotRevokeTransferable
 		);
-
-		let budget = budget::Value::new(<Test as Config>::NestingBudget::get());
-		// This should also fail since we are not allowed to nest too many NFTs
+		// Only the issuer can revoke
 		assert_noop!(
-			RMRKCore::lookup_root_owner(
-				COLLECTION_ID_0,
-				<Test as Config>::NestingBudget::get() + 1,
-				&budget
-			),
-			Error::<Test>::NoAvailableNftId
+			RMRKCore::revoke(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoPermission
 		);
-
-		// `lookup_root_owner` should work.
-		let budget = budget::Value::new(<Test as Config>::NestingBudget::get());
-		assert_ok!(RMRKCore::lookup_root_owner(
-			COLLECTION_ID_0,
-			<Test as Config>::NestingBudget::get(),
-			&budget
-		));
+		// ALICE revokes BOB's NFT
+		assert_ok!(RMRKCore::revoke(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTRevoked {
+			issuer: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_0), None);
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 1);
 	});
 }
 
-/// NFT: Reject tests (RMRK2.0 spec: new)
+/// NFT: NFTs of collections that aren't revocable can't be revoked
 #[test]
-fn reject_nft_works() {
+fn revoke_fails_for_non_revocable_collection() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
-		assert_ok!(basic_collection());
-		// Cannot reject non-existent NFT
+		assert_ok!(soulbound_collection(CollectionPolicy::default()));
+		assert_ok!(soulbound_mint(NFT_ID_0));
 		assert_noop!(
-			RMRKCore::reject_nft(Origin::signed(BOB), 0, 2,),
-			Error::<Test>::NoAvailableNftId
+			RMRKCore::revoke(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::CollectionNotRevocable
 		);
-		// Mint NFTs (0, 0), (0, 1), (0, 2)
-		for id in 0..3 {
-			assert_ok!(basic_mint(id));
-		}
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// ALICE sends NFT (0, 2) to BOB
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			2,
-			AccountIdOrCollectionNftTuple::AccountId(BOB),
-		));
-		// ALICE sends NFT (0, 0) to Bob-owned NFT (0,2)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			0,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
-		));
-		// Bob rejects NFT (0,0) for Bob-owned NFT (0,0)
-		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 0,));
-		// Rejected NFT gets burned
-		assert!(RMRKCore::nfts(0, 0).is_none());
-		// Child is burned if parent is rejected
-		assert!(RMRKCore::nfts(0, 1).is_none());
 	});
 }
 
-/// NFT: Reject test: Cannot reject non-pending NFT
+/// NFT: Time-limited NFTs can't be transferred once expired and are burned in `on_idle`
 #[test]
-fn reject_cannot_reject_non_pending_nft() {
+fn expiring_nft_works() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
 		assert_ok!(basic_collection());
-		// ALICE mints (0, 0) for ALICE
-		assert_ok!(RMRKCore::mint_nft(
+		let mint_expiring = |id: u32, expires_at| {
+			RMRKCore::mint_nft(
+				Origin::signed(ALICE),
+				None,
+				id,
+				COLLECTION_ID_0,
+				Some(ALICE),
+				Some(Permill::from_float(1.525)),
+				bvec![0u8; 20],
+				true,
+				None,
+				Some(expires_at),
+			)
+		};
+		// Expiry must be in the future
+		assert_noop!(mint_expiring(NFT_ID_0, 1), Error::<Test>::InvalidExpiry);
+		assert_ok!(mint_expiring(NFT_ID_0, 5));
+		assert_eq!(RMRKCore::nft_expiry(COLLECTION_ID_0, NFT_ID_0), Some(5));
+		// The NFT can be transferred before its expiry
+		System::set_block_number(4);
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			None,
-			NFT_ID_0,
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
-			bvec![0u8; 20],
-			true,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
 			None
 		));
-		// NFT (0, 0) is not pending
-		assert!(!RMRKCore::nfts(0, 0).unwrap().pending);
-		// ALICE cannot reject NFT (0, 0) since it is not pending
+		// Nothing is burned before the expiry
+		RMRKCore::on_idle(4, Weight::MAX);
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_some());
+		// Once expired, the NFT can't be transferred anymore
+		System::set_block_number(5);
 		assert_noop!(
-			RMRKCore::reject_nft(Origin::signed(ALICE), 0, 0),
-			Error::<Test>::CannotRejectNonPendingNft
+			RMRKCore::send(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+				None
+			),
+			Error::<Test>::NftExpired
 		);
-		// NFT (0, 0) still exists after failed rejection
-		assert!(RMRKCore::nfts(0, 0).is_some());
+		// Expiry is kept apart from the locks of the NFT
+		assert!(!RMRKCore::is_locked(COLLECTION_ID_0, NFT_ID_0));
+		// Expired NFTs aren't burned without enough weight
+		assert_eq!(RMRKCore::on_idle(5, Weight::zero()), Weight::zero());
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_some());
+		// The NFT is burned in on_idle
+		RMRKCore::on_idle(5, Weight::MAX);
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTExpired {
+			owner: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_0), None);
+		assert_eq!(RMRKCore::nft_expiry(COLLECTION_ID_0, NFT_ID_0), None);
+		assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(6));
 	});
 }
 
-/// NFT: Reject tests (RMRK2.0 spec: new)
+/// NFT: Burning a time-limited NFT removes its expiry
 #[test]
-fn reject_nft_removes_self_from_parents_children() {
+fn burn_expiring_nft_removes_expiry() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Alice mints (0, 0) for herself
-		assert_ok!(basic_mint(0));
-		// Alice mints (0, 1) for Bob
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
-			Some(BOB),
-			1,
+			None,
+			NFT_ID_0,
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			true,
-			None
-		));
-		// BOB sends NFT (0, 1) to ALICE's NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(BOB),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
+			Some(5),
 		));
-		// ALICE rejects NFT (0, 1)
-		assert_ok!(RMRKCore::reject_nft(Origin::signed(ALICE), 0, 1));
-		// Rejected NFT gets burned
-		assert_eq!(RMRKCore::nfts(0, 1).is_none(), true);
-		assert_eq!(RMRKCore::children((0, 0), (0, 1)).is_none(), true);
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert_eq!(RMRKCore::nft_expiry(COLLECTION_ID_0, NFT_ID_0), None);
+		assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
 	});
 }
 
-/// NFT: Send tests, siblings (RMRK2.0 spec: SEND)
+/// NFT: Lifecycle hooks are called and their errors are returned to the caller
 #[test]
-fn send_two_nfts_to_same_nft_creates_two_children() {
+fn nft_hooks_work() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2)
-		for id in 0..3 {
-			assert_ok!(basic_mint(id));
-		}
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// NFT (0,0) has NFT (0,1) in Children StorageMap
-		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
-		// ALICE sends NFT (0, 2) to NFT (0, 0)
+		let mints_before = HookedMints::get();
+		assert_ok!(basic_mint(NFT_ID_0));
+		assert_ok!(basic_mint(HOOK_DENIED_NFT_ID));
+		// The mint hook saw both mints
+		assert_eq!(HookedMints::get(), mints_before + 2);
 		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
 		));
-		// NFT (0,0) has NFT (0,1) & (0,2) in Children StorageMap
-		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
-		assert!(RMRKCore::children((0, 0), (0, 2)).is_some());
+		// The error of the transfer hook is returned as is
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				HOOK_DENIED_NFT_ID,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None
+			),
+			DispatchError::Other("Transfer denied by hook")
+		);
 	});
 }
 
-/// NFT: Send tests, removing parent (RMRK2.0 spec: SEND)
+/// NFT: An NFT stays locked while any lock reason is active
 #[test]
-fn send_nft_removes_existing_parent() {
+fn lock_reasons_work() {
 	ExtBuilder::build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
-		for id in 0..4 {
-			assert_ok!(basic_mint(id));
-		}
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// ALICE sends NFT (0, 2) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// NFT (0, 0) is parent of NFT (0, 1)
-		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
-		assert!(RMRKCore::children((0, 0), (0, 2)).is_some());
-		// ALICE sends NFT (0, 1) to NFT (0, 2)
+		assert_ok!(basic_mint(NFT_ID_0));
+		// Two pallets lock the NFT for their own reasons
+		assert_ok!(RMRKCore::set_lock(*b"staking ", (COLLECTION_ID_0, NFT_ID_0)));
+		assert_ok!(RMRKCore::set_lock(*b"rental  ", (COLLECTION_ID_0, NFT_ID_0)));
+		// Setting the same reason twice is a no-op
+		assert_ok!(RMRKCore::set_lock(*b"rental  ", (COLLECTION_ID_0, NFT_ID_0)));
+		assert_eq!(RMRKCore::locks((COLLECTION_ID_0, NFT_ID_0)).len(), 2);
+		// Removing one reason keeps the NFT locked
+		RMRKCore::remove_lock(*b"rental  ", (COLLECTION_ID_0, NFT_ID_0));
+		assert!(RMRKCore::is_locked(COLLECTION_ID_0, NFT_ID_0));
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None
+			),
+			pallet_uniques::Error::<Test>::Locked
+		);
+		// Removing an unknown reason doesn't affect the other locks
+		RMRKCore::remove_lock(*b"unknown ", (COLLECTION_ID_0, NFT_ID_0));
+		assert!(RMRKCore::is_locked(COLLECTION_ID_0, NFT_ID_0));
+		// Once the last reason is removed the NFT can be sent
+		RMRKCore::remove_lock(*b"staking ", (COLLECTION_ID_0, NFT_ID_0));
+		assert!(!RMRKCore::is_locked(COLLECTION_ID_0, NFT_ID_0));
+		assert!(!Locks::<Test>::contains_key((COLLECTION_ID_0, NFT_ID_0)));
 		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
 		));
-		// NFT (0, 0) is no longer parent of NFT (0, 1)
-		assert!(RMRKCore::children((0, 0), (0, 1)).is_none());
+		// The number of lock reasons is bounded by MaxLocks
+		assert_ok!(RMRKCore::set_lock(*b"lock0000", (COLLECTION_ID_0, NFT_ID_0)));
+		assert_ok!(RMRKCore::set_lock(*b"lock0001", (COLLECTION_ID_0, NFT_ID_0)));
+		assert_ok!(RMRKCore::set_lock(*b"lock0002", (COLLECTION_ID_0, NFT_ID_0)));
+		assert_noop!(
+			RMRKCore::set_lock(*b"lock0003", (COLLECTION_ID_0, NFT_ID_0)),
+			Error::<Test>::TooManyLocks
+		);
 	});
 }
 
-/// NFT: Send tests, multi-generational circular testing (RMRK2.0 spec: SEND)
+/// NFT: Approved delegate can send an NFT once
 #[test]
-fn send_to_grandchild_fails() {
+fn send_nft_by_approved_delegate_works() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
+		// Create a basic collection and mint 2 NFTs
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2)
-		for id in 0..3 {
-			assert_ok!(basic_mint(id));
-		}
-		// Alice sends NFT (0, 1) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		// BOB can't send ALICE's NFT
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+		// Only the root owner can approve
+		assert_noop!(
+			RMRKCore::approve_transfer(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, BOB, None),
+			Error::<Test>::NoPermission
+		);
+		// Expiry can't be in the past
+		assert_noop!(
+			RMRKCore::approve_transfer(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BOB,
+				Some(1)
+			),
+			Error::<Test>::ApprovalExpired
+		);
+		// ALICE approves BOB to transfer NFT (0, 0)
+		assert_ok!(RMRKCore::approve_transfer(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BOB,
+			None
 		));
-		// Alice sends NFT (0, 2) to NFT (0, 1)
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::TransferApproved {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			delegate: BOB,
+			expires: None,
+		}));
+		// The approval doesn't cover other NFTs
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_1,
+				AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+		// BOB sends NFT (0, 0) to CHARLIE
 		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+			None,
 		));
-		// Sending NFT to its own grandchild should fail
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_0), Some(CHARLIE));
+		// Approval is cleared on transfer
+		assert_eq!(RMRKCore::approvals((COLLECTION_ID_0, NFT_ID_0)), None);
 		assert_noop!(
 			RMRKCore::send(
-				Origin::signed(ALICE),
-				0,
-				0,
-				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None,
 			),
-			Error::<Test>::CannotSendToDescendentOrSelf
+			Error::<Test>::NoPermission
 		);
 	});
 }
 
-/// NFT: Burn simple tests (RMRK2.0 spec: BURN)
+/// NFT: Expired and cancelled approvals can't be used
 #[test]
-fn burn_nft_works() {
+fn approval_expiry_and_cancel_works() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
+		// Create a basic collection and mint an NFT
 		assert_ok!(basic_collection());
-		// Mint an NFT
 		assert_ok!(basic_mint(0));
-
-		// Add a property to the NFT to see whether it will also get deleted
-		let key = stbk("test-key");
-		let value = stb("test-value");
-		assert_ok!(RMRKCore::set_property(
-			Origin::signed(ALICE),
-			0,
-			Some(0),
-			key.clone(),
-			value.clone()
-		));
-
-		// Add two resources to NFT (to test if burning also burns the resources)
-
-		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
-
-		assert_ok!(RMRKCore::add_basic_resource(
+		// Nothing to cancel
+		assert_noop!(
+			RMRKCore::cancel_approval(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoApproval
+		);
+		// ALICE approves BOB until block 10
+		assert_ok!(RMRKCore::approve_transfer(
 			Origin::signed(ALICE),
-			0,
-			0,
-			basic_resource.clone(),
-			0
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BOB,
+			Some(10)
 		));
-
-		assert_ok!(RMRKCore::add_basic_resource(Origin::signed(ALICE), 0, 0, basic_resource, 1));
-
-		// Ensure resources are there
-		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 2);
-
-		// BOB should not be able to burn ALICE's NFT
+		System::set_block_number(10);
 		assert_noop!(
-			RMRKCore::burn_nft(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0),
+			RMRKCore::send(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None,
+			),
 			Error::<Test>::NoPermission
 		);
-		// ALICE burns her NFT
-		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
-		// Successful burn creates NFTBurned event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTBurned {
+		// CHARLIE can't cancel the approval
+		assert_noop!(
+			RMRKCore::cancel_approval(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoPermission
+		);
+		// The delegate can cancel the approval
+		assert_ok!(RMRKCore::cancel_approval(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ApprovalCancelled {
 			owner: ALICE,
-			nft_id: 0,
-			collection_id: 0,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			delegate: BOB,
 		}));
-		// NFT count of collection is now 0
-		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 0);
-
-		// Once the NFT is burned the property should be deleted
-		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())), None);
+		assert_eq!(RMRKCore::approvals((COLLECTION_ID_0, NFT_ID_0)), None);
+	});
+}
 
-		// ALICE can't burn an NFT twice
+/// NFT: Operators can send all of the NFTs of an account
+#[test]
+fn send_nft_by_operator_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection and mint 2 NFTs, NFT (0, 1) is a child of NFT (0, 0)
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+			None,
+		));
+		// ALICE sets BOB as operator
+		assert_ok!(RMRKCore::set_operator(Origin::signed(ALICE), BOB, true));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::OperatorSet {
+			owner: ALICE,
+			operator: BOB,
+			approved: true,
+		}));
+		// BOB can send the nested NFT out to ALICE
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE),
+			None,
+		));
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_1), Some(ALICE));
+		// ALICE removes BOB as operator
+		assert_ok!(RMRKCore::set_operator(Origin::signed(ALICE), BOB, false));
 		assert_noop!(
-			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
-			Error::<Test>::NoAvailableNftId
+			RMRKCore::send(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None,
+			),
+			Error::<Test>::NoPermission
 		);
-		// Burned NFT no longer exists
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none(), true);
-		// Resources associated with the NFT should no longer exist
-		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 0);
 	});
 }
 
-/// NFT: Burn complex multi-generational tests (RMRK2.0 spec: BURN)
+/// NFT: Send a batch of NFTs to accounts and NFTs
 #[test]
-fn burn_nft_with_great_grandchildren_works() {
+fn send_batch_works() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
+		// Create a basic collection and mint 4 NFTs
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
 		for id in 0..4 {
 			assert_ok!(basic_mint(id));
 		}
+		// Sending NFT (0, 0) into NFT (0, 1) and NFT (0, 1) into NFT (0, 0) creates a cycle
+		assert_noop!(
+			RMRKCore::send_batch(
+				Origin::signed(ALICE),
+				bvec![
+					(
+						COLLECTION_ID_0,
+						0,
+						AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 1)
+					),
+					(
+						COLLECTION_ID_0,
+						1,
+						AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 0)
+					),
+				]
+			),
+			Error::<Test>::CannotSendToDescendentOrSelf
+		);
+		// Batch fails as a whole if any item fails
+		assert_noop!(
+			RMRKCore::send_batch(
+				Origin::signed(ALICE),
+				bvec![
+					(COLLECTION_ID_0, 0, AccountIdOrCollectionNftTuple::AccountId(BOB)),
+					(COLLECTION_ID_0, 99, AccountIdOrCollectionNftTuple::AccountId(BOB)),
+				]
+			),
+			Error::<Test>::NoAvailableNftId
+		);
+		// Send NFT (0, 1) into NFT (0, 0), then NFT (0, 2) into NFT (0, 1), and NFT (0, 3) to BOB
+		assert_ok!(RMRKCore::send_batch(
+			Origin::signed(ALICE),
+			bvec![
+				(
+					COLLECTION_ID_0,
+					1,
+					AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 0)
+				),
+				(
+					COLLECTION_ID_0,
+					2,
+					AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 1)
+				),
+				(COLLECTION_ID_0, 3, AccountIdOrCollectionNftTuple::AccountId(BOB)),
+			]
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTSent {
+			sender: ALICE,
+			recipient: AccountIdOrCollectionNftTuple::AccountId(BOB),
+			collection_id: COLLECTION_ID_0,
+			nft_id: 3,
+			approval_required: false,
+		}));
+		assert!(RMRKCore::children((COLLECTION_ID_0, 0), (COLLECTION_ID_0, 1)).is_some());
+		assert!(RMRKCore::children((COLLECTION_ID_0, 1), (COLLECTION_ID_0, 2)).is_some());
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, 3), Some(BOB));
+	});
+}
 
-		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+#[test]
+fn mint_non_transferrable_gem_on_to_nft_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
 
-		// Add two resources to the great-grandchild (0, 3)
-		assert_ok!(RMRKCore::add_basic_resource(
+		// Mint NFT (transferrable, will be the parent of a later-minted non-transferrable NFT)
+		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
+			Some(BOB),
+			NFT_ID_0,
 			COLLECTION_ID_0,
-			3,
-			basic_resource.clone(),
-			0
+			Some(ALICE),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true, // transferable
+			None,
+			None,
 		));
 
-		assert_ok!(RMRKCore::add_basic_resource(
+		// Mint non-transferable NFT *on to* Bob-owned NFT (0, 0)
+		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
 			Origin::signed(ALICE),
+			(0, 0),
+			1,
 			COLLECTION_ID_0,
-			3,
-			basic_resource,
-			1
+			Some(ALICE),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			false, // non-transferable
+			None,
+			None,
 		));
 
-		// Ensure resources are there
-		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, 3)).count(), 2);
+		// NFT (0, 1) exists and is non-transferrable
+		assert!(!RMRKCore::nfts(0, 1).unwrap().transferable);
 
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		// NFT (0, 1) is owned by BOB-owned NFT (0, 0)
+		assert_eq!(
+			RMRKCore::nfts(0, 1).unwrap().owner,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0)
+		);
+
+		// BOB *cannot send* non-transferrable NFT (0, 1) to CHARLIE
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(BOB),
+				0,
+				1,
+				AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+				None
+			),
+			Error::<Test>::NonTransferable
+		);
+
+		// BOB *cannot send* non-transferrable NFT (0, 1) to BOB (his own root account)
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(BOB),
+				0,
+				1,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None
+			),
+			Error::<Test>::NonTransferable
+		);
+
+		// BOB *can* send NFT (0, 0) to CHARLIE
 		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
+			Origin::signed(BOB),
 			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// ALICE sends NFT (0, 2) to NFT (0, 1)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
 			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+			None
 		));
-		// ALICE sends NFT (0, 3) to NFT (0, 2)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			3,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
-		));
-		// Great-grandchild NFT (0, 3) exists
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
-		// Burn great-grandparent NFT (0, 0)
-		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
-		// Great-grandchild NFT (0, 3) is dead :'-(
-		assert!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_none());
-		// Great-grandchild resources are gone
-		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, 3)).count(), 0);
+
+		// CHARLIE now rootowns NFT (0, 1)
+		let budget = budget::Value::new(<Test as Config>::NestingBudget::get());
+		assert_eq!(RMRKCore::lookup_root_owner(0, 1, &budget).unwrap().0, CHARLIE);
 	});
 }
 
-/// NFT: Burn beyond max_recursions fails gracefully
 #[test]
-fn burn_nft_beyond_max_recursions_fails_gracefully() {
+fn lookup_root_owner_nesting_budget_works() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
-		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
-		for id in 0..5 {
-			assert_ok!(basic_mint(id));
-		}
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// ALICE sends NFT (0, 2) to NFT (0, 1)
-		assert_ok!(RMRKCore::send(
+		// Create a collection with a minting limit of 10.
+		assert_ok!(RMRKCore::create_collection(
 			Origin::signed(ALICE),
-			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			COLLECTION_ID_0,
+			bvec![0u8; 20],
+			Some(10),
+			bvec![0u8; 15],
+			CollectionPolicy::default(),
 		));
-		// ALICE sends NFT (0, 3) to NFT (0, 2)
-		assert_ok!(RMRKCore::send(
+
+		// Mint NFT (transferrable, will be the parent of a later-minted non-transferrable NFT)
+		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
-			0,
-			3,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			Some(BOB),
+			NFT_ID_0,
+			COLLECTION_ID_0,
+			Some(ALICE),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			None,
+			None,
 		));
-		// ALICE sends NFT (0, 4) to NFT (0, 3) fails bc the nested budget threshold is exceeded
+
+		// Mint a bunch of nfts(Equal to the `NestingBudget`).
+		for i in 1..=<Test as Config>::NestingBudget::get() {
+			assert_ok!(RMRKCore::mint_nft_directly_to_nft(
+				Origin::signed(ALICE),
+				(COLLECTION_ID_0, i - 1),
+				i,
+				COLLECTION_ID_0,
+				None,
+				None,
+				bvec![0u8; 20],
+				true,
+				None,
+				None,
+			));
+		}
+
+		// This should fail because the `mint_nft_directly_to_nft` function searches for the root
+		// owner, and it is nested too deep.
 		assert_noop!(
-			RMRKCore::send(
+			RMRKCore::mint_nft_directly_to_nft(
 				Origin::signed(ALICE),
-				0,
-				4,
-				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 3),
+				(COLLECTION_ID_0, <Test as Config>::NestingBudget::get()),
+				<Test as Config>::NestingBudget::get() + 1,
+				COLLECTION_ID_0,
+				None,
+				None,
+				bvec![0u8; 20],
+				true,
+				None,
+				None,
 			),
 			Error::<Test>::TooManyRecursions
 		);
-		// ALICE sends NFT (0, 4) to NFT (0, 0) succeeds since the budget threshold is not exceeded
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			4,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// All NFTs exist
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
-		// Burn great-grandparent NFT (0, 0)
+
+		let budget = budget::Value::new(<Test as Config>::NestingBudget::get());
+		// This should also fail since we are not allowed to nest too many NFTs
 		assert_noop!(
-			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, 0),
-			Error::<Test>::TooManyRecursions
-		);
-		// All NFTs still exist
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
-		// Burn great-grandparent NFT (0,1) successfully
-		assert_ok!(
-			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, 1),
-			//Error::<Test>::TooManyRecursions
+			RMRKCore::lookup_root_owner(
+				COLLECTION_ID_0,
+				<Test as Config>::NestingBudget::get() + 1,
+				&budget
+			),
+			Error::<Test>::NoAvailableNftId
 		);
-		// NFTs [0,1], [0,2], [0,3] are burned
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), false);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), false);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), false);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
+
+		// `lookup_root_owner` should work.
+		let budget = budget::Value::new(<Test as Config>::NestingBudget::get());
+		assert_ok!(RMRKCore::lookup_root_owner(
+			COLLECTION_ID_0,
+			<Test as Config>::NestingBudget::get(),
+			&budget
+		));
 	});
 }
 
-/// NFT: Burn child removes NFT from owner-NFT's Children list
+/// NFT: Reject tests (RMRK2.0 spec: new)
 #[test]
-fn burn_child_nft_removes_parents_children() {
+fn reject_nft_works() {
 	ExtBuilder::build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
-		for id in 0..2 {
+		// Cannot reject non-existent NFT
+		assert_noop!(
+			RMRKCore::reject_nft(Origin::signed(BOB), 0, 2, true),
+			Error::<Test>::NoAvailableNftId
+		);
+		// Mint NFTs (0, 0), (0, 1), (0, 2)
+		for id in 0..3 {
 			assert_ok!(basic_mint(id));
 		}
 		// ALICE sends NFT (0, 1) to NFT (0, 0)
@@ -1308,688 +1747,3576 @@ fn burn_child_nft_removes_parents_children() {
 			0,
 			1,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
 		));
-		// All NFTs exist
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
-		// NFT (0, 0) should have 1 Children storage member
-		assert_eq!(Children::<Test>::iter_prefix((0, 0)).count(), 1);
-		// Burn NFT (0, 1)
-		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), 0, 1),);
-		// NFT (0, 0) should have 0 Children storage members
-		assert_eq!(Children::<Test>::iter_prefix((0, 0)).count(), 0);
+		// ALICE sends NFT (0, 2) to BOB
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		// ALICE sends NFT (0, 0) to Bob-owned NFT (0,2)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			None,
+		));
+		// Bob rejects NFT (0,0) for Bob-owned NFT (0,0) and burns it
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 0, true));
+		// Rejected NFT gets burned
+		assert!(RMRKCore::nfts(0, 0).is_none());
+		// Child is burned if parent is rejected
+		assert!(RMRKCore::nfts(0, 1).is_none());
 	});
 }
 
-/// Resource: Basic resource addition (RMRK2.0 spec: RESADD)
+/// NFT: Expired pending NFT is sent back to its sender
 #[test]
-fn create_resource_works() {
+fn expire_pending_nft_works() {
 	ExtBuilder::build().execute_with(|| {
-		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
-
-		// Adding a resource to non-existent NFT should fail
-		assert_noop!(
-			RMRKCore::add_basic_resource(
-				Origin::signed(ALICE),
-				0, // collection_id
-				0, // nft_id
-				basic_resource,
-				0,
-			),
-			Error::<Test>::CollectionUnknown
-		);
-		// Create a basic collection
+		// Create a basic collection and mint NFTs (0, 0), (0, 1)
 		assert_ok!(basic_collection());
-		// Mint NFT
 		assert_ok!(basic_mint(0));
-
-		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
-
-		// Add resource to NFT
-		assert_ok!(RMRKCore::add_basic_resource(
+		assert_ok!(basic_mint(1));
+		// ALICE sends NFT (0, 1) to BOB
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
 			COLLECTION_ID_0,
-			NFT_ID_0,
-			basic_resource,
-			0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
 		));
-		// Successful resource addition should trigger ResourceAdded event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
-			nft_id: 0,
-			resource_id: 0, // resource_id
-			collection_id: 0,
-		}));
-		// Since ALICE rootowns NFT, pending status of resource should be false
-		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
-
-		// Create Composable resource
-		let composable_resource = ComposableResource {
-			parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
-			base: 0,                               // BaseID
-			metadata: None,
-			slot: None,
-		};
-
-		// Composable resource addition works
-		assert_ok!(RMRKCore::add_composable_resource(
+		// Non-pending NFT can't be expired
+		assert_noop!(
+			RMRKCore::set_pending_expiry(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, None, 5),
+			Error::<Test>::NftNotPending
+		);
+		// ALICE sends NFT (0, 0) to BOB-owned NFT (0, 1)
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
 			COLLECTION_ID_0,
 			NFT_ID_0,
-			composable_resource,
-			1
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_1),
+			None,
 		));
-
-		// Create Slot resource
-		let slot_resource = SlotResource {
-			base: 0, // BaseID
-			metadata: None,
-			slot: 0, // SlotID
-		};
-
-		// Slot resource addition works
-		assert_ok!(RMRKCore::add_slot_resource(
+		assert_eq!(RMRKCore::pending_transfers((COLLECTION_ID_0, NFT_ID_0)).unwrap().sender, ALICE);
+		// No expiry set yet
+		assert_noop!(
+			RMRKCore::expire_pending(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0, None),
+			Error::<Test>::NoPendingExpiry
+		);
+		// Only the sender can set the expiry
+		assert_noop!(
+			RMRKCore::set_pending_expiry(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, None, 5),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::set_pending_expiry(
 			Origin::signed(ALICE),
 			COLLECTION_ID_0,
 			NFT_ID_0,
-			slot_resource,
-			2
+			None,
+			5
 		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PendingExpirySet {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			maybe_resource_id: None,
+			expires: 5,
+		}));
+		// Can't expire before the expiry
+		System::set_block_number(4);
+		assert_noop!(
+			RMRKCore::expire_pending(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0, None),
+			Error::<Test>::PendingNotExpired
+		);
+		// Anyone can expire the pending NFT after the expiry
+		System::set_block_number(5);
+		assert_ok!(RMRKCore::expire_pending(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			None
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PendingNftExpired {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			returned_to: AccountIdOrCollectionNftTuple::AccountId(ALICE),
+		}));
+		// NFT is back to ALICE and is no longer pending
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_0), Some(ALICE));
+		let nft = RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).unwrap();
+		assert_eq!(nft.owner, AccountIdOrCollectionNftTuple::AccountId(ALICE));
+		assert!(!nft.pending);
+		assert!(
+			RMRKCore::children((COLLECTION_ID_0, NFT_ID_1), (COLLECTION_ID_0, NFT_ID_0)).is_none()
+		);
+		assert_eq!(RMRKCore::pending_transfers((COLLECTION_ID_0, NFT_ID_0)), None);
 	});
 }
 
-/// Minting with resources works
+/// Resource: Expired pending resource addition and removal are dropped
 #[test]
-fn add_resource_on_mint_works() {
+fn expire_pending_resource_works() {
 	ExtBuilder::build().execute_with(|| {
-		let basic_resource: BasicResource<BoundedVec<u8, UniquesStringLimit>> =
-			BasicResource { metadata: stbd("bafybeiakahlc6") };
-
-		// Create a basic collection
+		// Create a basic collection, mint NFT (0, 0) and send it to BOB
 		assert_ok!(basic_collection());
-
-		// Resources to add
-		let resources_to_add = bvec![
-			ResourceInfoMin { id: 0, resource: ResourceTypes::Basic(basic_resource.clone()) },
-			ResourceInfoMin { id: 1, resource: ResourceTypes::Basic(basic_resource) },
-		];
-
-		// Mint NFT
-		assert_ok!(RMRKCore::mint_nft(
+		assert_ok!(basic_mint(0));
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			Some(ALICE),
+			COLLECTION_ID_0,
 			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		// ALICE adds resources 0 and 1, BOB accepts resource 1
+		for resource_id in 0..2 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None,
+			));
+		}
+		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 1));
+		// Accepted resource can't be expired
+		assert_noop!(
+			RMRKCore::set_pending_expiry(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				Some(1),
+				5
+			),
+			Error::<Test>::ResourceNotPending
+		);
+		// ALICE proposes to remove resource 1
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 1));
+		// Only the issuer can set the expiry of a resource
+		assert_noop!(
+			RMRKCore::set_pending_expiry(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				Some(0),
+				5
+			),
+			Error::<Test>::NoPermission
+		);
+		for resource_id in 0..2 {
+			assert_ok!(RMRKCore::set_pending_expiry(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				Some(resource_id),
+				5
+			));
+		}
+		assert_noop!(
+			RMRKCore::expire_pending(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0, Some(0)),
+			Error::<Test>::PendingNotExpired
+		);
+		System::set_block_number(5);
+		// Pending resource addition is removed
+		assert_ok!(RMRKCore::expire_pending(
+			Origin::signed(CHARLIE),
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
-			bvec![0u8; 20],
-			true,
-			Some(resources_to_add),
+			NFT_ID_0,
+			Some(0)
 		));
-
-		assert_eq!(RMRKCore::resources((0, 0, 0)).is_some(), true);
-		assert_eq!(RMRKCore::resources((0, 0, 1)).is_some(), true);
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PendingResourceExpired {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+		}));
+		assert!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+		// Pending resource removal is dropped and the resource is kept
+		assert_ok!(RMRKCore::expire_pending(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			Some(1)
+		));
+		assert!(!RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 1)).unwrap().pending_removal);
+		assert_eq!(RMRKCore::pending_resource_expiry((COLLECTION_ID_0, NFT_ID_0, 1)), None);
 	});
 }
 
-/// Minting with more than max resources (set to 3 in mock) should panic
-#[should_panic]
+/// NFT: Rejected NFT goes back to its previous owner with its children
 #[test]
-fn add_resource_on_mint_beyond_max_fails() {
+fn reject_nft_returns_to_previous_owner() {
 	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
+		// Create a basic collection and mint NFTs (0, 0) to (0, 3)
 		assert_ok!(basic_collection());
+		for id in 0..4 {
+			assert_ok!(basic_mint(id));
+		}
+		// NFT (0, 2) is a child of NFT (0, 1) which is a child of NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			None,
+		));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
+		));
+		// ALICE sends NFT (0, 3) to BOB
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			3,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		// ALICE sends NFT (0, 1) with its child out of NFT (0, 0) into BOB-owned NFT (0, 3)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 3),
+			None,
+		));
+		assert!(RMRKCore::nfts(0, 1).unwrap().pending);
+		// BOB rejects NFT (0, 1) without burning it
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 1, false));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTRejected {
+			sender: BOB,
+			collection_id: 0,
+			nft_id: 1,
+			burned: false,
+		}));
+		// NFT (0, 1) is back in NFT (0, 0) along with its child
+		let nft = RMRKCore::nfts(0, 1).unwrap();
+		assert_eq!(nft.owner, AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0));
+		assert!(!nft.pending);
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
+		assert!(RMRKCore::children((0, 3), (0, 1)).is_none());
+		assert!(RMRKCore::children((0, 1), (0, 2)).is_some());
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 1), Some(RMRKCore::nft_to_account_id(0, 0)));
+		assert_eq!(RMRKCore::pending_transfers((0, 1)), None);
 
-		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
-
-		// Resources to add
-		let resources_to_add = bvec![
-			{ ResourceInfoMin { resource: ResourceTypes::Basic(basic_resource.clone()), id: 0 } },
-			{ ResourceInfoMin { resource: ResourceTypes::Basic(basic_resource.clone()), id: 1 } },
-			{ ResourceInfoMin { resource: ResourceTypes::Basic(basic_resource.clone()), id: 2 } },
-			{ ResourceInfoMin { resource: ResourceTypes::Basic(basic_resource), id: 3 } },
-		];
+		// ALICE sends NFT (0, 1) to BOB-owned NFT (0, 3) again, then sends NFT (0, 0) to BOB
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 3),
+			None,
+		));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		// ALICE no longer owns NFT (0, 0), so the rejected NFT goes back to ALICE's account
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 1, false));
+		assert_eq!(
+			RMRKCore::nfts(0, 1).unwrap().owner,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE)
+		);
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 1), Some(ALICE));
+		assert!(RMRKCore::children((0, 1), (0, 2)).is_some());
+	});
+}
 
-		// Mint NFT
+/// NFT: Reject test: Cannot reject non-pending NFT
+#[test]
+fn reject_cannot_reject_non_pending_nft() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// ALICE mints (0, 0) for ALICE
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
-			Some(ALICE),
+			None,
 			NFT_ID_0,
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			true,
-			Some(resources_to_add),
+			None,
+			None,
 		));
+		// NFT (0, 0) is not pending
+		assert!(!RMRKCore::nfts(0, 0).unwrap().pending);
+		// ALICE cannot reject NFT (0, 0) since it is not pending
+		assert_noop!(
+			RMRKCore::reject_nft(Origin::signed(ALICE), 0, 0, false),
+			Error::<Test>::CannotRejectNonPendingNft
+		);
+		// NFT (0, 0) still exists after failed rejection
+		assert!(RMRKCore::nfts(0, 0).is_some());
 	});
 }
 
-/// Resource: Resource addition with pending and accept (RMRK2.0 spec: ACCEPT)
+/// NFT: Reject tests (RMRK2.0 spec: new)
 #[test]
-fn add_resource_pending_works() {
+fn reject_nft_removes_self_from_parents_children() {
 	ExtBuilder::build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
+		// Alice mints (0, 0) for herself
+		assert_ok!(basic_mint(0));
+		// Alice mints (0, 1) for Bob
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
-			NFT_ID_0,
+			1,
 			COLLECTION_ID_0,
-			Some(BOB),
+			Some(ALICE),
 			Some(Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			true,
-			None
+			None,
+			None,
 		));
-
-		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
-
-		// Since BOB isn't collection issuer, he can't add resources
-		assert_noop!(
-			RMRKCore::add_basic_resource(
-				Origin::signed(BOB),
-				COLLECTION_ID_0,
-				NFT_ID_0,
-				basic_resource.clone(),
-				0,
-			),
-			Error::<Test>::NoPermission
-		);
-
-		// Collection issuer can add resource
-		assert_ok!(RMRKCore::add_basic_resource(
-			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			basic_resource,
+		// BOB sends NFT (0, 1) to ALICE's NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
 			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
 		));
-
-		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, true);
-		// ALICE doesn't own BOB's NFT, so accept should fail
-		assert_noop!(
-			RMRKCore::accept_resource(Origin::signed(ALICE), 0, 0, 0),
-			Error::<Test>::NoPermission
-		);
-		// BOB can accept his own NFT's pending resource
-		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0));
-		// Valid resource acceptance should trigger a ResourceAccepted event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAccepted {
-			nft_id: 0,
-			resource_id: 0, // resource_id
-			collection_id: 0,
-		}));
-		// Resource should now have false pending status
-		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
-		// Accepting resource again should fail with ResourceNotPending
-		assert_noop!(
-			RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0),
-			Error::<Test>::ResourceNotPending
-		);
+		// ALICE rejects NFT (0, 1) and burns it
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(ALICE), 0, 1, true));
+		// Rejected NFT gets burned
+		assert_eq!(RMRKCore::nfts(0, 1).is_none(), true);
+		assert_eq!(RMRKCore::children((0, 0), (0, 1)).is_none(), true);
 	});
 }
 
-/// Resource: Basic resource removal
+/// NFT: Send tests, siblings (RMRK2.0 spec: SEND)
 #[test]
-fn resource_removal_works() {
+fn send_two_nfts_to_same_nft_creates_two_children() {
 	ExtBuilder::build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
-		assert_ok!(basic_mint(0));
-
-		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
-
-		// Add resource to NFT
-		assert_ok!(RMRKCore::add_basic_resource(
+		// Mint NFTs (0, 0), (0, 1), (0, 2)
+		for id in 0..3 {
+			assert_ok!(basic_mint(id));
+		}
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			basic_resource,
-			0
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
 		));
-		// Resource res-1 doesn't exist
-		assert_noop!(
-			RMRKCore::remove_resource(
-				Origin::signed(ALICE),
-				COLLECTION_ID_0,
-				NFT_ID_0,
-				1, // resource_id
-			),
-			Error::<Test>::ResourceDoesntExist
-		);
-		// Only collection issuer can request resource removal
-		assert_noop!(
-			RMRKCore::remove_resource(
-				Origin::signed(BOB),
-				COLLECTION_ID_0,
-				NFT_ID_0,
-				0, // resource_id
-			),
-			Error::<Test>::NoPermission
-		);
-		// Remove resource
-		assert_ok!(RMRKCore::remove_resource(
-			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			0, // resource_id
-		));
-		// Successful resource removal should trigger ResourceRemoval event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemoval {
-			nft_id: 0,
-			resource_id: 0, // resource_id
-			collection_id: 0,
-		}));
-		// Since ALICE rootowns NFT, resource should be removed
-		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
-
-		let base_id = 11;
-		let slot_id = 22;
-		let resource_id = 10;
-
-		// Create Composable resource
-		let composable_resource = ComposableResource {
-			parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
-			base: base_id,                         // base_id
-			metadata: None,
-			slot: Some((base_id, slot_id)),
-		};
-
-		// Add composable resource to NFT
-		assert_ok!(RMRKCore::add_composable_resource(
-			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			composable_resource,
-			resource_id
-		));
-
-		// Values should now exist in EquippableBases and EquippableSlots
-		assert!(EquippableBases::<Test>::get((COLLECTION_ID_0, NFT_ID_0, base_id)).is_some());
-		assert!(EquippableSlots::<Test>::get((
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			resource_id,
-			base_id,
-			slot_id
-		))
-		.is_some());
-
-		// Remove resource
-		assert_ok!(RMRKCore::remove_resource(
+		// NFT (0,0) has NFT (0,1) in Children StorageMap
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
+		// ALICE sends NFT (0, 2) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			resource_id,
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
 		));
-
-		assert!(EquippableBases::<Test>::get((COLLECTION_ID_0, NFT_ID_0, base_id)).is_none());
-		assert!(EquippableSlots::<Test>::get((
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			resource_id,
-			base_id,
-			slot_id
-		))
-		.is_none());
+		// NFT (0,0) has NFT (0,1) & (0,2) in Children StorageMap
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
+		assert!(RMRKCore::children((0, 0), (0, 2)).is_some());
 	});
 }
 
+/// NFT: Send tests, removing parent (RMRK2.0 spec: SEND)
 #[test]
-fn resource_replace_works() {
+fn send_nft_removes_existing_parent() {
 	ExtBuilder::build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
-		assert_ok!(basic_mint(0));
-		// Add resource to NFT
-		assert_ok!(RMRKCore::add_basic_resource(
-			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			BasicResource { metadata: stbd("bafybeiakahlc6") },
-			0
-		));
-
-		// Replace to Basic resource
-		assert_ok!(RMRKCore::replace_resource(
+		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
+		for id in 0..4 {
+			assert_ok!(basic_mint(id));
+		}
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			ResourceTypes::Basic(BasicResource { metadata: stbd("new_meta") }), // new_resource
-			0,                                                                  // resource_id
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
 		));
-
-		// Replace to Composable resource
-		assert_ok!(RMRKCore::replace_resource(
+		// ALICE sends NFT (0, 2) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			ResourceTypes::Composable(ComposableResource {
-				parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
-				base: 0,                               // BaseID
-				metadata: None,
-				slot: None,
-			}), // new_resource
-			0, // resource_id
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
 		));
-
-		// Replace to Slot resource
-		assert_ok!(RMRKCore::replace_resource(
+		// NFT (0, 0) is parent of NFT (0, 1)
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
+		assert!(RMRKCore::children((0, 0), (0, 2)).is_some());
+		// ALICE sends NFT (0, 1) to NFT (0, 2)
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			ResourceTypes::Slot(SlotResource {
-				base: 0,
-				metadata: Some(stbd("new_meta_slot")),
-				slot: 0
-			}), // new_resource
-			0, // resource_id
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			None,
 		));
-
-		// Successful resource replace should trigger ResourceReplaced event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceReplaced {
-			nft_id: 0,
-			resource_id: 0,
-			collection_id: 0,
-		}));
+		// NFT (0, 0) is no longer parent of NFT (0, 1)
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_none());
 	});
 }
 
+/// NFT: Send tests, multi-generational circular testing (RMRK2.0 spec: SEND)
 #[test]
-fn resource_replace_non_exist_resource() {
+fn send_to_grandchild_fails() {
 	ExtBuilder::build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
-		assert_ok!(basic_mint(0));
-		// Add resource to NFT
-		assert_ok!(RMRKCore::add_basic_resource(
+		// Mint NFTs (0, 0), (0, 1), (0, 2)
+		for id in 0..3 {
+			assert_ok!(basic_mint(id));
+		}
+		// Alice sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			BasicResource { metadata: stbd("bafybeiakahlc6") },
-			0
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
 		));
-
-		// Replace to Basic resource of non exist resource
+		// Alice sends NFT (0, 2) to NFT (0, 1)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			None,
+		));
+		// Sending NFT to its own grandchild should fail
 		assert_noop!(
-			RMRKCore::replace_resource(
+			RMRKCore::send(
 				Origin::signed(ALICE),
-				COLLECTION_ID_0,
-				NFT_ID_0,
-				ResourceTypes::Basic(BasicResource { metadata: stbd("new_meta") }), // new_resource
-				101,                                                                // resource_id
+				0,
+				0,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+				None,
 			),
-			Error::<Test>::ResourceDoesntExist
+			Error::<Test>::CannotSendToDescendentOrSelf
 		);
 	});
 }
 
-/// Resource: Resource removal with pending and accept
+/// NFT: Burn simple tests (RMRK2.0 spec: BURN)
 #[test]
-fn resource_removal_pending_works() {
+fn burn_nft_works() {
 	ExtBuilder::build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
-		assert_ok!(RMRKCore::mint_nft(
+		// Mint an NFT
+		assert_ok!(basic_mint(0));
+
+		// Add a property to the NFT to see whether it will also get deleted
+		let key = stbk("test-key");
+		let value = stb("test-value");
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
-			Some(BOB),
-			NFT_ID_0,
-			COLLECTION_ID_0,
-			Some(BOB),
-			Some(Permill::from_float(1.525)),
-			bvec![0u8; 20],
-			true,
-			None
+			0,
+			Some(0),
+			key.clone(),
+			value.clone()
 		));
 
+		// Add two resources to NFT (to test if burning also burns the resources)
+
 		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
 
-		// Add resource to NFT
 		assert_ok!(RMRKCore::add_basic_resource(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			basic_resource,
-			0
+			0,
+			0,
+			basic_resource.clone(),
+			Some(0),
+			None
 		));
 
-		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0,));
-		// Accepting a resource removal that is not pending should fail
-		assert_noop!(
-			RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 0),
-			Error::<Test>::ResourceNotPending
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			0,
+			0,
+			basic_resource,
+			Some(1),
+			None
+		));
+
+		// Ensure resources are there
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 2);
+
+		// BOB should not be able to burn ALICE's NFT
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoPermission
+		);
+		// ALICE burns her NFT
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		// Successful burn creates NFTBurned event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTBurned {
+			owner: ALICE,
+			nft_id: 0,
+			collection_id: 0,
+		}));
+		// NFT count of collection is now 0
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 0);
+
+		// Once the NFT is burned the property should be deleted
+		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())), None);
+
+		// ALICE can't burn an NFT twice
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoAvailableNftId
+		);
+		// Burned NFT no longer exists
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none(), true);
+		// Resources associated with the NFT should no longer exist
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 0);
+	});
+}
+
+/// NFT: Burn complex multi-generational tests (RMRK2.0 spec: BURN)
+#[test]
+fn burn_nft_with_great_grandchildren_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
+		for id in 0..4 {
+			assert_ok!(basic_mint(id));
+		}
+
+		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+
+		// Add two resources to the great-grandchild (0, 3)
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			3,
+			basic_resource.clone(),
+			Some(0),
+			None
+		));
+
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			3,
+			basic_resource,
+			Some(1),
+			None
+		));
+
+		// Ensure resources are there
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, 3)).count(), 2);
+
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
+		));
+		// ALICE sends NFT (0, 2) to NFT (0, 1)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			None,
+		));
+		// ALICE sends NFT (0, 3) to NFT (0, 2)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			3,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			None,
+		));
+		// Great-grandchild NFT (0, 3) exists
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
+		// Burn great-grandparent NFT (0, 0)
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		// Great-grandchild NFT (0, 3) is dead :'-(
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_none());
+		// Great-grandchild resources are gone
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, 3)).count(), 0);
+	});
+}
+
+/// NFT: Burn beyond max_recursions fails gracefully
+#[test]
+fn burn_nft_beyond_max_recursions_fails_gracefully() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
+		for id in 0..5 {
+			assert_ok!(basic_mint(id));
+		}
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
+		));
+		// ALICE sends NFT (0, 2) to NFT (0, 1)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			None,
+		));
+		// ALICE sends NFT (0, 3) to NFT (0, 2)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			3,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			None,
+		));
+		// ALICE sends NFT (0, 4) to NFT (0, 3) fails bc the nested budget threshold is exceeded
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				4,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 3),
+				None,
+			),
+			Error::<Test>::TooManyRecursions
+		);
+		// ALICE sends NFT (0, 4) to NFT (0, 0) succeeds since the budget threshold is not exceeded
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			4,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
+		));
+		// All NFTs exist
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
+		// Burn great-grandparent NFT (0, 0)
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, 0),
+			Error::<Test>::TooManyRecursions
+		);
+		// All NFTs still exist
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
+		// Burn great-grandparent NFT (0,1) successfully
+		assert_ok!(
+			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, 1),
+			//Error::<Test>::TooManyRecursions
+		);
+		// NFTs [0,1], [0,2], [0,3] are burned
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), false);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), false);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), false);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
+	});
+}
+
+/// NFT: Burn child removes NFT from owner-NFT's Children list
+#[test]
+fn burn_child_nft_removes_parents_children() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
+		for id in 0..2 {
+			assert_ok!(basic_mint(id));
+		}
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			None,
+		));
+		// All NFTs exist
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
+		// NFT (0, 0) should have 1 Children storage member
+		assert_eq!(Children::<Test>::iter_prefix((0, 0)).count(), 1);
+		// Burn NFT (0, 1)
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), 0, 1),);
+		// NFT (0, 0) should have 0 Children storage members
+		assert_eq!(Children::<Test>::iter_prefix((0, 0)).count(), 0);
+	});
+}
+
+/// Resource: Basic resource addition (RMRK2.0 spec: RESADD)
+#[test]
+fn create_resource_works() {
+	ExtBuilder::build().execute_with(|| {
+		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+
+		// Adding a resource to non-existent NFT should fail
+		assert_noop!(
+			RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				0, // collection_id
+				0, // nft_id
+				basic_resource,
+				Some(0),
+				None,
+			),
+			Error::<Test>::CollectionUnknown
+		);
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+
+		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource,
+			Some(0),
+			None,
+		));
+		// Successful resource addition should trigger ResourceAdded event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
+			nft_id: 0,
+			resource_id: 0, // resource_id
+			collection_id: 0,
+		}));
+		// Since ALICE rootowns NFT, pending status of resource should be false
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
+
+		// Create Composable resource
+		let composable_resource = ComposableResource {
+			parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
+			base: 0,                               // BaseID
+			metadata: None,
+			slot: None,
+		};
+
+		// Composable resource addition works
+		assert_ok!(RMRKCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			composable_resource,
+			Some(1),
+			None
+		));
+
+		// Create Slot resource
+		let slot_resource = SlotResource {
+			base: 0, // BaseID
+			metadata: None,
+			slot: 0, // SlotID
+		};
+
+		// Slot resource addition works
+		assert_ok!(RMRKCore::add_slot_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			slot_resource,
+			Some(2),
+			None
+		));
+	});
+}
+
+/// Minting with resources works
+#[test]
+fn add_resource_on_mint_works() {
+	ExtBuilder::build().execute_with(|| {
+		let basic_resource: BasicResource<BoundedVec<u8, UniquesStringLimit>> =
+			BasicResource { metadata: stbd("bafybeiakahlc6") };
+
+		// Create a basic collection
+		assert_ok!(basic_collection());
+
+		// Resources to add
+		let resources_to_add = bvec![
+			ResourceInfoMin { id: 0, resource: ResourceTypes::Basic(basic_resource.clone()) },
+			ResourceInfoMin { id: 1, resource: ResourceTypes::Basic(basic_resource) },
+		];
+
+		// Mint NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			NFT_ID_0,
+			COLLECTION_ID_0,
+			Some(ALICE),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			Some(resources_to_add),
+			None,
+		));
+
+		assert_eq!(RMRKCore::resources((0, 0, 0)).is_some(), true);
+		assert_eq!(RMRKCore::resources((0, 0, 1)).is_some(), true);
+	});
+}
+
+/// Minting with more than max resources (set to 3 in mock) should panic
+#[should_panic]
+#[test]
+fn add_resource_on_mint_beyond_max_fails() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+
+		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+
+		// Resources to add
+		let resources_to_add = bvec![
+			{ ResourceInfoMin { resource: ResourceTypes::Basic(basic_resource.clone()), id: 0 } },
+			{ ResourceInfoMin { resource: ResourceTypes::Basic(basic_resource.clone()), id: 1 } },
+			{ ResourceInfoMin { resource: ResourceTypes::Basic(basic_resource.clone()), id: 2 } },
+			{ ResourceInfoMin { resource: ResourceTypes::Basic(basic_resource), id: 3 } },
+		];
+
+		// Mint NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			NFT_ID_0,
+			COLLECTION_ID_0,
+			Some(ALICE),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			Some(resources_to_add),
+			None,
+		));
+	});
+}
+
+/// Resource: Resource addition with pending and accept (RMRK2.0 spec: ACCEPT)
+#[test]
+fn add_resource_pending_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			NFT_ID_0,
+			COLLECTION_ID_0,
+			Some(BOB),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			None,
+			None,
+		));
+
+		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+
+		// Since BOB isn't collection issuer, he can't add resources
+		assert_noop!(
+			RMRKCore::add_basic_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				basic_resource.clone(),
+				Some(0),
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// Collection issuer can add resource
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource,
+			Some(0),
+			None,
+		));
+
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, true);
+		// ALICE doesn't own BOB's NFT, so accept should fail
+		assert_noop!(
+			RMRKCore::accept_resource(Origin::signed(ALICE), 0, 0, 0),
+			Error::<Test>::NoPermission
+		);
+		// BOB can accept his own NFT's pending resource
+		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0));
+		// Valid resource acceptance should trigger a ResourceAccepted event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAccepted {
+			nft_id: 0,
+			resource_id: 0, // resource_id
+			collection_id: 0,
+		}));
+		// Resource should now have false pending status
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
+		// Accepting resource again should fail with ResourceNotPending
+		assert_noop!(
+			RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0),
+			Error::<Test>::ResourceNotPending
+		);
+	});
+}
+
+/// Resource: Basic resource removal
+#[test]
+fn resource_removal_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+
+		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource,
+			Some(0),
+			None
+		));
+		// Resource res-1 doesn't exist
+		assert_noop!(
+			RMRKCore::remove_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				1, // resource_id
+			),
+			Error::<Test>::ResourceDoesntExist
+		);
+		// Only collection issuer can request resource removal
+		assert_noop!(
+			RMRKCore::remove_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				0, // resource_id
+			),
+			Error::<Test>::NoPermission
+		);
+		// Remove resource
+		assert_ok!(RMRKCore::remove_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0, // resource_id
+		));
+		// Successful resource removal should trigger ResourceRemoval event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemoval {
+			nft_id: 0,
+			resource_id: 0, // resource_id
+			collection_id: 0,
+		}));
+		// Since ALICE rootowns NFT, resource should be removed
+		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
+
+		let base_id = 11;
+		let slot_id = 22;
+		let resource_id = 10;
+
+		// Create Composable resource
+		let composable_resource = ComposableResource {
+			parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
+			base: base_id,                         // base_id
+			metadata: None,
+			slot: Some((base_id, slot_id)),
+		};
+
+		// Add composable resource to NFT
+		assert_ok!(RMRKCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			composable_resource,
+			Some(resource_id),
+			None
+		));
+
+		// Values should now exist in EquippableBases and EquippableSlots
+		assert!(EquippableBases::<Test>::get((COLLECTION_ID_0, NFT_ID_0, base_id)).is_some());
+		assert!(EquippableSlots::<Test>::get((
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			resource_id,
+			base_id,
+			slot_id
+		))
+		.is_some());
+
+		// Remove resource
+		assert_ok!(RMRKCore::remove_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			resource_id,
+		));
+
+		assert!(EquippableBases::<Test>::get((COLLECTION_ID_0, NFT_ID_0, base_id)).is_none());
+		assert!(EquippableSlots::<Test>::get((
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			resource_id,
+			base_id,
+			slot_id
+		))
+		.is_none());
+	});
+}
+
+#[test]
+fn resource_replace_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(0),
+			None
+		));
+
+		// Replace to Basic resource
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			ResourceTypes::Basic(BasicResource { metadata: stbd("new_meta") }), // new_resource
+			0,                                                                  // resource_id
+		));
+
+		// Replace to Composable resource
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			ResourceTypes::Composable(ComposableResource {
+				parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
+				base: 0,                               // BaseID
+				metadata: None,
+				slot: None,
+			}), // new_resource
+			0, // resource_id
+		));
+
+		// Replace to Slot resource
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			ResourceTypes::Slot(SlotResource {
+				base: 0,
+				metadata: Some(stbd("new_meta_slot")),
+				slot: 0
+			}), // new_resource
+			0, // resource_id
+		));
+
+		// Successful resource replace should trigger ResourceReplaced event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceReplaced {
+			nft_id: 0,
+			resource_id: 0,
+			collection_id: 0,
+		}));
+	});
+}
+
+#[test]
+fn resource_replace_non_exist_resource() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(0),
+			None
+		));
+
+		// Replace to Basic resource of non exist resource
+		assert_noop!(
+			RMRKCore::replace_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				ResourceTypes::Basic(BasicResource { metadata: stbd("new_meta") }), // new_resource
+				101,                                                                // resource_id
+			),
+			Error::<Test>::ResourceDoesntExist
+		);
+	});
+}
+
+/// Resource: Resource removal with pending and accept
+#[test]
+fn resource_removal_pending_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			NFT_ID_0,
+			COLLECTION_ID_0,
+			Some(BOB),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			None,
+			None,
+		));
+
+		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource,
+			Some(0),
+			None
+		));
+
+		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0,));
+		// Accepting a resource removal that is not pending should fail
+		assert_noop!(
+			RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 0),
+			Error::<Test>::ResourceNotPending
+		);
+		// Only collection's issuer can request resource removal
+		assert_noop!(
+			RMRKCore::remove_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				0, // resource_id
+			),
+			Error::<Test>::NoPermission
+		);
+		// Resource removal requested by the collection issuer
+		assert_ok!(RMRKCore::remove_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0, // resource_id
+		));
+		// Since ALICE doesn't root-own NFT, resource's removal is waiting for acceptance
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending_removal, true);
+		// ALICE doesn't own BOB's NFT, so accept should fail
+		assert_noop!(
+			RMRKCore::accept_resource_removal(Origin::signed(ALICE), 0, 0, 0),
+			Error::<Test>::NoPermission
+		);
+		// Resource res-1 doesn't exist
+		assert_noop!(
+			RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 1),
+			Error::<Test>::ResourceDoesntExist
+		);
+		// BOB can accept his own NFT's pending resource removal
+		assert_ok!(RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 0));
+		// Successful resource removal acceptance should trigger ResourceRemovalAccepted event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemovalAccepted {
+			nft_id: 0,
+			resource_id: 0, // resource_id
+			collection_id: 0,
+		}));
+		// Resource removed
+		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
+	});
+}
+
+/// Property: Setting property tests (RMRK2.0 spec: SETPROPERTY)
+#[test]
+fn set_property_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Define property key
+		let key = stbk("test-key");
+		// Define property value
+		let value = stb("test-value");
+		// set_property fails without a collection (CollectionUnknown)
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(0), key.clone(), value.clone()),
+			Error::<Test>::CollectionUnknown
+		);
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+		// ALICE sets property on NFT
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			0,
+			Some(0),
+			key.clone(),
+			value.clone()
+		));
+		// Successful property setting should trigger a PropertySet event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
+			collection_id: 0,
+			maybe_nft_id: Some(0),
+			key: key.clone(),
+			value: value.clone(),
+		}));
+		// Property value now exists
+		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), value.clone());
+		// BOB does not own NFT so attempt to set property should fail
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(BOB), 0, Some(0), key.clone(), value.clone()),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_property_with_internal_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Define property key
+		let key = stbk("test-key");
+		// Define property value
+		let value = stb("test-value");
+		// set_property fails without a collection (CollectionUnknown)
+		assert_noop!(
+			RMRKCore::do_set_property(0, Some(0), key.clone(), value.clone()),
+			Error::<Test>::CollectionUnknown
+		);
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+		// Root sets property on NFT
+		assert_ok!(RMRKCore::do_set_property(0, Some(0), key.clone(), value.clone()));
+		// Successful property setting should trigger a `PropertySet` event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
+			collection_id: 0,
+			maybe_nft_id: Some(0),
+			key: key.clone(),
+			value: value.clone(),
+		}));
+		// Property value now exists
+		assert_eq!(RMRKCore::properties((0, Some(0), key)).unwrap(), value);
+	});
+}
+
+#[test]
+fn remove_property_with_internal_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Define property key
+		let key = stbk("test-key");
+		// Define property value
+		let value = stb("test-value");
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+		// Root sets property on NFT
+		assert_ok!(RMRKCore::do_set_property(0, Some(0), key.clone(), value.clone()));
+		// Successful property setting should trigger a PropertySet event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
+			collection_id: 0,
+			maybe_nft_id: Some(0),
+			key: key.clone(),
+			value: value.clone(),
+		}));
+		// Property value now exists
+		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), value);
+		// `Origin::root()` removes property
+		assert_ok!(RMRKCore::do_remove_property(0, Some(0), key.clone()));
+		assert_eq!(RMRKCore::properties((0, Some(0), key)), None);
+	});
+}
+
+#[test]
+fn remove_properties_with_internal_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Define the keys of the properties
+		let keys = vec![stbk("test-key-1"), stbk("test-key-2"), stbk("test-key-3")];
+		// Define properties value
+		let value = stb("test-value");
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+		// Root sets properties on NFT
+		keys.into_iter().for_each(|key| {
+			assert_ok!(RMRKCore::do_set_property(0, Some(0), key.clone(), value.clone()));
+
+			// Successful property setting should trigger a `PropertySet` event
+			System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
+				collection_id: 0,
+				maybe_nft_id: Some(0),
+				key: key.clone(),
+				value: value.clone(),
+			}));
+
+			// Property value now exists
+			assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), value);
+		});
+
+		// `Origin::root()` removes all properties
+		assert_ok!(RMRKCore::do_remove_properties(
+			0,
+			Some(0),
+			<Test as Config>::PropertiesLimit::get()
+		));
+		assert_eq!(RMRKCore::query_properties(0, Some(0), None).count(), 0);
+
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertiesRemoved {
+			collection_id: 0,
+			maybe_nft_id: Some(0),
+		}));
+	});
+}
+
+/// Priority: Setting priority tests (RMRK2.0 spec: SETPRIORITY)
+#[test]
+fn set_priority_works() {
+	ExtBuilder::build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+		// Add the resources to prioritize
+		for resource_id in [100, 500, 1000] {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None,
+			));
+		}
+		// BOB cannot set priority on NFT
+
+		assert_noop!(
+			RMRKCore::set_priority(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, bvec![100, 500]),
+			Error::<Test>::NoPermission
+		);
+		// ALICE sets priority on NFT
+		assert_ok!(RMRKCore::set_priority(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			bvec![100, 500] // BoundedVec Resource 0, 1
+		));
+		// Successful priority set should trigger PrioritySet event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PrioritySet {
+			collection_id: 0,
+			nft_id: 0,
+		}));
+		// Resource 100 should have priority 0
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 100)).unwrap(), 0);
+		// Resource 500 should have priority 1
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 500)).unwrap(), 1);
+		// Setting priority again drains and resets priorities
+		assert_ok!(RMRKCore::set_priority(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			bvec![1000, 100] // BoundedVec Resources 2, 0
+		));
+		// Priorities reset, resource 100 should have priority one
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 100)).unwrap(), 1);
+		// Resource 1000 should have priority zero
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 1000)).unwrap(), 0);
+		// Resource 500 should no longer have a priority
+		assert!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 500)).is_none(),);
+	});
+}
+
+/// Storage deposits: Resources, properties and priorities are paid for by the issuer, and refunded
+/// when removed or when the NFT is burned
+#[test]
+fn storage_deposits_are_reserved_and_refunded() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		let reserved = Balances::reserved_balance(ALICE);
+		assert_ok!(basic_mint(0));
+		let reserved_after_mint = Balances::reserved_balance(ALICE);
+		// Adding a resource reserves a deposit from the issuer
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(0),
+			None,
+		));
+		let resource_deposit = Balances::reserved_balance(ALICE) - reserved_after_mint;
+		assert!(resource_deposit > 0);
+		assert_eq!(StorageDeposits::<Test>::iter().count(), 1);
+		// So do properties and priorities
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			Some(NFT_ID_0),
+			stbk("test-key"),
+			stb("test-value")
+		));
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(1),
+			None,
+		));
+		let reserved_before_priorities = Balances::reserved_balance(ALICE);
+		assert_ok!(RMRKCore::set_priority(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			bvec![0, 1]
+		));
+		let priority_deposit = (Balances::reserved_balance(ALICE) - reserved_before_priorities) / 2;
+		assert_eq!(StorageDeposits::<Test>::iter().count(), 5);
+		// Setting the priorities again only holds deposits for the new ones
+		let reserved_before_priority = Balances::reserved_balance(ALICE);
+		assert_ok!(RMRKCore::set_priority(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			bvec![1, 0]
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), reserved_before_priority);
+		assert_eq!(StorageDeposits::<Test>::iter().count(), 5);
+		// Removing the resource refunds its deposit and the deposit of its priority
+		let reserved_before_removal = Balances::reserved_balance(ALICE);
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 0));
+		assert_eq!(
+			Balances::reserved_balance(ALICE),
+			reserved_before_removal - resource_deposit - priority_deposit
+		);
+		// Burning the NFT refunds all of the remaining deposits
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
+		assert_eq!(StorageDeposits::<Test>::iter().count(), 0);
+	});
+}
+
+/// Storage deposits: The child entry of an NFT sent to another NFT is paid for by the sender
+#[test]
+fn storage_deposit_for_children_works() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		let reserved = Balances::reserved_balance(ALICE);
+		// Sending NFT 1 into NFT 0 reserves a deposit for the child entry
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+			None,
+		));
+		assert!(Balances::reserved_balance(ALICE) > reserved);
+		// Sending it back out of NFT 0 refunds the deposit
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE),
+			None,
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
+		assert_eq!(StorageDeposits::<Test>::iter().count(), 0);
+	});
+}
+
+/// Storage deposits: Storage entries can't be added without funds for the deposit
+#[test]
+fn storage_deposit_requires_funds() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		Balances::make_free_balance_be(&ALICE, 1);
+		assert_noop!(
+			RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(0),
+				None,
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			RMRKCore::set_property(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				Some(NFT_ID_0),
+				stbk("test-key"),
+				stb("test-value")
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn force_calls_require_protocol_origin() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_noop!(
+			RMRKCore::force_burn(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RMRKCore::force_transfer(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RMRKCore::force_unlock(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, *b"testlock"),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RMRKCore::force_collection_issuer(Origin::signed(ALICE), COLLECTION_ID_0, BOB),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RMRKCore::force_remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn force_burn_and_transfer_work() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		// NFT 1 is nested under NFT 0, both owned by ALICE
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+			None,
+		));
+		// Governance moves NFT 0 (and its child) to BOB
+		assert_ok!(RMRKCore::force_transfer(
+			Origin::root(),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		));
+		assert_eq!(
+			RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).unwrap().owner,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		);
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		// Governance burns NFT 0, which burns its child as well
+		assert_ok!(RMRKCore::force_burn(Origin::root(), COLLECTION_ID_0, NFT_ID_0));
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_1).is_none());
+		assert_noop!(
+			RMRKCore::force_burn(Origin::root(), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoAvailableNftId
+		);
+	});
+}
+
+#[test]
+fn force_unlock_works() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(RMRKCore::set_lock(*b"testlock", (COLLECTION_ID_0, NFT_ID_0)));
+		assert_ok!(RMRKCore::set_lock(*b"otherlck", (COLLECTION_ID_0, NFT_ID_0)));
+		assert_noop!(
+			RMRKCore::force_unlock(Origin::root(), COLLECTION_ID_0, NFT_ID_0, *b"nolockid"),
+			Error::<Test>::LockNotFound
+		);
+		// Only the given lock is removed
+		assert_ok!(RMRKCore::force_unlock(Origin::root(), COLLECTION_ID_0, NFT_ID_0, *b"testlock"));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTUnlocked {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			id: *b"testlock",
+		}));
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None
+			),
+			pallet_uniques::Error::<Test>::Locked
+		);
+		assert_ok!(RMRKCore::force_unlock(Origin::root(), COLLECTION_ID_0, NFT_ID_0, *b"otherlck"));
+		assert!(!RMRKCore::is_locked(COLLECTION_ID_0, NFT_ID_0));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		assert_noop!(
+			RMRKCore::force_unlock(Origin::root(), COLLECTION_ID_0, 42, *b"testlock"),
+			Error::<Test>::NoAvailableNftId
+		);
+	});
+}
+
+#[test]
+fn force_collection_issuer_works() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(RMRKCore::propose_issuer(Origin::signed(ALICE), COLLECTION_ID_0, CHARLIE));
+		assert_ok!(RMRKCore::force_collection_issuer(Origin::root(), COLLECTION_ID_0, BOB));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IssuerChanged {
+			old_issuer: ALICE,
+			new_issuer: BOB,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().issuer, BOB);
+		assert_eq!(UNQ::Pallet::<Test>::collection_owner(COLLECTION_ID_0), Some(BOB));
+		// The pending proposal is cancelled
+		assert_eq!(RMRKCore::pending_issuer(COLLECTION_ID_0), None);
+		assert_noop!(
+			RMRKCore::force_collection_issuer(Origin::root(), 1, BOB),
+			Error::<Test>::CollectionUnknown
+		);
+	});
+}
+
+#[test]
+fn force_remove_resource_works() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		// BOB owns the NFT, so an issuer removal would need his approval
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(0),
+			None,
+		));
+		assert_ok!(RMRKCore::force_remove_resource(Origin::root(), COLLECTION_ID_0, NFT_ID_0, 0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemoval {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+		assert_noop!(
+			RMRKCore::force_remove_resource(Origin::root(), COLLECTION_ID_0, NFT_ID_0, 0),
+			Error::<Test>::ResourceDoesntExist
+		);
+	});
+}
+
+#[test]
+fn pause_calls_works() {
+	ExtBuilder::build().execute_with(|| {
+		let send = |collection_id| crate::Call::<Test>::send {
+			collection_id,
+			nft_id: NFT_ID_0,
+			new_owner: AccountIdOrCollectionNftTuple::AccountId(BOB),
+		};
+		let mint = crate::Call::<Test>::mint_nft {
+			owner: None,
+			nft_id: NFT_ID_0,
+			collection_id: COLLECTION_ID_0,
+			royalty_recipient: None,
+			royalty: None,
+			metadata: stbd("test"),
+			transferable: true,
+			resources: None,
+			expires_at: None,
+		};
+		assert_noop!(
+			RMRKCore::pause_calls(Origin::signed(ALICE), CallGroup::Transfers, None),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		// Pausing transfers of a single collection
+		assert_ok!(RMRKCore::pause_calls(Origin::root(), CallGroup::Transfers, Some(0)));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CallsPaused {
+			group: CallGroup::Transfers,
+			collection_id: Some(0),
+		}));
+		assert_noop!(
+			RMRKCore::pause_calls(Origin::root(), CallGroup::Transfers, Some(0)),
+			Error::<Test>::CallsAlreadyPaused
+		);
+		assert!(!CallsNotPaused::<Test>::contains(&send(0)));
+		assert!(CallsNotPaused::<Test>::contains(&send(1)));
+		assert!(CallsNotPaused::<Test>::contains(&mint));
+		// Pausing transfers globally
+		assert_ok!(RMRKCore::pause_calls(Origin::root(), CallGroup::Transfers, None));
+		assert!(!CallsNotPaused::<Test>::contains(&send(1)));
+		assert!(!CallsNotPaused::<Test>::contains(&crate::Call::<Test>::set_operator {
+			operator: BOB,
+			approved: true,
+		}));
+		// Calls not belonging to a group are never paused
+		assert!(CallsNotPaused::<Test>::contains(&crate::Call::<Test>::force_unlock {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			id: *b"testlock",
+		}));
+		// Unpausing only lifts the pause of the given scope
+		assert_ok!(RMRKCore::unpause_calls(Origin::root(), CallGroup::Transfers, None));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CallsUnpaused {
+			group: CallGroup::Transfers,
+			collection_id: None,
+		}));
+		assert!(CallsNotPaused::<Test>::contains(&send(1)));
+		assert!(!CallsNotPaused::<Test>::contains(&send(0)));
+		assert_ok!(RMRKCore::unpause_calls(Origin::root(), CallGroup::Transfers, Some(0)));
+		assert!(CallsNotPaused::<Test>::contains(&send(0)));
+		assert_noop!(
+			RMRKCore::unpause_calls(Origin::root(), CallGroup::Transfers, Some(0)),
+			Error::<Test>::CallsNotPaused
+		);
+	});
+}
+
+#[test]
+fn root_owner_index_follows_nesting() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		for id in 0..3 {
+			assert_ok!(basic_mint(id));
+		}
+		// Build the tree [0,0] > [0,1] > [0,2]
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 1),
+			None,
+		));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 0),
+			None,
+		));
+		assert_eq!(RMRKCore::root_owner((COLLECTION_ID_0, 0)), None);
+		assert_eq!(
+			RMRKCore::root_owner((COLLECTION_ID_0, 1)),
+			Some(RootNft { root: (COLLECTION_ID_0, 0), depth: 1 })
+		);
+		// The child moved along with its parent
+		assert_eq!(
+			RMRKCore::root_owner((COLLECTION_ID_0, 2)),
+			Some(RootNft { root: (COLLECTION_ID_0, 0), depth: 2 })
+		);
+		// Transferring the root NFT changes the root owner of the whole tree
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		let budget = budget::Value::new(2);
+		assert_eq!(
+			RMRKCore::lookup_root_owner(COLLECTION_ID_0, 2, &budget),
+			Ok((BOB, (COLLECTION_ID_0, 0)))
+		);
+		// The budget is consumed as deep as the NFT is nested
+		let budget = budget::Value::new(1);
+		assert_noop!(
+			RMRKCore::lookup_root_owner(COLLECTION_ID_0, 2, &budget),
+			Error::<Test>::TooManyRecursions
+		);
+		// Sending [0,1] to an account makes it the root of [0,2]
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			1,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+			None,
+		));
+		assert_eq!(RMRKCore::root_owner((COLLECTION_ID_0, 1)), None);
+		assert_eq!(
+			RMRKCore::root_owner((COLLECTION_ID_0, 2)),
+			Some(RootNft { root: (COLLECTION_ID_0, 1), depth: 1 })
+		);
+		assert!(!RMRKCore::is_x_descendent_of_y(COLLECTION_ID_0, 2, COLLECTION_ID_0, 0));
+		assert!(RMRKCore::is_x_descendent_of_y(COLLECTION_ID_0, 2, COLLECTION_ID_0, 1));
+		// Burning removes the entries of the whole tree
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(CHARLIE), COLLECTION_ID_0, 1));
+		assert_eq!(RMRKCore::root_owner((COLLECTION_ID_0, 2)), None);
+	});
+}
+
+#[test]
+fn removing_resource_compacts_priorities() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		for resource_id in 0..3 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None,
+			));
+		}
+		assert_ok!(RMRKCore::set_priority(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			bvec![2, 1, 0]
+		));
+		let deposits = StorageDeposits::<Test>::iter().count();
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 1));
+		// The removed resource is gone from the priorities, resource 0 moved up
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 1)), None);
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 2)), Some(0));
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 0)), Some(1));
+		// The deposits of both the resource and its priority were refunded
+		assert_eq!(StorageDeposits::<Test>::iter().count(), deposits - 2);
+	});
+}
+
+#[test]
+fn collection_resources_are_shared_and_overridable() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		let shared =
+			ResourceTypes::Slot(SlotResource { base: 0, slot: 1, metadata: Some(stbd("shared")) });
+		// Only the issuer can add shared resources
+		assert_noop!(
+			RMRKCore::add_collection_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				shared.clone(),
+				0
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::add_collection_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			shared.clone(),
+			0
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionResourceAdded {
+			collection_id: COLLECTION_ID_0,
+			resource_id: 0,
+		}));
+		assert_noop!(
+			RMRKCore::add_collection_resource(Origin::signed(ALICE), COLLECTION_ID_0, shared, 0),
+			Error::<Test>::ResourceAlreadyExists
+		);
+		// Every NFT of the collection has the shared resource
+		for nft_id in [NFT_ID_0, NFT_ID_1] {
+			let resources: Vec<_> = RMRKCore::iterate_resources(COLLECTION_ID_0, nft_id).collect();
+			assert_eq!(resources.len(), 1);
+			assert_eq!(resources[0].id, 0);
+			assert!(RMRKCore::is_equippable_into_slot(COLLECTION_ID_0, nft_id, 0, 0, 1));
+		}
+		// An NFT overrides the shared resource with its own resource of the same ID
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			BasicResource { metadata: stbd("override") },
+			Some(0),
+			None,
+		));
+		let resources: Vec<_> = RMRKCore::iterate_resources(COLLECTION_ID_0, NFT_ID_1).collect();
+		assert_eq!(resources.len(), 1);
+		assert_eq!(
+			resources[0].resource,
+			ResourceTypes::Basic(BasicResource { metadata: stbd("override") })
+		);
+		assert!(!RMRKCore::is_equippable_into_slot(COLLECTION_ID_0, NFT_ID_1, 0, 0, 1));
+		// The collection can't be destroyed while it has shared resources
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_1));
+		assert_noop!(
+			RMRKCore::destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0),
+			Error::<Test>::CollectionHasResources
+		);
+		assert_ok!(RMRKCore::remove_collection_resource(Origin::signed(ALICE), COLLECTION_ID_0, 0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionResourceRemoved {
+			collection_id: COLLECTION_ID_0,
+			resource_id: 0,
+		}));
+		assert_noop!(
+			RMRKCore::remove_collection_resource(Origin::signed(ALICE), COLLECTION_ID_0, 0),
+			Error::<Test>::ResourceDoesntExist
+		);
+		assert_ok!(RMRKCore::destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+	});
+}
+
+#[test]
+fn add_resource_to_many_works() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		for nft_id in 0..3 {
+			assert_ok!(basic_mint(nft_id));
+		}
+		// BOB owns NFT 1, so the resource is pending on it
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		let resource = ResourceTypes::Basic(BasicResource { metadata: stbd("holiday") });
+		assert_noop!(
+			RMRKCore::add_resource_to_many(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				resource.clone(),
+				Some(0),
+				bvec![0, 1, 2]
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::add_resource_to_many(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			resource.clone(),
+			Some(0),
+			bvec![0, 1, 2]
+		));
+		for nft_id in 0..3 {
+			let res = RMRKCore::resources((COLLECTION_ID_0, nft_id, 0)).unwrap();
+			assert_eq!(res.resource, resource);
+			assert_eq!(res.pending, nft_id == 1);
+		}
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
+			nft_id: 2,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		// Nothing is added if any of the NFTs already has the resource
+		assert_ok!(basic_mint(3));
+		assert_noop!(
+			RMRKCore::add_resource_to_many(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				resource,
+				Some(0),
+				bvec![3, 0]
+			),
+			Error::<Test>::ResourceAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn resource_ids_are_auto_assigned_and_bounded() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		let basic = || BasicResource { metadata: stbd("bafybeiakahlc6") };
+		// The first auto-assigned ID is 0
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic(),
+			None,
+			None
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		// IDs chosen by the caller don't move the auto-assigned ones
+		for resource_id in [2, u32::MAX] {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				basic(),
+				Some(resource_id),
+				None
+			));
+		}
+		assert_eq!(RMRKCore::next_resource_id(COLLECTION_ID_0, NFT_ID_0), 1);
+		// Auto-assigned IDs skip the IDs already taken, including the ones of the resources
+		// shared by the collection
+		assert_ok!(RMRKCore::add_collection_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			ResourceTypes::Basic(basic()),
+			1
+		));
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic(),
+			None,
+			None
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
+			nft_id: NFT_ID_0,
+			resource_id: 3,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert_eq!(RMRKCore::next_resource_id(COLLECTION_ID_0, NFT_ID_0), 4);
+		assert_eq!(RMRKCore::resources_count(COLLECTION_ID_0, NFT_ID_0), 4);
+		// Removing a resource frees a slot
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 0));
+		assert_eq!(RMRKCore::resources_count(COLLECTION_ID_0, NFT_ID_0), 3);
+		// An NFT can't have more than `MaxResourcesPerNft` resources
+		for _ in 3..MaxResourcesPerNft::get() {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				basic(),
+				None,
+				None
+			));
+		}
+		assert_noop!(
+			RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				basic(),
+				None,
+				None
+			),
+			Error::<Test>::TooManyResources
+		);
+		// Burning the NFT clears all of its resources and counters
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert_eq!(RMRKCore::iterate_resources(COLLECTION_ID_0, NFT_ID_0).count(), 1);
+		assert_eq!(RMRKCore::resources_count(COLLECTION_ID_0, NFT_ID_0), 0);
+		assert_eq!(RMRKCore::next_resource_id(COLLECTION_ID_0, NFT_ID_0), 0);
+	});
+}
+
+#[test]
+fn burned_nft_storage_past_limits_is_cleaned_up_lazily() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		// More properties than can be removed in the burn
+		let properties_count = PropertiesLimit::get() + 5;
+		for i in 0..properties_count {
+			assert_ok!(RMRKCore::set_property(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				Some(NFT_ID_0),
+				stbk(&format!("key{}", i)),
+				stb("value"),
+			));
+		}
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		let leftover_properties =
+			|| Properties::<Test>::iter_prefix((COLLECTION_ID_0, Some(NFT_ID_0))).count();
+		assert_eq!(leftover_properties(), 5);
+		assert!(RMRKCore::pending_cleanup(COLLECTION_ID_0, NFT_ID_0).is_some());
+		// The NFT can't be minted again until its storage is removed
+		assert_noop!(basic_mint(0), Error::<Test>::NftPendingCleanup);
+		// Entries are removed as long as there is weight left
+		let entry_weight = <Test as Config>::WeightInfo::cleanup_burned_nft_entry();
+		RMRKCore::on_idle(1, entry_weight.saturating_mul(3));
+		assert_eq!(leftover_properties(), 2);
+		assert!(RMRKCore::pending_cleanup(COLLECTION_ID_0, NFT_ID_0).is_some());
+		RMRKCore::on_idle(2, Weight::MAX);
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::BurnedNftCleanedUp {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert_eq!(leftover_properties(), 0);
+		assert!(RMRKCore::pending_cleanup(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert_eq!(CleanupQueue::<Test>::iter().count(), 0);
+		assert_ok!(basic_mint(0));
+	});
+}
+
+#[test]
+fn burn_removes_equippable_bases_and_slots() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(RMRKCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			ComposableResource {
+				parts: bvec![0, 1],
+				base: 0,
+				metadata: Some(stbd("composable")),
+				slot: Some((0, 1)),
+			},
+			None,
+			None,
+		));
+		assert!(RMRKCore::equippable_bases((COLLECTION_ID_0, NFT_ID_0, 0)).is_some());
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert!(RMRKCore::equippable_bases((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+		assert!(RMRKCore::equippable_slots((COLLECTION_ID_0, NFT_ID_0, 0, 0, 1)).is_none());
+		// Nothing was left to be cleaned up later
+		assert!(RMRKCore::pending_cleanup(COLLECTION_ID_0, NFT_ID_0).is_none());
+	});
+}
+
+#[test]
+fn add_media_resource_works() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		let media = MediaResource {
+			metadata: stbd("ipfs://metadata"),
+			media_type: Some(stbd("image/png")),
+			hash: Some(stbd("0x1fbf3ba58bc44a5e")),
+			thumb: None,
+			license: Some(stbd("https://creativecommons.org/licenses/by/4.0/")),
+			src: Some(stbd("ipfs://image.png")),
+		};
+		assert_noop!(
+			RMRKCore::add_media_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				media.clone(),
+				None,
+				None
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::add_media_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			media.clone(),
+			None,
+			None
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		let res = RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).unwrap();
+		assert_eq!(res.resource, ResourceTypes::Media(media));
+		assert!(!res.pending);
+		// Media resources are removed like any other resource
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 0));
+		assert!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+		// A media resource pending on the NFT of BOB can be expired
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		assert_ok!(RMRKCore::add_media_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			media,
+			Some(1),
+			Some(5)
+		));
+		assert!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 1)).unwrap().pending);
+		assert_eq!(RMRKCore::pending_resource_expiry((COLLECTION_ID_0, NFT_ID_0, 1)), Some(5));
+		System::set_block_number(5);
+		assert_ok!(RMRKCore::expire_pending(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			Some(1)
+		));
+		assert!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 1)).is_none());
+	});
+}
+
+#[test]
+fn context_priorities_work() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		for resource_id in 0..3 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None,
+			));
+		}
+		let resource_for = |context: Option<&str>| {
+			RMRKCore::resource_for_context(COLLECTION_ID_0, NFT_ID_0, context.map(stbk).as_ref())
+				.map(|res| res.id)
+		};
+		// Without any priorities, the first resource is picked
+		assert_eq!(resource_for(None), Some(0));
+		assert_ok!(RMRKCore::set_priority(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			bvec![1, 0, 2]
+		));
+		// Only the root owner can set the priorities of a context
+		assert_noop!(
+			RMRKCore::set_priority_for_context(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbk("thumbnail"),
+				bvec![2]
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::set_priority_for_context(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			stbk("thumbnail"),
+			bvec![2]
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ContextPrioritySet {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			context: stbk("thumbnail"),
+		}));
+		assert_eq!(resource_for(Some("thumbnail")), Some(2));
+		// Contexts without priorities fall back to the default ones
+		assert_eq!(resource_for(Some("3d")), Some(1));
+		assert_eq!(resource_for(None), Some(1));
+		// So do contexts whose resources are all gone
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 2));
+		assert_eq!(resource_for(Some("thumbnail")), Some(1));
+		// An empty list removes the context
+		assert_ok!(RMRKCore::set_priority_for_context(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			stbk("thumbnail"),
+			bvec![]
+		));
+		assert_eq!(
+			RMRKCore::context_priorities((COLLECTION_ID_0, NFT_ID_0, stbk("thumbnail"))),
+			None
+		);
+	});
+}
+
+#[test]
+fn priorities_are_validated_and_partially_updatable() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		for resource_id in 0..3 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None,
+			));
+		}
+		let set_priority = |priorities: BoundedVec<ResourceId, MaxPriorities>| {
+			RMRKCore::set_priority(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, priorities)
+		};
+		// Every resource must exist on the NFT and appear only once
+		assert_noop!(set_priority(bvec![0, 42]), Error::<Test>::ResourceDoesntExist);
+		assert_noop!(set_priority(bvec![0, 1, 0]), Error::<Test>::DuplicatePriority);
+		// Pending resources can't be prioritized
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(3),
+			None,
+		));
+		assert_noop!(
+			RMRKCore::set_priority(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, bvec![3]),
+			Error::<Test>::ResourcePending
+		);
+		assert_ok!(RMRKCore::set_priority(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			bvec![0, 1, 2]
+		));
+		let priorities = || {
+			(0..3)
+				.map(|resource_id| RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, resource_id)))
+				.collect::<Vec<_>>()
+		};
+		// Moving a resource shifts the resources in between
+		assert_noop!(
+			RMRKCore::move_priority(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 2, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::move_priority(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 2, 0));
+		assert_eq!(priorities(), vec![Some(1), Some(2), Some(0)]);
+		assert_ok!(RMRKCore::move_priority(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 2, 1));
+		assert_eq!(priorities(), vec![Some(0), Some(2), Some(1)]);
+		assert_noop!(
+			RMRKCore::move_priority(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 2, 3),
+			Error::<Test>::InvalidPriorityIndex
+		);
+		assert_noop!(
+			RMRKCore::move_priority(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 3, 0),
+			Error::<Test>::ResourceNotInPriorities
+		);
+		// Swapping two resources leaves the others in place
+		assert_ok!(RMRKCore::swap_priorities(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0, 1));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PrioritySet {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert_eq!(priorities(), vec![Some(2), Some(0), Some(1)]);
+		assert_noop!(
+			RMRKCore::swap_priorities(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0, 3),
+			Error::<Test>::ResourceNotInPriorities
+		);
+	});
+}
+
+#[test]
+fn owner_proposed_resources_need_issuer_approval() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		let propose = |resource_id| {
+			RMRKCore::propose_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				ResourceTypes::Basic(BasicResource { metadata: stbd("community-skin") }),
+				resource_id,
+			)
+		};
+		// Only the root owner can propose a resource
+		assert_noop!(
+			RMRKCore::propose_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				ResourceTypes::Basic(BasicResource { metadata: stbd("community-skin") }),
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+		let reserved = Balances::reserved_balance(BOB);
+		assert_ok!(propose(None));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceProposed {
+			proposer: BOB,
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		// The proposer pays the deposit of the pending resource
+		assert!(Balances::reserved_balance(BOB) > reserved);
+		assert!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).unwrap().pending);
+		assert_eq!(RMRKCore::proposed_resources((COLLECTION_ID_0, NFT_ID_0, 0)), Some(BOB));
+		// The owner can't accept its own proposal
+		assert_noop!(
+			RMRKCore::accept_resource(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0),
+			Error::<Test>::ResourceAwaitingApproval
+		);
+		assert_noop!(
+			RMRKCore::approve_resource_proposal(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0),
+			Error::<Test>::NoPermission
+		);
+		// The issuer approves the proposal
+		assert_ok!(RMRKCore::approve_resource_proposal(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAccepted {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert!(!RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).unwrap().pending);
+		assert!(RMRKCore::proposed_resources((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+		assert_noop!(
+			RMRKCore::approve_resource_proposal(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				0
+			),
+			Error::<Test>::ResourceNotProposed
+		);
+		// Resources added by the issuer aren't proposals
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(1),
+			None,
+		));
+		assert_noop!(
+			RMRKCore::reject_resource_proposal(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 1),
+			Error::<Test>::ResourceNotProposed
+		);
+		// A rejected proposal is removed and its deposit refunded
+		let reserved = Balances::reserved_balance(BOB);
+		assert_ok!(propose(Some(2)));
+		assert_ok!(RMRKCore::reject_resource_proposal(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			2
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceProposalRejected {
+			nft_id: NFT_ID_0,
+			resource_id: 2,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 2)).is_none());
+		assert!(RMRKCore::proposed_resources((COLLECTION_ID_0, NFT_ID_0, 2)).is_none());
+		assert_eq!(Balances::reserved_balance(BOB), reserved);
+	});
+}
+
+#[test]
+fn resource_replacement_needs_owner_consent() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(0),
+			None,
+		));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		let current = ResourceTypes::Basic(BasicResource { metadata: stbd("bafybeiakahlc6") });
+		let replacement = ResourceTypes::Basic(BasicResource { metadata: stbd("new_meta") });
+		let replace = |resource: &ResourceTypes<_, _>| {
+			RMRKCore::replace_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				resource.clone(),
+				0,
+			)
+		};
+		// Only the issuer can replace a resource
+		assert_noop!(
+			RMRKCore::replace_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				replacement.clone(),
+				0
+			),
+			Error::<Test>::NoPermission
+		);
+		// The replacement stays pending next to the current resource
+		let reserved = Balances::reserved_balance(ALICE);
+		assert_ok!(replace(&replacement));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceReplacementProposed {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert!(Balances::reserved_balance(ALICE) > reserved);
+		assert_eq!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).unwrap().resource, current);
+		assert_eq!(
+			RMRKCore::pending_replacements((COLLECTION_ID_0, NFT_ID_0, 0)),
+			Some(replacement.clone())
+		);
+		// Only the owner can accept or reject it
+		assert_noop!(
+			RMRKCore::accept_resource_replacement(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				0
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::reject_resource_replacement(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceReplacementRejected {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert_eq!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).unwrap().resource, current);
+		assert!(RMRKCore::pending_replacements((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
+		assert_noop!(
+			RMRKCore::reject_resource_replacement(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				0
+			),
+			Error::<Test>::NoPendingReplacement
+		);
+		// An accepted replacement replaces the resource
+		assert_ok!(replace(&replacement));
+		assert_ok!(RMRKCore::accept_resource_replacement(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceReplacementAccepted {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert_eq!(
+			RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).unwrap().resource,
+			replacement
+		);
+		assert!(RMRKCore::pending_replacements((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+		// Removing the resource drops its pending replacement
+		assert_ok!(replace(&current));
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 0));
+		assert_ok!(RMRKCore::accept_resource_removal(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0
+		));
+		assert!(RMRKCore::pending_replacements((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+	});
+}
+
+#[test]
+fn migration_to_v1_adds_lock_and_policy_to_collections() {
+	use crate::migration::v1;
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	ExtBuilder::build().execute_with(|| {
+		StorageVersion::new(0).put::<RMRKCore>();
+		let old_collection = |max| -> v1::OldCollectionInfoOf<Test> {
+			v1::OldCollectionInfo {
+				issuer: ALICE,
+				metadata: stbd("metadata"),
+				max,
+				symbol: bvec![0u8; 15],
+				nfts_count: 2,
+			}
+		};
+		// A collection locked by setting its max to 0, and an unlocked one
+		unhashed::put(&Collections::<Test>::hashed_key_for(0), &old_collection(Some(0)));
+		unhashed::put(&Collections::<Test>::hashed_key_for(1), &old_collection(Some(5)));
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(RMRKCore::on_chain_storage_version(), 1);
+		let locked = RMRKCore::collections(0).unwrap();
+		assert!(locked.locked);
+		assert_eq!(locked.max, Some(0));
+		assert_eq!(locked.nfts_count, 2);
+		assert!(locked.ever_minted);
+		assert_eq!(locked.policy, CollectionPolicy::default());
+		let unlocked = RMRKCore::collections(1).unwrap();
+		assert!(!unlocked.locked);
+		assert_eq!(unlocked.max, Some(5));
+	});
+}
+
+#[test]
+fn destroy_collection_items_releases_foreign_children_and_respects_locks() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(RMRKCore::create_collection(
+			Origin::signed(ALICE),
+			1,
+			bvec![0u8; 20],
+			None,
+			bvec![0u8; 15],
+			CollectionPolicy::default(),
+		));
+		assert_ok!(basic_mint(0));
+		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
+			Origin::signed(ALICE),
+			(COLLECTION_ID_0, 0),
+			0,
+			1,
+			None,
+			None,
+			bvec![0u8; 20],
+			true,
+			None,
+		));
+		// More collection properties than can be removed along with the collection
+		for i in 0..PropertiesLimit::get() + 1 {
+			assert_ok!(RMRKCore::set_property(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				None,
+				stbk(&i.to_string()),
+				stb("test-value")
+			));
+		}
+		assert_ok!(RMRKCore::start_destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+
+		// Locked NFTs can't be destroyed
+		assert_ok!(RMRKCore::set_lock(*b"testlock", (COLLECTION_ID_0, 0)));
+		assert_noop!(
+			RMRKCore::destroy_collection_items(Origin::signed(BOB), COLLECTION_ID_0, 10),
+			pallet_uniques::Error::<Test>::Locked
+		);
+		RMRKCore::remove_lock(*b"testlock", (COLLECTION_ID_0, 0));
+
+		// The child of the other collection goes back to the root owner instead of being burned
+		assert_ok!(RMRKCore::destroy_collection_items(Origin::signed(BOB), COLLECTION_ID_0, 10));
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_none());
+		let child = RMRKCore::nfts(1, 0).unwrap();
+		assert_eq!(child.owner, AccountIdOrCollectionNftTuple::AccountId(ALICE));
+		assert_eq!(UNQ::Pallet::<Test>::owner(1, 0), Some(ALICE));
+		assert_eq!(Children::<Test>::iter().count(), 0);
+
+		// The properties past the limit are removed by another call before the collection can
+		// be destroyed
+		assert_eq!(Properties::<Test>::iter_prefix((COLLECTION_ID_0, None::<u32>)).count(), 1);
+		assert_noop!(
+			RMRKCore::destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0),
+			Error::<Test>::CollectionHasProperties
+		);
+		assert_ok!(RMRKCore::destroy_collection_items(Origin::signed(BOB), COLLECTION_ID_0, 10));
+		assert_ok!(RMRKCore::destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+	});
+}
+
+#[test]
+fn send_and_add_resource_can_set_pending_expiry() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		System::set_block_number(5);
+		// The expiry must be in the future
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				Some(5),
+			),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			Some(10),
+		));
+		// NFTs sent to an account aren't pending, so the expiry is ignored
+		assert_eq!(RMRKCore::pending_transfers((COLLECTION_ID_0, NFT_ID_0)), None);
+
+		// A resource added to BOB's NFT is pending and can be expired from the given block
+		assert_noop!(
+			RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(0),
+				Some(4),
+			),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			Some(0),
+			Some(10),
+		));
+		assert_eq!(RMRKCore::pending_resource_expiry((COLLECTION_ID_0, NFT_ID_0, 0)), Some(10));
+		System::set_block_number(10);
+		assert_ok!(RMRKCore::expire_pending(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			Some(0)
+		));
+		assert!(RMRKCore::resources((COLLECTION_ID_0, NFT_ID_0, 0)).is_none());
+	});
+}
+
+#[test]
+fn migration_to_v1_moves_locks_under_the_market_lock_id() {
+	use crate::migration::v1;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		StorageVersion::new(0).put::<RMRKCore>();
+		v1::Lock::<Test>::insert((COLLECTION_ID_0, 0), true);
+		v1::Lock::<Test>::insert((COLLECTION_ID_0, 1), false);
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(v1::Lock::<Test>::iter().count(), 0);
+		assert_eq!(RMRKCore::locks((COLLECTION_ID_0, 0)).into_inner(), vec![v1::MARKET_LOCK_ID]);
+		assert!(RMRKCore::is_locked(COLLECTION_ID_0, 0));
+		assert!(!Locks::<Test>::contains_key((COLLECTION_ID_0, 1)));
+		assert!(!RMRKCore::is_locked(COLLECTION_ID_0, 1));
+	});
+}
+
+#[test]
+fn failed_expiry_burn_is_retried() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			None,
+			NFT_ID_0,
+			COLLECTION_ID_0,
+			Some(ALICE),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			None,
+			Some(5),
+		));
+		System::set_block_number(5);
+		// The burn fails and the NFT is queued again for the next block
+		HookDeniesBurns::set(true);
+		RMRKCore::on_idle(5, Weight::MAX);
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_some());
+		assert_eq!(ExpiryQueue::<Test>::get(6, (COLLECTION_ID_0, NFT_ID_0)), Some(()));
+		// The burn is retried once it can succeed
+		HookDeniesBurns::set(false);
+		System::set_block_number(6);
+		RMRKCore::on_idle(6, Weight::MAX);
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn resource_removal_hooks_run_on_burn_and_expiry() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		for nft_id in 0..2 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				nft_id,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(0),
+				Some(5),
+			));
+		}
+		HookedResourceRemovals::set((0, 0));
+
+		// Burning an NFT removes its resources through the hooks
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, 0));
+		assert_eq!(HookedResourceRemovals::get(), (1, 1));
+
+		// So does expiring a pending resource
+		System::set_block_number(5);
+		assert_ok!(RMRKCore::expire_pending(Origin::signed(CHARLIE), COLLECTION_ID_0, 1, Some(0)));
+		assert_eq!(HookedResourceRemovals::get(), (2, 2));
+	});
+}
+
+#[test]
+fn force_transfer_skips_transfer_checks() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		// NFT 0 is locked and non-transferable
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			None,
+			NFT_ID_0,
+			COLLECTION_ID_0,
+			None,
+			None,
+			bvec![0u8; 20],
+			false,
+			None,
+			None,
+		));
+		assert_ok!(RMRKCore::set_lock(*b"testlock", (COLLECTION_ID_0, NFT_ID_0)));
+		assert_ok!(basic_mint(1));
+		assert_ok!(basic_mint(2));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			2,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		// Sending NFT 1 into BOB's NFT 2 is pending
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 2),
+			None,
+		));
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_1).unwrap().pending);
+
+		// The locked NFT goes into BOB's NFT without approval and keeps its lock
+		assert_ok!(RMRKCore::force_transfer(
+			Origin::root(),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 2),
+		));
+		let nft = RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).unwrap();
+		assert_eq!(
+			nft.owner,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 2)
 		);
-		// Only collection's issuer can request resource removal
+		assert!(!nft.pending);
+		assert!(RMRKCore::children((COLLECTION_ID_0, 2), (COLLECTION_ID_0, NFT_ID_0)).is_some());
+		assert_eq!(
+			UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_0),
+			Some(RMRKCore::nft_to_account_id(COLLECTION_ID_0, 2))
+		);
+		assert!(RMRKCore::is_locked(COLLECTION_ID_0, NFT_ID_0));
+
+		// The pending transfer of NFT 1 is dropped
+		assert_ok!(RMRKCore::force_transfer(
+			Origin::root(),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+		));
+		let nft = RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_1).unwrap();
+		assert_eq!(nft.owner, AccountIdOrCollectionNftTuple::AccountId(CHARLIE));
+		assert!(!nft.pending);
+		assert!(!PendingTransfers::<Test>::contains_key((COLLECTION_ID_0, NFT_ID_1)));
+		assert!(RMRKCore::children((COLLECTION_ID_0, 2), (COLLECTION_ID_0, NFT_ID_1)).is_none());
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_1), Some(CHARLIE));
+
+		// Cycles are still refused
 		assert_noop!(
-			RMRKCore::remove_resource(
-				Origin::signed(BOB),
+			RMRKCore::force_transfer(
+				Origin::root(),
+				COLLECTION_ID_0,
+				2,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+			),
+			Error::<Test>::CannotSendToDescendentOrSelf
+		);
+	});
+}
+
+#[test]
+fn root_owner_of_parent_without_entry_is_rebuilt() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		for id in 0..3 {
+			assert_ok!(basic_mint(id));
+		}
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+			None,
+		));
+		// NFT 1 was nested before `RootOwner` was tracked
+		RootOwner::<Test>::remove((COLLECTION_ID_0, NFT_ID_1));
+
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_1),
+			None,
+		));
+		assert_eq!(
+			RMRKCore::root_owner((COLLECTION_ID_0, 2)),
+			Some(RootNft { root: (COLLECTION_ID_0, NFT_ID_0), depth: 2 })
+		);
+		assert_eq!(
+			RMRKCore::root_owner((COLLECTION_ID_0, NFT_ID_1)),
+			Some(RootNft { root: (COLLECTION_ID_0, NFT_ID_0), depth: 1 })
+		);
+	});
+}
+
+#[test]
+fn removed_resources_leave_context_priorities() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		for resource_id in 0..3 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
 				COLLECTION_ID_0,
 				NFT_ID_0,
-				0, // resource_id
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None,
+			));
+		}
+		for (context, priorities) in
+			[("thumbnail", bvec![2, 0]), ("3d", bvec![2]), ("2d", bvec![1])]
+		{
+			assert_ok!(RMRKCore::set_priority_for_context(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbk(context),
+				priorities
+			));
+		}
+		// The NFT can't have priorities for more contexts
+		assert_noop!(
+			RMRKCore::set_priority_for_context(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbk("audio"),
+				bvec![0]
 			),
-			Error::<Test>::NoPermission
+			Error::<Test>::TooManyPriorityContexts
 		);
-		// Resource removal requested by the collection issuer
-		assert_ok!(RMRKCore::remove_resource(
+		// Existing contexts can still be updated
+		assert_ok!(RMRKCore::set_priority_for_context(
 			Origin::signed(ALICE),
 			COLLECTION_ID_0,
 			NFT_ID_0,
-			0, // resource_id
+			stbk("2d"),
+			bvec![1, 2]
 		));
-		// Since ALICE doesn't root-own NFT, resource's removal is waiting for acceptance
-		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending_removal, true);
-		// ALICE doesn't own BOB's NFT, so accept should fail
-		assert_noop!(
-			RMRKCore::accept_resource_removal(Origin::signed(ALICE), 0, 0, 0),
-			Error::<Test>::NoPermission
+
+		let deposits = StorageDeposits::<Test>::iter().count();
+		// The deposits of the resource and of the "3d" context are released
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 2));
+		assert_eq!(
+			RMRKCore::context_priorities((COLLECTION_ID_0, NFT_ID_0, stbk("thumbnail")))
+				.unwrap()
+				.into_inner(),
+			vec![0]
 		);
-		// Resource res-1 doesn't exist
-		assert_noop!(
-			RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 1),
-			Error::<Test>::ResourceDoesntExist
+		assert_eq!(
+			RMRKCore::context_priorities((COLLECTION_ID_0, NFT_ID_0, stbk("2d")))
+				.unwrap()
+				.into_inner(),
+			vec![1]
 		);
-		// BOB can accept his own NFT's pending resource removal
-		assert_ok!(RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 0));
-		// Successful resource removal acceptance should trigger ResourceRemovalAccepted event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemovalAccepted {
-			nft_id: 0,
-			resource_id: 0, // resource_id
-			collection_id: 0,
-		}));
-		// Resource removed
-		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
+		// A context left without resources is removed, along with its deposit
+		assert_eq!(RMRKCore::context_priorities((COLLECTION_ID_0, NFT_ID_0, stbk("3d"))), None);
+		assert_eq!(StorageDeposits::<Test>::iter().count(), deposits - 2);
 	});
 }
 
-/// Property: Setting property tests (RMRK2.0 spec: SETPROPERTY)
 #[test]
-fn set_property_works() {
+fn removing_collection_resources_cleans_up_nfts() {
 	ExtBuilder::build().execute_with(|| {
-		// Define property key
-		let key = stbk("test-key");
-		// Define property value
-		let value = stb("test-value");
-		// set_property fails without a collection (CollectionUnknown)
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		let shared = ResourceTypes::Basic(BasicResource { metadata: stbd("shared") });
+		assert_ok!(RMRKCore::add_collection_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			shared.clone(),
+			0
+		));
+		// NFT 1 overrides the shared resource
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			BasicResource { metadata: stbd("override") },
+			Some(0),
+			None,
+		));
+		for nft_id in [NFT_ID_0, NFT_ID_1] {
+			assert_ok!(RMRKCore::set_priority(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				nft_id,
+				bvec![0]
+			));
+			assert_ok!(RMRKCore::set_priority_for_context(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				nft_id,
+				stbk("thumbnail"),
+				bvec![0]
+			));
+		}
+		HookedResourceRemovals::set((0, 0));
+
+		assert_ok!(RMRKCore::remove_collection_resource(Origin::signed(ALICE), COLLECTION_ID_0, 0));
+		// Only the NFT using the shared resource goes through the hooks and loses it from its
+		// priorities
+		assert_eq!(HookedResourceRemovals::get(), (1, 1));
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 0)), None);
+		assert_eq!(
+			RMRKCore::context_priorities((COLLECTION_ID_0, NFT_ID_0, stbk("thumbnail"))),
+			None
+		);
+		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_1, 0)), Some(0));
+		assert!(
+			RMRKCore::context_priorities((COLLECTION_ID_0, NFT_ID_1, stbk("thumbnail"))).is_some()
+		);
+
+		// A collection shares at most `MaxCollectionResources` resources
+		for resource_id in 0..MaxCollectionResources::get() {
+			assert_ok!(RMRKCore::add_collection_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				shared.clone(),
+				resource_id
+			));
+		}
 		assert_noop!(
-			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(0), key.clone(), value.clone()),
-			Error::<Test>::CollectionUnknown
+			RMRKCore::add_collection_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				shared,
+				MaxCollectionResources::get()
+			),
+			Error::<Test>::TooManyCollectionResources
 		);
-		// Create a basic collection
+
+		// Shared resources of collections with too many NFTs can't be removed
+		for id in 2..=MaxCollectionResourceNfts::get() {
+			assert_ok!(basic_mint(id));
+		}
+		assert_noop!(
+			RMRKCore::remove_collection_resource(Origin::signed(ALICE), COLLECTION_ID_0, 0),
+			Error::<Test>::TooManyNftsForResourceRemoval
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_counts_the_resources_of_nfts() {
+	use crate::migration::v1;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	ExtBuilder::build().execute_with(|| {
 		assert_ok!(basic_collection());
-		// Mint NFT
 		assert_ok!(basic_mint(0));
-		// ALICE sets property on NFT
-		assert_ok!(RMRKCore::set_property(
+		assert_ok!(basic_mint(1));
+		for resource_id in 0..2 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None,
+			));
+		}
+		// The resources were added before they were counted
+		ResourcesCount::<Test>::remove(COLLECTION_ID_0, NFT_ID_0);
+		StorageVersion::new(0).put::<RMRKCore>();
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(RMRKCore::resources_count(COLLECTION_ID_0, NFT_ID_0), 2);
+		assert!(!ResourcesCount::<Test>::contains_key(COLLECTION_ID_0, NFT_ID_1));
+	});
+}
+
+#[test]
+fn proposed_resources_are_capped_per_nft() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		let propose = || {
+			RMRKCore::propose_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				ResourceTypes::Basic(BasicResource { metadata: stbd("community-skin") }),
+				None,
+			)
+		};
+		for _ in 0..MaxProposalsPerNft::get() {
+			assert_ok!(propose());
+		}
+		assert_noop!(propose(), Error::<Test>::TooManyProposals);
+		// The issuer can still add resources
+		assert_ok!(RMRKCore::add_basic_resource(
 			Origin::signed(ALICE),
-			0,
-			Some(0),
-			key.clone(),
-			value.clone()
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { metadata: stbd("bafybeiakahlc6") },
+			None,
+			None,
 		));
-		// Successful property setting should trigger a PropertySet event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
-			collection_id: 0,
-			maybe_nft_id: Some(0),
-			key: key.clone(),
-			value: value.clone(),
-		}));
-		// Property value now exists
-		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), value.clone());
-		// BOB does not own NFT so attempt to set property should fail
-		assert_noop!(
-			RMRKCore::set_property(Origin::signed(BOB), 0, Some(0), key.clone(), value.clone()),
-			Error::<Test>::NoPermission
-		);
+		// Approving a proposal makes room for another one
+		assert_ok!(RMRKCore::approve_resource_proposal(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0
+		));
+		assert_ok!(propose());
 	});
 }
 
 #[test]
-fn set_property_with_internal_works() {
+fn resource_replacement_deposits_stay_with_the_proposer() {
 	ExtBuilder::build().execute_with(|| {
-		// Define property key
-		let key = stbk("test-key");
-		// Define property value
-		let value = stb("test-value");
-		// set_property fails without a collection (CollectionUnknown)
-		assert_noop!(
-			RMRKCore::do_set_property(0, Some(0), key.clone(), value.clone()),
-			Error::<Test>::CollectionUnknown
-		);
-		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
 		assert_ok!(basic_mint(0));
-		// Root sets property on NFT
-		assert_ok!(RMRKCore::do_set_property(0, Some(0), key.clone(), value.clone()));
-		// Successful property setting should trigger a `PropertySet` event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
-			collection_id: 0,
-			maybe_nft_id: Some(0),
-			key: key.clone(),
-			value: value.clone(),
-		}));
-		// Property value now exists
-		assert_eq!(RMRKCore::properties((0, Some(0), key)).unwrap(), value);
+		for resource_id in 0..4 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None,
+			));
+		}
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		let replacement = ResourceTypes::Basic(BasicResource { metadata: stbd("new_meta") });
+		let replace = |resource_id| {
+			RMRKCore::replace_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				replacement.clone(),
+				resource_id,
+			)
+		};
+		for resource_id in 0..MaxReplacementsPerNft::get() {
+			assert_ok!(replace(resource_id));
+		}
+		// A pending replacement can be overwritten, but no other resource can get one
+		assert_ok!(replace(0));
+		assert_noop!(replace(MaxReplacementsPerNft::get()), Error::<Test>::TooManyReplacements);
+
+		// The deposit reserved from the proposer moves to the replaced resource, the new issuer
+		// pays nothing
+		assert_ok!(RMRKCore::force_collection_issuer(Origin::root(), COLLECTION_ID_0, CHARLIE));
+		let deposit_of = |key: Vec<u8>| {
+			StorageDeposits::<Test>::get(
+				<<Test as frame_system::Config>::Hashing as sp_runtime::traits::Hash>::hash(&key),
+			)
+		};
+		let resource_key = Resources::<Test>::hashed_key_for((COLLECTION_ID_0, NFT_ID_0, 0));
+		let replacement_key =
+			PendingReplacements::<Test>::hashed_key_for((COLLECTION_ID_0, NFT_ID_0, 0));
+		let (_, resource_deposit) = deposit_of(resource_key.clone()).unwrap();
+		let replacement_deposit = deposit_of(replacement_key.clone());
+		let alice_reserved = Balances::reserved_balance(ALICE);
+		let charlie_reserved = Balances::reserved_balance(CHARLIE);
+		assert_ok!(RMRKCore::accept_resource_replacement(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0
+		));
+		assert_eq!(Balances::reserved_balance(CHARLIE), charlie_reserved);
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved - resource_deposit);
+		assert_eq!(deposit_of(replacement_key), None);
+		assert_eq!(deposit_of(resource_key), replacement_deposit);
+
+		// The pending replacements are dropped when the NFT changes hands, refunding their
+		// deposits
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+			None
+		));
+		assert_eq!(PendingReplacements::<Test>::iter().count(), 0);
+		assert!(Balances::reserved_balance(ALICE) < alice_reserved - resource_deposit);
 	});
 }
 
 #[test]
-fn remove_property_with_internal_works() {
+fn nfts_nested_before_root_owner_was_tracked_cannot_form_cycles() {
+	use crate::migration::v1;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
 	ExtBuilder::build().execute_with(|| {
-		// Define property key
-		let key = stbk("test-key");
-		// Define property value
-		let value = stb("test-value");
-		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
-		assert_ok!(basic_mint(0));
-		// Root sets property on NFT
-		assert_ok!(RMRKCore::do_set_property(0, Some(0), key.clone(), value.clone()));
-		// Successful property setting should trigger a PropertySet event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
-			collection_id: 0,
-			maybe_nft_id: Some(0),
-			key: key.clone(),
-			value: value.clone(),
-		}));
-		// Property value now exists
-		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), value);
-		// `Origin::root()` removes property
-		assert_ok!(RMRKCore::do_remove_property(0, Some(0), key.clone()));
-		assert_eq!(RMRKCore::properties((0, Some(0), key)), None);
+		for id in 0..3 {
+			assert_ok!(basic_mint(id));
+		}
+		// NFT 2 is in NFT 1, which is in NFT 0
+		for (child, parent) in [(NFT_ID_1, NFT_ID_0), (2, NFT_ID_1)] {
+			assert_ok!(RMRKCore::send(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				child,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, parent),
+				None,
+			));
+		}
+		// The NFTs were nested before `RootOwner` was tracked
+		RootOwner::<Test>::remove((COLLECTION_ID_0, NFT_ID_1));
+		RootOwner::<Test>::remove((COLLECTION_ID_0, 2));
+
+		for descendant in [NFT_ID_1, 2] {
+			assert_noop!(
+				RMRKCore::send(
+					Origin::signed(ALICE),
+					COLLECTION_ID_0,
+					NFT_ID_0,
+					AccountIdOrCollectionNftTuple::CollectionAndNftTuple(
+						COLLECTION_ID_0,
+						descendant
+					),
+					None,
+				),
+				Error::<Test>::CannotSendToDescendentOrSelf
+			);
+		}
+
+		// The migration fills the missing entries
+		StorageVersion::new(0).put::<RMRKCore>();
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			RMRKCore::root_owner((COLLECTION_ID_0, 2)),
+			Some(RootNft { root: (COLLECTION_ID_0, NFT_ID_0), depth: 2 })
+		);
+		assert_eq!(
+			RMRKCore::root_owner((COLLECTION_ID_0, NFT_ID_1)),
+			Some(RootNft { root: (COLLECTION_ID_0, NFT_ID_0), depth: 1 })
+		);
+		assert_eq!(RMRKCore::root_owner((COLLECTION_ID_0, NFT_ID_0)), None);
 	});
 }
 
 #[test]
-fn remove_properties_with_internal_works() {
+fn replaced_resources_update_the_equippable_indexes() {
 	ExtBuilder::build().execute_with(|| {
-		// Define the keys of the properties
-		let keys = vec![stbk("test-key-1"), stbk("test-key-2"), stbk("test-key-3")];
-		// Define properties value
-		let value = stb("test-value");
-		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
 		assert_ok!(basic_mint(0));
-		// Root sets properties on NFT
-		keys.into_iter().for_each(|key| {
-			assert_ok!(RMRKCore::do_set_property(0, Some(0), key.clone(), value.clone()));
-
-			// Successful property setting should trigger a `PropertySet` event
-			System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
-				collection_id: 0,
-				maybe_nft_id: Some(0),
-				key: key.clone(),
-				value: value.clone(),
-			}));
+		assert_ok!(basic_mint(1));
+		for nft_id in [NFT_ID_0, NFT_ID_1] {
+			assert_ok!(RMRKCore::add_slot_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				nft_id,
+				SlotResource { base: 0, slot: 1, metadata: Some(stbd("slot")) },
+				Some(0),
+				None,
+			));
+			assert!(RMRKCore::is_equippable_into_slot(COLLECTION_ID_0, nft_id, 0, 0, 1));
+		}
 
-			// Property value now exists
-			assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), value);
-		});
+		// A slot resource replaced by a basic one can't be equipped anymore
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			ResourceTypes::Basic(BasicResource { metadata: stbd("basic") }),
+			0,
+		));
+		assert!(!RMRKCore::is_equippable_into_slot(COLLECTION_ID_0, NFT_ID_0, 0, 0, 1));
 
-		// `Origin::root()` removes all properties
-		assert_ok!(RMRKCore::do_remove_properties(
+		// An accepted replacement with another base and slot moves the index entries
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None
+		));
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			ResourceTypes::Composable(ComposableResource {
+				parts: bvec![0],
+				base: 1,
+				metadata: None,
+				slot: Some((2, 3)),
+			}),
 			0,
-			Some(0),
-			<Test as Config>::PropertiesLimit::get()
 		));
-		assert_eq!(RMRKCore::query_properties(0, Some(0), None).count(), 0);
+		assert!(RMRKCore::is_equippable_into_slot(COLLECTION_ID_0, NFT_ID_1, 0, 0, 1));
+		assert_ok!(RMRKCore::accept_resource_replacement(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			0
+		));
+		assert!(!RMRKCore::is_equippable_into_slot(COLLECTION_ID_0, NFT_ID_1, 0, 0, 1));
+		assert!(RMRKCore::is_equippable_into_slot(COLLECTION_ID_0, NFT_ID_1, 0, 2, 3));
+		assert!(RMRKCore::has_equippable_base(COLLECTION_ID_0, NFT_ID_1, 1));
+	});
+}
 
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertiesRemoved {
-			collection_id: 0,
-			maybe_nft_id: Some(0),
+#[test]
+fn pausing_covers_expiry_revocation_and_settings() {
+	ExtBuilder::build().execute_with(|| {
+		let not_paused = |call: crate::Call<Test>| CallsNotPaused::<Test>::contains(&call);
+		let expire = |maybe_resource_id| crate::Call::<Test>::expire_pending {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			maybe_resource_id,
+		};
+		let revoke =
+			|| crate::Call::<Test>::revoke { collection_id: COLLECTION_ID_0, nft_id: NFT_ID_0 };
+		let set_transferable = || crate::Call::<Test>::set_transferable {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			transferable: false,
+		};
+		let set_priority = || crate::Call::<Test>::set_priority {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			priorities: bvec![0],
+		};
+		let set_property = || crate::Call::<Test>::set_property {
+			collection_id: COLLECTION_ID_0,
+			maybe_nft_id: Some(NFT_ID_0),
+			key: stbk("key"),
+			value: stb("value"),
+		};
+
+		// Expiring a pending NFT and changing its transferability are transfers
+		assert_ok!(RMRKCore::pause_calls(Origin::root(), CallGroup::Transfers, Some(0)));
+		assert!(!not_paused(expire(None)));
+		assert!(!not_paused(set_transferable()));
+		assert!(not_paused(expire(Some(0))));
+		assert!(not_paused(revoke()));
+		assert_ok!(RMRKCore::unpause_calls(Origin::root(), CallGroup::Transfers, Some(0)));
+
+		// Revoking an NFT burns it
+		assert_ok!(RMRKCore::pause_calls(Origin::root(), CallGroup::Burning, Some(0)));
+		assert!(!not_paused(revoke()));
+		assert!(not_paused(expire(None)));
+		assert_ok!(RMRKCore::unpause_calls(Origin::root(), CallGroup::Burning, Some(0)));
+
+		// Expiring a pending resource and setting priorities are resource calls
+		assert_ok!(RMRKCore::pause_calls(Origin::root(), CallGroup::Resources, Some(0)));
+		assert!(!not_paused(expire(Some(0))));
+		assert!(!not_paused(set_priority()));
+		assert!(not_paused(set_property()));
+		assert_ok!(RMRKCore::unpause_calls(Origin::root(), CallGroup::Resources, Some(0)));
+
+		assert_ok!(RMRKCore::pause_calls(Origin::root(), CallGroup::Properties, Some(0)));
+		assert!(!not_paused(set_property()));
+		assert!(not_paused(set_priority()));
+		// The administration of collections stays available
+		assert!(not_paused(crate::Call::<Test>::lock_collection {
+			collection_id: COLLECTION_ID_0
 		}));
 	});
 }
 
-/// Priority: Setting priority tests (RMRK2.0 spec: SETPRIORITY)
 #[test]
-fn set_priority_works() {
-	ExtBuilder::build().execute_with(|| {
-		// Create a basic collection
-		assert_ok!(basic_collection());
-		// Mint NFT
-		assert_ok!(basic_mint(0));
-		// BOB cannot set priority on NFT
+#[allow(deprecated)]
+fn transfer_hooks_adapter_fails_rejected_transfers() {
+	use rmrk_traits::misc::{NftHooks, TransferHooks};
+	use sp_core::crypto::AccountId32;
+
+	// Only lets ALICE send NFTs, and only to BOB
+	struct AliceToBob;
+	impl TransferHooks<AccountId32, u32, u32> for AliceToBob {
+		fn pre_check(sender: &AccountId32, _: &u32, _: &u32) -> bool {
+			*sender == ALICE
+		}
+		fn post_transfer(_: &AccountId32, recipient: &AccountId32, _: &u32, _: &u32) -> bool {
+			*recipient == BOB
+		}
+	}
+	type Hooks = crate::TransferHooksAdapter<Test, AliceToBob>;
 
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(Hooks::pre_transfer(&ALICE, &COLLECTION_ID_0, &NFT_ID_0));
 		assert_noop!(
-			RMRKCore::set_priority(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, bvec![100, 500]),
-			Error::<Test>::NoPermission
+			Hooks::pre_transfer(&BOB, &COLLECTION_ID_0, &NFT_ID_0),
+			Error::<Test>::FailedTransferHooksPreCheck
 		);
-		// ALICE sets priority on NFT
-		assert_ok!(RMRKCore::set_priority(
+		assert_ok!(Hooks::post_transfer(&ALICE, &BOB, &COLLECTION_ID_0, &NFT_ID_0));
+		assert_noop!(
+			Hooks::post_transfer(&ALICE, &CHARLIE, &COLLECTION_ID_0, &NFT_ID_0),
+			Error::<Test>::FailedTransferHooksPostTransfer
+		);
+	});
+}
+
+/// NFT: Expired NFTs are burned with their resources accounted for, and locked ones are kept
+/// until they are unlocked
+#[test]
+fn expired_locked_nfts_are_kept_until_unlocked() {
+	ExtBuilder::build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
+			None,
 			NFT_ID_0,
-			bvec![100, 500] // BoundedVec Resource 0, 1
-		));
-		// Successful priority set should trigger PrioritySet event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PrioritySet {
-			collection_id: 0,
-			nft_id: 0,
-		}));
-		// Resource 100 should have priority 0
-		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 100)).unwrap(), 0);
-		// Resource 500 should have priority 1
-		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 500)).unwrap(), 1);
-		// Setting priority again drains and resets priorities
-		assert_ok!(RMRKCore::set_priority(
-			Origin::signed(ALICE),
 			COLLECTION_ID_0,
-			NFT_ID_0,
-			bvec![1000, 100] // BoundedVec Resources 2, 0
+			Some(ALICE),
+			Some(Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			None,
+			Some(5),
 		));
-		// Priorities reset, resource 100 should have priority one
-		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 100)).unwrap(), 1);
-		// Resource 1000 should have priority zero
-		assert_eq!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 1000)).unwrap(), 0);
-		// Resource 500 should no longer have a priority
-		assert!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 500)).is_none(),);
+		for resource_id in 0..2 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BasicResource { metadata: stbd("bafybeiakahlc6") },
+				Some(resource_id),
+				None
+			));
+		}
+		assert_ok!(RMRKCore::set_lock(*b"testlock", (COLLECTION_ID_0, NFT_ID_0)));
+		System::set_block_number(5);
+		// The locked NFT is kept and queued again for the next block
+		RMRKCore::on_idle(5, Weight::MAX);
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_some());
+		assert_eq!(ExpiryQueue::<Test>::get(6, (COLLECTION_ID_0, NFT_ID_0)), Some(()));
+		// Once unlocked, it is burned and the weight of its resources is reserved
+		RMRKCore::remove_lock(*b"testlock", (COLLECTION_ID_0, NFT_ID_0));
+		System::set_block_number(6);
+		let consumed = RMRKCore::on_idle(6, Weight::MAX);
+		assert!(consumed.all_gte(<Test as Config>::WeightInfo::burn_nft(
+			NestingBudget::get(),
+			PropertiesLimit::get(),
+			2
+		)));
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 0);
+		assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
 	});
 }
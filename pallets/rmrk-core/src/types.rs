@@ -5,6 +5,7 @@
 use frame_support::pallet_prelude::*;
 use sp_runtime::Permill;
 
+use rmrk_traits::AccountIdOrCollectionNftTuple;
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -28,3 +29,33 @@ pub struct InstanceInfo<AccountId, BoundedString> {
 	/// Arbitrary data about an instance, e.g. IPFS hash
 	pub metadata: BoundedString,
 }
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TransferApproval<AccountId, BlockNumber> {
+	/// Root owner who approved the transfer, the approval is void once the owner changes
+	pub owner: AccountId,
+	/// Account allowed to transfer the NFT
+	pub delegate: AccountId,
+	/// After this block the approval can't be used
+	pub expires: Option<BlockNumber>,
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub struct PendingTransfer<AccountId, CollectionId, NftId, BlockNumber> {
+	/// Root owner of the NFT when it was sent
+	pub sender: AccountId,
+	/// Owner of the NFT before it was sent, the NFT goes back there if rejected or expired
+	pub previous_owner: AccountIdOrCollectionNftTuple<AccountId, CollectionId, NftId>,
+	/// From this block anyone can send the NFT back to the sender
+	pub expires: Option<BlockNumber>,
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RootNft<CollectionId, NftId> {
+	/// Top-level NFT of the tree, its owner is the root owner of the nested NFT
+	pub root: (CollectionId, NftId),
+	/// Number of NFTs above the nested NFT, up to and including the root NFT
+	pub depth: u32,
+}
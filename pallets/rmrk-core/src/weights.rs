@@ -5,6 +5,10 @@
 //! DATE: 2023-01-17, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `Sergejs-MacBook-Air.local`, CPU: `<UNKNOWN>`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! The weights of the calls added or changed since this run are estimated from their storage
+//! accesses and marked as such. Regenerate this file with `make benchmark-core` before
+//! relying on them.
 
 // Executed Command:
 // ./target/release/rmrk-substrate
@@ -35,23 +39,54 @@ pub trait WeightInfo {
 	fn create_collection() -> Weight;
 	fn mint_nft() -> Weight;
 	fn mint_nft_directly_to_nft(n: u32) -> Weight;
-	fn destroy_collection() -> Weight;
+	fn destroy_collection(k: u32) -> Weight;
 	fn send_to_account(n: u32) -> Weight;
 	fn send_to_nft(n: u32) -> Weight;
-	fn burn_nft(n: u32, k: u32) -> Weight;
+	fn burn_nft(n: u32, k: u32, r: u32) -> Weight;
 	fn accept_nft(n: u32) -> Weight;
 	fn reject_nft(n: u32) -> Weight;
-	fn change_collection_issuer() -> Weight;
+	fn propose_issuer() -> Weight;
 	fn set_property() -> Weight;
 	fn lock_collection() -> Weight;
 	fn add_basic_resource(n: u32) -> Weight;
 	fn add_composable_resource(n: u32) -> Weight;
 	fn add_slot_resource(n: u32) -> Weight;
 	fn accept_resource(n: u32) -> Weight;
-	fn remove_resource(n: u32) -> Weight;
-	fn accept_resource_removal(n: u32) -> Weight;
+	fn remove_resource(n: u32, k: u32, c: u32) -> Weight;
+	fn accept_resource_removal(n: u32, k: u32, c: u32) -> Weight;
 	fn set_priority(n: u32, k: u32) -> Weight;
-	fn replace_resource() -> Weight;
+	fn replace_resource(n: u32) -> Weight;
+	fn accept_issuer() -> Weight;
+	fn cancel_issuer_proposal() -> Weight;
+	fn unlock_collection() -> Weight;
+	fn set_collection_max() -> Weight;
+	fn start_destroy_collection() -> Weight;
+	fn destroy_collection_items(n: u32, b: u32, p: u32) -> Weight;
+	fn approve_transfer(n: u32) -> Weight;
+	fn cancel_approval(n: u32) -> Weight;
+	fn set_operator() -> Weight;
+	fn send_batch(n: u32, b: u32) -> Weight;
+	fn set_pending_expiry() -> Weight;
+	fn expire_pending() -> Weight;
+	fn set_transferable() -> Weight;
+	fn revoke(n: u32, k: u32) -> Weight;
+	fn force_unlock() -> Weight;
+	fn pause_calls() -> Weight;
+	fn unpause_calls() -> Weight;
+	fn add_collection_resource() -> Weight;
+	fn remove_collection_resource(n: u32) -> Weight;
+	fn add_resource_to_many(n: u32, b: u32, ) -> Weight;
+	fn cleanup_burned_nft_entry() -> Weight;
+	fn add_media_resource(n: u32) -> Weight;
+	fn set_priority_for_context(n: u32, k: u32, c: u32) -> Weight;
+	fn move_priority(n: u32, k: u32) -> Weight;
+	fn swap_priorities(k: u32) -> Weight;
+	fn propose_resource(n: u32) -> Weight;
+	fn approve_resource_proposal() -> Weight;
+	fn reject_resource_proposal() -> Weight;
+	fn accept_resource_replacement(n: u32) -> Weight;
+	fn reject_resource_replacement(n: u32) -> Weight;
+	fn force_collection_issuer() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -71,11 +106,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
 	// Storage: Uniques Account (r:0 w:1)
+	// Storage: RmrkCore NftExpiry (r:0 w:1)
+	// Storage: RmrkCore ExpiryQueue (r:0 w:1)
+	// Storage: RmrkCore ExpiryCursor (r:1 w:1)
+	// Storage: RmrkCore PendingCleanup (r:1 w:0)
 	fn mint_nft() -> Weight {
-		// Minimum execution time: 47_000 nanoseconds.
-		Weight::from_ref_time(48_000_000)
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(52_000_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: RmrkCore Nfts (r:1 w:1)
@@ -84,15 +123,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
 	// Storage: RmrkCore Children (r:0 w:1)
 	// Storage: Uniques Account (r:0 w:1)
+	// Storage: RmrkCore NftExpiry (r:0 w:1)
+	// Storage: RmrkCore ExpiryQueue (r:0 w:1)
+	// Storage: RmrkCore ExpiryCursor (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore RootOwner (r:1 w:1)
+	// Storage: RmrkCore PendingCleanup (r:1 w:0)
 	/// The range of component `n` is `[1, 19]`.
 	fn mint_nft_directly_to_nft(n: u32, ) -> Weight {
-		// Minimum execution time: 52_000 nanoseconds.
-		Weight::from_ref_time(49_914_677)
-			// Standard Error: 32_019
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(53_614_902)
 			.saturating_add(Weight::from_ref_time(3_338_584).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(12))
 	}
 	// Storage: RmrkCore Collections (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
@@ -100,45 +145,60 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques ClassAccount (r:0 w:1)
 	// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	// Storage: Uniques CollectionMaxSupply (r:0 w:1)
-	fn destroy_collection() -> Weight {
-		// Minimum execution time: 50_000 nanoseconds.
-		Weight::from_ref_time(50_000_000)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(5))
+	// Storage: RmrkCore PendingIssuer (r:0 w:1)
+	// Storage: RmrkCore DestroyingCollections (r:0 w:1)
+	// Storage: RmrkCore Properties (r:0 w:1)
+	// Storage: RmrkCore CollectionResources (r:1 w:0)
+	/// The range of component `k` is `[0, 15]`.
+	fn destroy_collection(k: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(53_104_210)
+			.saturating_add(Weight::from_ref_time(1_104_853).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
 	}
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: RmrkCore Nfts (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
 	// Storage: Uniques Account (r:0 w:2)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	// Storage: RmrkCore Children (r:0 w:1)
+	// Storage: RmrkCore RootOwner (r:1 w:1)
+	// Storage: RmrkCore PendingReplacements (r:6 w:5)
+	// Storage: RmrkCore StorageDeposits (r:5 w:5)
+	// Storage: System Account (r:5 w:5)
 	/// The range of component `n` is `[1, 20]`.
 	fn send_to_account(n: u32, ) -> Weight {
-		// Minimum execution time: 41_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(40_529_053)
-			// Standard Error: 26_390
 			.saturating_add(Weight::from_ref_time(3_241_611).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(20))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(22))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	// Storage: Uniques Asset (r:2 w:1)
 	// Storage: RmrkCore Nfts (r:2 w:1)
 	// Storage: Uniques Class (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
 	// Storage: RmrkCore Children (r:0 w:1)
 	// Storage: Uniques Account (r:0 w:2)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: RmrkCore PendingReplacements (r:6 w:5)
+	// Storage: RmrkCore StorageDeposits (r:6 w:6)
+	// Storage: System Account (r:6 w:6)
+	// Storage: RmrkCore RootOwner (r:2 w:1)
 	/// The range of component `n` is `[1, 20]`.
 	fn send_to_nft(n: u32, ) -> Weight {
-		// Minimum execution time: 50_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(49_328_797)
-			// Standard Error: 25_787
 			.saturating_add(Weight::from_ref_time(3_267_385).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(25))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes(25))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: RmrkCore Nfts (r:1 w:1)
@@ -146,21 +206,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: RmrkCore Collections (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: RmrkCore Properties (r:0 w:25)
-	// Storage: RmrkCore Lock (r:0 w:1)
+	// Storage: RmrkCore Locks (r:0 w:1)
 	// Storage: Uniques Account (r:0 w:1)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: RmrkCore NftExpiry (r:1 w:1)
+	// Storage: RmrkCore ExpiryQueue (r:0 w:1)
+	// Storage: RmrkCore StorageDeposits (r:25 w:25)
+	// Storage: RmrkCore Priorities (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore RootOwner (r:0 w:1)
+	// Storage: RmrkCore ResourcesCount (r:0 w:1)
+	// Storage: RmrkCore NextResourceId (r:0 w:1)
+	// Storage: RmrkCore Properties (r:1 w:0)
+	// Storage: RmrkCore Resources (r:1 w:0)
+	// Storage: RmrkCore EquippableBases (r:1 w:0)
+	// Storage: RmrkCore EquippableSlots (r:1 w:0)
+	// Storage: RmrkCore PendingResourceExpiry (r:1 w:0)
+	// Storage: RmrkCore CleanupCursor (r:1 w:1)
+	// Storage: RmrkCore CleanupQueue (r:0 w:1)
+	// Storage: RmrkCore PendingCleanup (r:0 w:1)
+	// Storage: RmrkCore ContextPriorities (r:1 w:0)
 	/// The range of component `n` is `[1, 20]`.
 	/// The range of component `k` is `[0, 25]`.
-	fn burn_nft(n: u32, k: u32, ) -> Weight {
-		// Minimum execution time: 88_000 nanoseconds.
+	/// The range of component `r` is `[0, 10]`.
+	fn burn_nft(n: u32, k: u32, r: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(89_000_000)
-			// Standard Error: 146_384
 			.saturating_add(Weight::from_ref_time(76_768_378).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
 	}
 	// Storage: Uniques Asset (r:2 w:0)
 	// Storage: RmrkCore Nfts (r:1 w:1)
@@ -178,38 +258,39 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: RmrkCore Children (r:2 w:1)
 	// Storage: RmrkCore Collections (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
-	// Storage: RmrkCore Lock (r:0 w:1)
+	// Storage: RmrkCore Locks (r:0 w:1)
 	// Storage: Uniques Account (r:0 w:1)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: RmrkCore RootOwner (r:1 w:1)
 	/// The range of component `n` is `[1, 20]`.
 	fn reject_nft(n: u32, ) -> Weight {
-		// Minimum execution time: 79_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(80_661_256)
-			// Standard Error: 4_919
 			.saturating_add(Weight::from_ref_time(241_591).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
-	// Storage: RmrkCore Collections (r:1 w:1)
-	// Storage: Uniques OwnershipAcceptance (r:1 w:1)
-	// Storage: Uniques Class (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
-	// Storage: Uniques ClassAccount (r:0 w:2)
-	fn change_collection_issuer() -> Weight {
-		// Minimum execution time: 47_000 nanoseconds.
-		Weight::from_ref_time(48_000_000)
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(6))
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore PendingIssuer (r:0 w:1)
+	// Storage: RmrkCore DestroyingCollections (r:1 w:0)
+	fn propose_issuer() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	// Storage: RmrkCore Collections (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:0)
 	// Storage: RmrkCore Properties (r:0 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn set_property() -> Weight {
-		// Minimum execution time: 25_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(25_000_000)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: RmrkCore Collections (r:1 w:1)
 	fn lock_collection() -> Weight {
@@ -218,109 +299,589 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	// Storage: RmrkCore Resources (r:1 w:1)
-	fn replace_resource() -> Weight {
-		// Minimum execution time: 22_000 nanoseconds.
-		Weight::from_ref_time(22_000_000)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore Resources (r:1 w:0)
+	// Storage: RmrkCore PendingReplacements (r:6 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 20]`.
+	fn replace_resource(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(25_418_922)
+			.saturating_add(Weight::from_ref_time(3_092_561).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: RmrkCore Collections (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
 	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore ResourcesCount (r:1 w:1)
+	// Storage: RmrkCore NextResourceId (r:1 w:1)
+	// Storage: RmrkCore CollectionResources (r:1 w:0)
 	/// The range of component `n` is `[1, 20]`.
 	fn add_basic_resource(n: u32, ) -> Weight {
-		// Minimum execution time: 27_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(25_278_509)
-			// Standard Error: 26_152
 			.saturating_add(Weight::from_ref_time(3_137_474).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	// Storage: RmrkCore Collections (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
 	// Storage: RmrkCore Resources (r:1 w:1)
 	// Storage: RmrkCore EquippableBases (r:0 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore ResourcesCount (r:1 w:1)
+	// Storage: RmrkCore NextResourceId (r:1 w:1)
+	// Storage: RmrkCore CollectionResources (r:1 w:0)
 	/// The range of component `n` is `[1, 20]`.
 	fn add_composable_resource(n: u32, ) -> Weight {
-		// Minimum execution time: 30_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(27_984_467)
-			// Standard Error: 36_861
 			.saturating_add(Weight::from_ref_time(3_241_071).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	// Storage: RmrkCore Collections (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
 	// Storage: RmrkCore Resources (r:1 w:1)
 	// Storage: RmrkCore EquippableSlots (r:0 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore ResourcesCount (r:1 w:1)
+	// Storage: RmrkCore NextResourceId (r:1 w:1)
+	// Storage: RmrkCore CollectionResources (r:1 w:0)
 	/// The range of component `n` is `[1, 20]`.
 	fn add_slot_resource(n: u32, ) -> Weight {
-		// Minimum execution time: 31_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(29_855_362)
-			// Standard Error: 16_467
 			.saturating_add(Weight::from_ref_time(3_125_612).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	// Storage: Uniques Asset (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
 	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore ProposedResources (r:1 w:0)
 	/// The range of component `n` is `[1, 20]`.
 	fn accept_resource(n: u32, ) -> Weight {
-		// Minimum execution time: 26_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(25_260_173)
-			// Standard Error: 6_040
 			.saturating_add(Weight::from_ref_time(3_004_573).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	// Storage: RmrkCore Collections (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:0)
 	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: RmrkEquip Equippings (r:0 w:1)
+	// Storage: RmrkCore ContextPriorities (r:10 w:10)
+	// Storage: RmrkCore Priorities (r:26 w:25)
+	// Storage: RmrkCore ProposedResources (r:0 w:1)
+	// Storage: RmrkCore PendingReplacements (r:1 w:1)
+	// Storage: RmrkCore ResourcesCount (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:12 w:12)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore PendingResourceExpiry (r:0 w:1)
 	/// The range of component `n` is `[1, 20]`.
-	fn remove_resource(n: u32, ) -> Weight {
-		// Minimum execution time: 27_000 nanoseconds.
-		Weight::from_ref_time(25_585_465)
-			// Standard Error: 15_199
+	/// The range of component `k` is `[1, 25]`.
+	/// The range of component `c` is `[1, 10]`.
+	fn remove_resource(n: u32, k: u32, c: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(38_410_224)
 			.saturating_add(Weight::from_ref_time(3_086_680).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(Weight::from_ref_time(2_905_318).saturating_mul(k.into()))
+			.saturating_add(Weight::from_ref_time(6_172_940).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(12))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
 	}
 	// Storage: Uniques Asset (r:1 w:0)
 	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: RmrkEquip Equippings (r:0 w:1)
+	// Storage: RmrkCore ContextPriorities (r:10 w:10)
+	// Storage: RmrkCore Priorities (r:26 w:25)
+	// Storage: RmrkCore ProposedResources (r:0 w:1)
+	// Storage: RmrkCore PendingReplacements (r:1 w:1)
+	// Storage: RmrkCore ResourcesCount (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:12 w:12)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore PendingResourceExpiry (r:0 w:1)
 	/// The range of component `n` is `[1, 20]`.
-	fn accept_resource_removal(n: u32, ) -> Weight {
-		// Minimum execution time: 27_000 nanoseconds.
-		Weight::from_ref_time(25_169_054)
-			// Standard Error: 26_481
+	/// The range of component `k` is `[1, 25]`.
+	/// The range of component `c` is `[1, 10]`.
+	fn accept_resource_removal(n: u32, k: u32, c: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(37_994_813)
 			.saturating_add(Weight::from_ref_time(3_114_864).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_ref_time(2_911_620).saturating_mul(k.into()))
+			.saturating_add(Weight::from_ref_time(6_160_035).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(12))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
 	}
 	// Storage: Uniques Asset (r:20 w:0)
-	// Storage: RmrkCore Lock (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
 	// Storage: RmrkCore Priorities (r:0 w:1)
+	// Storage: RmrkCore StorageDeposits (r:25 w:25)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore Resources (r:25 w:0)
 	/// The range of component `n` is `[1, 25]`.
 	/// The range of component `k` is `[1, 20]`.
 	fn set_priority(n: u32, k: u32, ) -> Weight {
-		// Minimum execution time: 76_000 nanoseconds.
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(15_555_098)
-			// Standard Error: 3_863
 			.saturating_add(Weight::from_ref_time(2_826_170).saturating_mul(n.into()))
-			// Standard Error: 4_880
 			.saturating_add(Weight::from_ref_time(2_985_272).saturating_mul(k.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+	}
+	// Storage: RmrkCore Collections (r:1 w:1)
+	// Storage: RmrkCore PendingIssuer (r:1 w:1)
+	// Storage: Uniques OwnershipAcceptance (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Uniques ClassAccount (r:0 w:2)
+	// Storage: RmrkCore DestroyingCollections (r:1 w:0)
+	fn accept_issuer() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(56_000_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore PendingIssuer (r:1 w:1)
+	fn cancel_issuer_proposal() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(22_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore Collections (r:1 w:1)
+	fn unlock_collection() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(18_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore Collections (r:1 w:1)
+	fn set_collection_max() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore DestroyingCollections (r:1 w:1)
+	fn start_destroy_collection() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore DestroyingCollections (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:21 w:20)
+	// Storage: Uniques Asset (r:40 w:20)
+	// Storage: RmrkCore Collections (r:20 w:20)
+	// Storage: Uniques Class (r:20 w:20)
+	// Storage: RmrkCore Children (r:20 w:19)
+	// Storage: RmrkCore Properties (r:0 w:20)
+	// Storage: RmrkCore Locks (r:0 w:20)
+	// Storage: RmrkCore Resources (r:0 w:20)
+	// Storage: Uniques Account (r:0 w:20)
+	// Storage: Uniques ItemPriceOf (r:0 w:20)
+	// Storage: RmrkCore StorageDeposits (r:15 w:15)
+	/// The range of component `n` is `[1, 20]`.
+	/// The range of component `b` is `[1, 20]`.
+	/// The range of component `p` is `[0, 15]`.
+	fn destroy_collection_items(n: u32, b: u32, p: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(30_000_000)
+			.saturating_add(Weight::from_ref_time(89_000_000).saturating_mul(n.into()))
+			.saturating_add(
+				Weight::from_ref_time(76_768_378).saturating_mul(n.into()).saturating_mul(b.into()),
+			)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(
+				T::DbWeight::get().writes((6_u64).saturating_mul(n.into()).saturating_mul(b.into())),
+			)
+			.saturating_add(Weight::from_ref_time(1_104_853).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: RmrkCore Approvals (r:0 w:1)
+	/// The range of component `n` is `[1, 20]`.
+	fn approve_transfer(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(21_436_912)
+			.saturating_add(Weight::from_ref_time(3_105_266).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore Approvals (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:0)
+	/// The range of component `n` is `[1, 20]`.
+	fn cancel_approval(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(24_690_407)
+			.saturating_add(Weight::from_ref_time(3_127_810).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore Operators (r:0 w:1)
+	fn set_operator() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(16_000_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Uniques Asset (r:2 w:5)
+	// Storage: RmrkCore Nfts (r:2 w:5)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore DestroyingCollections (r:2 w:0)
+	// Storage: RmrkCore Approvals (r:0 w:5)
+	// Storage: Uniques Account (r:0 w:10)
+	// Storage: Uniques ItemPriceOf (r:0 w:5)
+	// Storage: RmrkCore Children (r:0 w:10)
+	// Storage: RmrkCore RootOwner (r:2 w:5)
+	/// The range of component `n` is `[1, 50]`.
+	/// The range of component `b` is `[0, 2000]`.
+	fn send_batch(n: u32, b: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(8_151_324)
+			.saturating_add(Weight::from_ref_time(43_260_881).saturating_mul(n.into()))
+			.saturating_add(Weight::from_ref_time(3_338_584).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore Resources (r:1 w:0)
+	// Storage: RmrkCore PendingResourceExpiry (r:0 w:1)
+	fn set_pending_expiry() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore PendingTransfers (r:1 w:1)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore Children (r:0 w:1)
+	// Storage: RmrkCore Approvals (r:0 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: RmrkCore RootOwner (r:1 w:1)
+	fn expire_pending() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(47_000_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	fn set_transferable() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(22_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore Collections (r:2 w:1)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: RmrkCore Children (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: RmrkCore Properties (r:0 w:25)
+	// Storage: RmrkCore Locks (r:0 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: RmrkCore NftExpiry (r:1 w:1)
+	// Storage: RmrkCore ExpiryQueue (r:0 w:1)
+	// Storage: RmrkCore RootOwner (r:1 w:1)
+	/// The range of component `n` is `[1, 20]`.
+	/// The range of component `k` is `[0, 25]`.
+	fn revoke(n: u32, k: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(95_000_000)
+			.saturating_add(Weight::from_ref_time(76_902_114).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: RmrkCore Locks (r:0 w:1)
+	fn force_unlock() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(16_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore PausedCalls (r:1 w:1)
+	fn pause_calls() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(12_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore PausedCalls (r:1 w:1)
+	fn unpause_calls() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(12_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore CollectionResources (r:21 w:1)
+	// Storage: RmrkCore CollectionEquippableBases (r:0 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn add_collection_resource() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(62_000_000)
+			.saturating_add(T::DbWeight::get().reads(24))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore CollectionResources (r:2 w:1)
+	// Storage: RmrkCore Nfts (r:50 w:50)
+	// Storage: RmrkCore Resources (r:50 w:0)
+	// Storage: RmrkEquip Equippings (r:0 w:50)
+	// Storage: RmrkCore ContextPriorities (r:500 w:500)
+	// Storage: RmrkCore Priorities (r:1300 w:1250)
+	// Storage: RmrkCore CollectionEquippableBases (r:0 w:1)
+	// Storage: RmrkCore StorageDeposits (r:551 w:551)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[0, 50]`.
+	fn remove_collection_resource(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(35_214_730)
+			.saturating_add(Weight::from_ref_time(134_802_166).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((49_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((48_u64).saturating_mul(n.into())))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore RootOwner (r:50 w:0)
+	// Storage: RmrkCore Nfts (r:50 w:0)
+	// Storage: Uniques Asset (r:50 w:0)
+	// Storage: RmrkCore Lock (r:50 w:0)
+	// Storage: RmrkCore Resources (r:50 w:50)
+	// Storage: RmrkCore StorageDeposits (r:50 w:50)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore EquippableBases (r:0 w:50)
+	// Storage: RmrkCore EquippableSlots (r:0 w:50)
+	// Storage: RmrkCore ResourcesCount (r:50 w:50)
+	// Storage: RmrkCore NextResourceId (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	/// The range of component `b` is `[1, 20]`.
+	fn add_resource_to_many(n: u32, b: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(11_872_410)
+			.saturating_add(Weight::from_ref_time(29_614_357).saturating_mul(n.into()))
+			.saturating_add(Weight::from_ref_time(3_338_584).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	// Storage: RmrkCore Properties (r:1 w:0)
+	// Storage: RmrkCore Resources (r:2 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore EquippableBases (r:0 w:1)
+	// Storage: RmrkCore EquippableSlots (r:0 w:1)
+	fn cleanup_burned_nft_entry() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(28_000_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore ResourcesCount (r:1 w:1)
+	// Storage: RmrkCore NextResourceId (r:1 w:1)
+	// Storage: RmrkCore CollectionResources (r:1 w:0)
+	/// The range of component `n` is `[1, 20]`.
+	fn add_media_resource(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(26_042_183)
+			.saturating_add(Weight::from_ref_time(3_137_474).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: Uniques Asset (r:20 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore ContextPriorities (r:11 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore Resources (r:25 w:0)
+	/// The range of component `n` is `[1, 25]`.
+	/// The range of component `k` is `[1, 20]`.
+	/// The range of component `c` is `[1, 10]`.
+	fn set_priority_for_context(n: u32, k: u32, c: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(24_114_520)
+			.saturating_add(Weight::from_ref_time(2_826_170).saturating_mul(n.into()))
+			.saturating_add(Weight::from_ref_time(2_985_272).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_ref_time(1_402_116).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+	}
+	// Storage: Uniques Asset (r:20 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore Priorities (r:26 w:25)
+	/// The range of component `n` is `[1, 25]`.
+	/// The range of component `k` is `[1, 20]`.
+	fn move_priority(n: u32, k: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(14_207_733)
+			.saturating_add(Weight::from_ref_time(1_482_390).saturating_mul(n.into()))
+			.saturating_add(Weight::from_ref_time(2_985_272).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
+	// Storage: Uniques Asset (r:20 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore Priorities (r:2 w:2)
+	/// The range of component `k` is `[1, 20]`.
+	fn swap_priorities(k: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(18_620_104)
+			.saturating_add(Weight::from_ref_time(2_985_272).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore ResourcesCount (r:1 w:1)
+	// Storage: RmrkCore NextResourceId (r:1 w:1)
+	// Storage: RmrkCore CollectionResources (r:1 w:0)
+	// Storage: RmrkCore ProposedResources (r:10 w:1)
+	/// The range of component `n` is `[1, 20]`.
+	fn propose_resource(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(39_502_117)
+			.saturating_add(Weight::from_ref_time(3_121_907).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore ProposedResources (r:1 w:1)
+	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore PendingResourceExpiry (r:0 w:1)
+	fn approve_resource_proposal() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore ProposedResources (r:1 w:1)
+	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RmrkCore Priorities (r:3 w:3)
+	// Storage: RmrkCore ResourcesCount (r:1 w:1)
+	// Storage: RmrkCore PendingResourceExpiry (r:0 w:1)
+	fn reject_resource_proposal() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore PendingReplacements (r:1 w:1)
+	// Storage: RmrkCore Resources (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 20]`.
+	fn accept_resource_replacement(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(31_204_377)
+			.saturating_add(Weight::from_ref_time(3_079_410).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: RmrkCore Locks (r:1 w:0)
+	// Storage: RmrkCore PendingReplacements (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 20]`.
+	fn reject_resource_replacement(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(23_861_045)
+			.saturating_add(Weight::from_ref_time(3_011_228).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: RmrkCore Collections (r:1 w:1)
+	// Storage: RmrkCore PendingIssuer (r:0 w:1)
+	// Storage: Uniques OwnershipAcceptance (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Uniques ClassAccount (r:0 w:2)
+	fn force_collection_issuer() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(50_000_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
 }
@@ -8,16 +8,12 @@ use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::traits::Currency;
 use frame_system::RawOrigin;
 use pallet_rmrk_core::Pallet as RmrkCore;
-use rmrk_traits::{ComposableResource, SlotPart, SlotResource};
+use rmrk_traits::{CollectionPolicy, ComposableResource, SlotPart, SlotResource};
 use sp_runtime::{traits::Bounded, Permill};
 use sp_std::vec;
 
 use crate::Pallet as RmrkEquip;
 
-pub type BalanceOf<T> = <<T as pallet_uniques::Config>::Currency as Currency<
-	<T as frame_system::Config>::AccountId,
->>::Balance;
-
 const SEED: u32 = 0;
 
 /// Turns a string into a BoundedVec
@@ -61,6 +57,7 @@ fn create_test_collection<T: Config>(
 		metadata,
 		max,
 		symbol,
+		CollectionPolicy::default(),
 	);
 
 	collection_id
@@ -87,6 +84,7 @@ fn mint_test_nft<T: Config>(
 		nft_metadata,
 		true,
 		resource,
+		None,
 	);
 	nft_id
 }
@@ -98,8 +96,13 @@ fn send_test_nft<T: Config>(
 	nft_id: T::ItemId,
 	new_owner_enum: AccountIdOrCollectionNftTuple<T::AccountId, T::CollectionId, T::ItemId>,
 ) {
-	let _ =
-		RmrkCore::<T>::send(RawOrigin::Signed(owner).into(), collection_id, nft_id, new_owner_enum);
+	let _ = RmrkCore::<T>::send(
+		RawOrigin::Signed(owner).into(),
+		collection_id,
+		nft_id,
+		new_owner_enum,
+		None,
+	);
 }
 
 /// Creates a base
@@ -110,6 +113,10 @@ fn base_create<T: Config>(
 		T::PartsLimit,
 	>,
 ) {
+	<T as pallet_uniques::Config>::Currency::make_free_balance_be(
+		&creator,
+		BalanceOf::<T>::max_value(),
+	);
 	let _ = RmrkEquip::<T>::create_base(
 		RawOrigin::Signed(creator).into(),
 		bvec![0u8; 20],
@@ -152,7 +159,8 @@ fn add_composable_resource<T: Config>(
 		collection_id,
 		item,
 		composable_resource,
-		0,
+		Some(0),
+		None,
 	);
 }
 
@@ -170,7 +178,8 @@ fn add_slot_resource<T: Config>(
 		collection_id,
 		item,
 		slot_resource,
-		0,
+		Some(0),
+		None,
 	);
 }
 
@@ -291,6 +300,8 @@ benchmarks! {
 			inherit: false,
 		};
 		base_create::<T>(caller.clone(), bvec![]);
+		// Overwrite an existing theme, whose properties are removed first
+		let _ = RmrkEquip::<T>::theme_add(RawOrigin::Signed(caller.clone()).into(), 0, default_theme.clone());
 	}: _(RawOrigin::Signed(caller.clone()), 0, default_theme)
 	verify {
 
@@ -3,11 +3,74 @@
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
 use super::*;
+use codec::Encode;
 use frame_support::traits::{tokens::Locker, Get};
 use rmrk_traits::budget;
 
 use sp_std::collections::btree_set::BTreeSet;
 
+impl<T: Config> NftHooks<T::AccountId, T::CollectionId, T::ItemId> for Pallet<T> {
+	/// Unequip an item whose equipped resource is being removed, so that no slot keeps pointing
+	/// to a missing resource.
+	fn pre_resource_remove(
+		collection_id: &T::CollectionId,
+		nft_id: &T::ItemId,
+		resource_id: &ResourceId,
+	) -> DispatchResult {
+		let nft = match pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id) {
+			Some(nft) => nft,
+			None => return Ok(()),
+		};
+		let slot_id = match nft.equipped {
+			Some((equipped_resource_id, slot_id)) if equipped_resource_id == *resource_id =>
+				slot_id,
+			_ => return Ok(()),
+		};
+		let base_id = match pallet_rmrk_core::Pallet::<T>::resolve_resource(
+			*collection_id,
+			*nft_id,
+			*resource_id,
+		)
+		.map(|res| res.resource)
+		{
+			Some(ResourceTypes::Slot(res)) => res.base,
+			Some(ResourceTypes::Composable(ComposableResource {
+				slot: Some((base, _)), ..
+			})) => base,
+			_ => return Ok(()),
+		};
+		// An item can only be equipped into its direct parent
+		if let AccountIdOrCollectionNftTuple::CollectionAndNftTuple(equipper_cid, equipper_nid) =
+			nft.owner
+		{
+			Equippings::<T>::remove(((equipper_cid, equipper_nid), base_id, slot_id));
+		}
+		pallet_rmrk_core::Nfts::<T>::mutate(collection_id, nft_id, |nft| {
+			if let Some(nft) = nft {
+				nft.equipped = None;
+			}
+		});
+
+		Self::deposit_event(Event::SlotUnequipped {
+			item_collection: *collection_id,
+			item_nft: *nft_id,
+			base_id,
+			slot_id,
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config> PausableCall<T::CollectionId> for Call<T> {
+	fn is_paused(&self, is_paused: impl Fn(CallGroup, Option<&T::CollectionId>) -> bool) -> bool {
+		match self {
+			Call::equip { item, .. } | Call::unequip { item, .. } =>
+				is_paused(CallGroup::Equipping, Some(&item.0)),
+			_ => false,
+		}
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	/// Helper function for getting next base ID
 	/// Currently, BaseId is auto-incremented from zero, may be worth changing
@@ -127,16 +190,23 @@ impl<T: Config>
 	) -> Result<BaseId, DispatchError> {
 		let base_id = Self::get_next_base_id()?;
 		for part in parts {
-			match part.clone() {
-				PartType::SlotPart(p) => {
-					Parts::<T>::insert(base_id, p.id, part);
-				},
-				PartType::FixedPart(p) => {
-					Parts::<T>::insert(base_id, p.id, part);
-				},
-			}
+			let part_id = match part.clone() {
+				PartType::SlotPart(p) => p.id,
+				PartType::FixedPart(p) => p.id,
+			};
+			pallet_rmrk_core::Pallet::<T>::reserve_storage_deposit(
+				&issuer,
+				&Parts::<T>::hashed_key_for(base_id, part_id),
+				part.encoded_size(),
+			)?;
+			Parts::<T>::insert(base_id, part_id, part);
 		}
-		let base = BaseInfo { issuer, base_type, symbol };
+		let base = BaseInfo { issuer: issuer.clone(), base_type, symbol };
+		pallet_rmrk_core::Pallet::<T>::reserve_storage_deposit(
+			&issuer,
+			&Bases::<T>::hashed_key_for(base_id),
+			base.encoded_size(),
+		)?;
 		Bases::<T>::insert(base_id, base);
 		Ok(base_id)
 	}
@@ -199,11 +269,13 @@ impl<T: Config>
 			!pallet_rmrk_core::Pallet::<T>::is_locked(item_collection_id, item_nft_id),
 			pallet_uniques::Error::<T>::Locked
 		);
+		pallet_rmrk_core::Pallet::<T>::ensure_not_expired(item_collection_id, item_nft_id)?;
 		// Check equipper NFT lock status
 		ensure!(
 			!pallet_rmrk_core::Pallet::<T>::is_locked(equipper_collection_id, equipper_nft_id),
 			pallet_uniques::Error::<T>::Locked
 		);
+		pallet_rmrk_core::Pallet::<T>::ensure_not_expired(equipper_collection_id, equipper_nft_id)?;
 
 		match pallet_rmrk_core::Pallet::<T>::nfts(item_collection_id, item_nft_id) {
 			None => {
@@ -265,26 +337,23 @@ impl<T: Config>
 		// Equipper must have a resource that is associated with the provided base ID
 		// First we iterate through the resources added to this NFT in search of the base ID
 		ensure!(
-			pallet_rmrk_core::Pallet::<T>::equippable_bases((
+			pallet_rmrk_core::Pallet::<T>::has_equippable_base(
 				equipper_collection_id,
 				equipper_nft_id,
-				// resource_id,
 				base_id
-			))
-			.is_some(),
+			),
 			Error::<T>::NoResourceForThisBaseFoundOnNft
 		);
 
 		// The item being equipped must have a resource that is equippable into that base.slot
 		ensure!(
-			pallet_rmrk_core::Pallet::<T>::equippable_slots((
+			pallet_rmrk_core::Pallet::<T>::is_equippable_into_slot(
 				item_collection_id,
 				item_nft_id,
 				resource_id,
 				base_id,
 				slot_id
-			))
-			.is_some(),
+			),
 			Error::<T>::ItemHasNoResourceToEquipThere
 		);
 
@@ -365,11 +434,13 @@ impl<T: Config>
 			!pallet_rmrk_core::Pallet::<T>::is_locked(item_collection_id, item_nft_id),
 			pallet_uniques::Error::<T>::Locked
 		);
+		pallet_rmrk_core::Pallet::<T>::ensure_not_expired(item_collection_id, item_nft_id)?;
 		// Check equipper NFT lock status
 		ensure!(
 			!pallet_rmrk_core::Pallet::<T>::is_locked(equipper_collection_id, equipper_nft_id),
 			pallet_uniques::Error::<T>::Locked
 		);
+		pallet_rmrk_core::Pallet::<T>::ensure_not_expired(equipper_collection_id, equipper_nft_id)?;
 
 		ensure!(
 			Self::slot_is_equipped((equipper_collection_id, equipper_nft_id), base_id, slot_id),
@@ -502,7 +573,13 @@ impl<T: Config>
 							},
 						};
 						// Overwrite Parts entry for this base_id.part_id
-						Parts::<T>::insert(base_id, part_id, PartType::SlotPart(slot_part));
+						let part = PartType::SlotPart(slot_part);
+						pallet_rmrk_core::Pallet::<T>::reserve_storage_deposit(
+							&issuer,
+							&Parts::<T>::hashed_key_for(base_id, part_id),
+							part.encoded_size(),
+						)?;
+						Parts::<T>::insert(base_id, part_id, part);
 						Ok((base_id, part_id))
 					},
 				}
@@ -555,8 +632,27 @@ impl<T: Config>
 		// fail
 		ensure!(def_count >= 1 || theme.name == default_as_bv, Error::<T>::NeedsDefaultThemeFirst);
 
+		// Remove the properties of a theme being overwritten, so that keys it no longer has
+		// don't keep their deposit
+		for key in Themes::<T>::iter_key_prefix((base_id, &theme.name))
+			.take(T::MaxPropertiesPerTheme::get() as usize)
+			.collect::<Vec<_>>()
+		{
+			pallet_rmrk_core::Pallet::<T>::release_storage_deposit(&Themes::<T>::hashed_key_for((
+				base_id,
+				&theme.name,
+				&key,
+			)));
+			Themes::<T>::remove((base_id, &theme.name, &key));
+		}
+
 		// Iterate through each property
 		for prop in theme.properties {
+			pallet_rmrk_core::Pallet::<T>::reserve_storage_deposit(
+				&issuer,
+				&Themes::<T>::hashed_key_for((base_id, &theme.name, &prop.key)),
+				prop.value.encoded_size(),
+			)?;
 			Themes::<T>::insert((base_id, theme.name.clone(), prop.key), prop.value)
 		}
 		Ok(())
@@ -20,7 +20,8 @@ pub use weights::WeightInfo;
 use rmrk_traits::{
 	base::EquippableOperation,
 	primitives::{BaseId, PartId, ResourceId, SlotId},
-	AccountIdOrCollectionNftTuple, Base, BaseInfo, EquippableList, PartType, Theme, ThemeProperty,
+	AccountIdOrCollectionNftTuple, Base, BaseInfo, CallGroup, ComposableResource, EquippableList,
+	NftHooks, PartType, PausableCall, ResourceTypes, Theme, ThemeProperty,
 };
 
 use sp_std::vec::Vec;
@@ -63,6 +64,8 @@ pub type BoundedThemePropertiesOf<T> =
 
 pub type BoundedThemeOf<T> = Theme<StringLimitOf<T>, BoundedThemePropertiesOf<T>>;
 
+pub type BalanceOf<T> = pallet_rmrk_core::BalanceOf<T>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
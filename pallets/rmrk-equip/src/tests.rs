@@ -4,7 +4,9 @@
 
 use super::*;
 
-use rmrk_traits::{ComposableResource, FixedPart, SlotPart, SlotResource, ThemeProperty};
+use rmrk_traits::{
+	CollectionPolicy, ComposableResource, FixedPart, SlotPart, SlotResource, ThemeProperty,
+};
 
 use frame_support::{assert_noop, assert_ok};
 use mock::{RuntimeEvent as MockEvent, RuntimeOrigin as Origin, *};
@@ -67,6 +69,9 @@ fn create_base_works() {
 			bvec![0u8; 20],        // symbol
 			bvec![PartType::FixedPart(fixed_part), PartType::SlotPart(slot_part),],
 		));
+		// The base and its parts are paid for by the issuer
+		assert_eq!(pallet_rmrk_core::StorageDeposits::<Test>::iter().count(), 3);
+		assert!(Balances::reserved_balance(ALICE) > 0);
 	});
 }
 
@@ -161,7 +166,8 @@ fn equip_works() {
 			COLLECTION_ID_0,
 			stb("ipfs://col0-metadata"), // metadata
 			Some(5),                     // max
-			sbvec!["COL0"]               // symbol
+			sbvec!["COL0"],              // symbol
+			CollectionPolicy::default(),
 		));
 
 		// Create collection 1
@@ -170,7 +176,8 @@ fn equip_works() {
 			COLLECTION_ID_1,
 			stb("ipfs://col1-metadata"), // metadata
 			Some(5),                     // max
-			sbvec!["COL1"]               // symbol
+			sbvec!["COL1"],              // symbol
+			CollectionPolicy::default(),
 		));
 
 		// Mint NFT 0 from collection 0 (character-0)
@@ -184,6 +191,7 @@ fn equip_works() {
 			stb("ipfs://character-0-metadata"), // metadata
 			true,
 			None,
+			None,
 		));
 
 		// Mint NFT 1 from collection 0 (character-1)
@@ -197,6 +205,7 @@ fn equip_works() {
 			stb("ipfs://character-1-metadata"), // metadata
 			true,
 			None,
+			None,
 		));
 
 		// Mint NFT 0 from collection 1 (sword)
@@ -210,6 +219,7 @@ fn equip_works() {
 			stb("ipfs://sword-metadata"),     // metadata
 			true,
 			None,
+			None,
 		));
 
 		// Mint NFT 1 from collection 1 (flashlight)
@@ -223,6 +233,7 @@ fn equip_works() {
 			stb("ipfs://flashlight-metadata"), // metadata
 			true,
 			None,
+			None,
 		));
 
 		// Attempt to equip sword should fail as character-0 doesn't own sword
@@ -243,7 +254,7 @@ fn equip_works() {
 			Origin::signed(ALICE),
 			1,                                                          // Collection ID
 			0,                                                          // NFT ID
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0), // Recipient
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0), // Recipient, None
 		));
 
 		// Sends NFT (0, 2) [flashlight] to NFT (0, 0) [character-0]
@@ -251,7 +262,7 @@ fn equip_works() {
 			Origin::signed(ALICE),
 			1,                                                          // Collection ID
 			0,                                                          // NFT ID
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0), // Recipient
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0), // Recipient, None
 		));
 
 		// Attempt to equip sword should fail as character-0 doesn't have a resource that is
@@ -282,7 +293,8 @@ fn equip_works() {
 			0, // collection_id
 			0, // nft id
 			composable_resource,
-			0,
+			Some(0),
+			None,
 		));
 
 		// Attempt to equip sword should fail as the sword doesn't have a resource that is
@@ -311,7 +323,8 @@ fn equip_works() {
 			1, // collection id
 			0, // nft id
 			sword_slot_resource_left,
-			0,
+			Some(0),
+			None,
 		));
 
 		let flashlight_slot_resource_left = SlotResource {
@@ -326,7 +339,8 @@ fn equip_works() {
 			1, // collection id
 			1, // nft id
 			flashlight_slot_resource_left,
-			0,
+			Some(0),
+			None,
 		));
 
 		// Equipping sword should now work
@@ -379,7 +393,8 @@ fn equip_works() {
 			1, // collection id
 			0, // nft id
 			sword_slot_resource_right,
-			1,
+			Some(1),
+			None,
 		));
 
 		// Equipping to right-hand should fail (already equipped in left hand)
@@ -482,7 +497,8 @@ fn equip_works() {
 				Origin::signed(ALICE),
 				1,
 				0,
-				AccountIdOrCollectionNftTuple::AccountId(BOB)
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+				None
 			),
 			pallet_rmrk_core::Error::<Test>::CannotSendEquippedItem,
 		);
@@ -548,7 +564,8 @@ fn nested_equip_works() {
 			COLLECTION_ID_0,
 			stb("person-collection"), // metadata
 			Some(5),                  // max
-			sbvec!["COL0"]            // symbol
+			sbvec!["COL0"],           // symbol
+			CollectionPolicy::default(),
 		));
 
 		// Create HEADWARE collection (1)
@@ -557,7 +574,8 @@ fn nested_equip_works() {
 			COLLECTION_ID_1,
 			stb("headware-collection"), // metadata
 			Some(5),                    // max
-			sbvec!["COL1"]              // symbol
+			sbvec!["COL1"],             // symbol
+			CollectionPolicy::default(),
 		));
 
 		// Create GEM collection (2)
@@ -566,7 +584,8 @@ fn nested_equip_works() {
 			COLLECTION_ID_2,
 			stb("gem-collection"), // metadata
 			Some(5),               // max
-			sbvec!["COL2"]         // symbol
+			sbvec!["COL2"],        // symbol
+			CollectionPolicy::default(),
 		));
 
 		// Mint PERSON 0
@@ -580,6 +599,7 @@ fn nested_equip_works() {
 			stb("ipfs://person-0-metadata"),  // metadata
 			true,
 			None,
+			None,
 		));
 
 		// Mint HAT 0
@@ -593,6 +613,7 @@ fn nested_equip_works() {
 			stb("hat-0"),                     // metadata
 			true,
 			None,
+			None,
 		));
 
 		// Mint GEM 0
@@ -606,6 +627,7 @@ fn nested_equip_works() {
 			stb("gem-0"),                     // metadata
 			true,
 			None,
+			None,
 		));
 
 		// Sends hat-0 to person-0
@@ -613,7 +635,7 @@ fn nested_equip_works() {
 			Origin::signed(ALICE),
 			1,                                                          // Collection ID
 			0,                                                          // NFT ID
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0), // Recipient
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0), // Recipient, None
 		));
 
 		// Sends gem-0 to hat-0
@@ -621,7 +643,7 @@ fn nested_equip_works() {
 			Origin::signed(ALICE),
 			2,                                                          // Collection ID
 			0,                                                          // NFT ID
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(1, 0), // Recipient
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(1, 0), // Recipient, None
 		));
 
 		// Create Composable resource for person-0
@@ -640,7 +662,8 @@ fn nested_equip_works() {
 			0, // collection_id
 			0, // nft id
 			composable_resource_for_person_zero,
-			0,
+			Some(0),
+			None,
 		));
 
 		// Create Composable resource for hat-0
@@ -660,7 +683,8 @@ fn nested_equip_works() {
 			1, // collection_id
 			0, // nft id
 			composable_resource_for_hat_zero,
-			0,
+			Some(0),
+			None,
 		));
 
 		// Create Slot resource for gem-0
@@ -677,7 +701,8 @@ fn nested_equip_works() {
 			2, // collection id
 			0, // nft id
 			gem_slot_resource,
-			0,
+			Some(0),
+			None,
 		));
 
 		for i in pallet_rmrk_core::EquippableSlots::<Test>::iter_prefix((0, 0)) {
@@ -1228,3 +1253,125 @@ fn theme_add_too_many_properties_fails() {
 		);
 	});
 }
+
+/// Base: Removing an equipped resource unequips the item
+#[test]
+fn removing_equipped_resource_unequips_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		let slot_part_left_hand = SlotPart {
+			id: 201,
+			z: 0,
+			src: Some(stb("left-hand")),
+			equippable: EquippableList::All,
+		};
+		assert_ok!(RmrkEquip::create_base(
+			Origin::signed(ALICE),
+			stb("svg"),
+			stb("KANPEOPLE"),
+			bvec![PartType::SlotPart(slot_part_left_hand)],
+		));
+		for collection_id in [COLLECTION_ID_0, COLLECTION_ID_1] {
+			assert_ok!(RmrkCore::create_collection(
+				Origin::signed(ALICE),
+				collection_id,
+				stb("ipfs://col-metadata"),
+				Some(5),
+				sbvec!["COL"],
+				CollectionPolicy::default(),
+			));
+			assert_ok!(RmrkCore::mint_nft(
+				Origin::signed(ALICE),
+				Some(ALICE),
+				0,
+				collection_id,
+				None,
+				None,
+				stb("ipfs://nft-metadata"),
+				true,
+				None,
+				None,
+			));
+		}
+		// Sword (1, 0) is owned by character (0, 0)
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_1,
+			0,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 0),
+			None,
+		));
+		assert_ok!(RmrkCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			0,
+			ComposableResource { parts: bvec![201], base: 0, metadata: None, slot: None },
+			Some(0),
+			None,
+		));
+		assert_ok!(RmrkCore::add_slot_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_1,
+			0,
+			SlotResource { base: 0, metadata: None, slot: 201 },
+			Some(0),
+			None,
+		));
+		assert_ok!(RmrkEquip::equip(Origin::signed(ALICE), (1, 0), (0, 0), 0, 0, 201));
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), Some(0));
+
+		// Removing the equipped resource of the sword unequips it
+		assert_ok!(RmrkCore::remove_resource(Origin::signed(ALICE), COLLECTION_ID_1, 0, 0));
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), None);
+		assert_eq!(RmrkCore::nfts(COLLECTION_ID_1, 0).unwrap().equipped, None);
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::RmrkEquip(crate::Event::SlotUnequipped {
+				item_collection: COLLECTION_ID_1,
+				item_nft: 0,
+				base_id: 0,
+				slot_id: 201,
+			})));
+	});
+}
+
+/// Theme add: overwriting a theme removes the properties it no longer has and their deposits
+#[test]
+fn theme_overwrite_releases_removed_properties() {
+	use codec::Encode;
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(RmrkEquip::create_base(
+			Origin::signed(ALICE), // origin
+			bvec![0u8; 20],        // base_type
+			bvec![0u8; 20],        // symbol
+			bvec![],
+		));
+		let reserved_with_base = Balances::reserved_balance(ALICE);
+		assert_ok!(RmrkEquip::theme_add(
+			Origin::signed(ALICE),
+			0, // BaseID
+			Theme {
+				name: stb("default"),
+				properties: bvec![
+					ThemeProperty { key: stb("primary_color"), value: stb("red") },
+					ThemeProperty { key: stb("secondary_color"), value: stb("blue") },
+				],
+				inherit: false,
+			}
+		));
+		let short_theme = Theme {
+			name: stb("default"),
+			properties: bvec![ThemeProperty { key: stb("primary_color"), value: stb("red") }],
+			inherit: false,
+		};
+		assert_ok!(RmrkEquip::theme_add(Origin::signed(ALICE), 0, short_theme));
+
+		assert_eq!(RmrkEquip::themes((0, stb("default"), stb("secondary_color"))), None);
+		assert_eq!(RmrkEquip::themes((0, stb("default"), stb("primary_color"))), Some(stb("red")));
+		// Only the deposit of the remaining property is held
+		let property_deposit = pallet_rmrk_core::Pallet::<Test>::storage_deposit(
+			Themes::<Test>::hashed_key_for((0, stb("default"), stb("primary_color"))).len(),
+			stb("red").encoded_size(),
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved_with_base + property_deposit);
+	});
+}
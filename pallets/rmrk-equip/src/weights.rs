@@ -5,6 +5,10 @@
 //! DATE: 2022-12-26, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `Sergejs-MacBook-Air.local`, CPU: `<UNKNOWN>`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! The weights of the calls added or changed since this run are estimated from their storage
+//! accesses and marked as such. Regenerate this file with `make benchmark-equip` before
+//! relying on them.
 
 // Executed Command:
 // ./target/release/rmrk-substrate
@@ -59,9 +63,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: RmrkCore EquippableBases (r:1 w:0)
 	// Storage: RmrkCore EquippableSlots (r:1 w:0)
 	// Storage: RmrkEquip Parts (r:1 w:0)
+	// Storage: RmrkCore CollectionEquippableBases (r:1 w:0)
+	// Storage: RmrkCore Resources (r:1 w:0)
+	// Storage: RmrkCore CollectionResources (r:1 w:0)
 	fn equip() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(47_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: RmrkCore Lock (r:2 w:0)
@@ -75,37 +83,52 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: RmrkEquip Bases (r:1 w:0)
 	// Storage: RmrkEquip Parts (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn equippable() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(16_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: RmrkEquip Bases (r:1 w:0)
 	// Storage: RmrkEquip Parts (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn equippable_add() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(16_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: RmrkEquip Bases (r:1 w:0)
 	// Storage: RmrkEquip Parts (r:1 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn equippable_remove() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(17_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: RmrkEquip Bases (r:1 w:0)
-	// Storage: RmrkEquip Themes (r:1 w:2)
+	// Storage: RmrkEquip Themes (r:3 w:4)
+	// Storage: RmrkCore StorageDeposits (r:4 w:4)
+	// Storage: System Account (r:1 w:1)
 	fn theme_add() -> Weight {
-		Weight::from_ref_time(16_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(21_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: RmrkEquip NextBaseId (r:1 w:1)
 	// Storage: RmrkEquip Bases (r:0 w:1)
+	// Storage: RmrkCore StorageDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn create_base() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
 		Weight::from_ref_time(13_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 }
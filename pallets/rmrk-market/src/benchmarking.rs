@@ -4,9 +4,10 @@
 
 use super::*;
 
-use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::traits::Get;
 use pallet_rmrk_core::Pallet as RmrkCore;
+use rmrk_traits::CollectionPolicy;
 use sp_runtime::{traits::Bounded, Permill, SaturatedConversion};
 
 use crate::Pallet as RmrkMarket;
@@ -65,6 +66,7 @@ fn create_test_collection<T: Config>(
 		metadata,
 		max,
 		symbol,
+		CollectionPolicy::default(),
 	);
 
 	collection_id
@@ -92,6 +94,7 @@ fn mint_test_nft<T: Config>(
 		nft_metadata,
 		true,
 		resource,
+		None,
 	);
 	nft_id
 }
@@ -199,5 +202,19 @@ benchmarks! {
 		assert_last_event::<T>(Event::OfferAccepted { owner: caller, buyer: offerer, collection_id, nft_id }.into());
 	}
 
+	force_unlist {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_index = 1;
+
+		let collection_id = create_test_collection::<T>(caller.clone(), collection_index);
+		let nft_id = mint_test_nft::<T>(caller.clone(), None, collection_id, 42);
+
+		let _ = list_test_nft::<T>(caller.clone(), collection_id, nft_id, 100);
+		let origin = T::ProtocolOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, collection_id, nft_id)
+	verify {
+		assert_last_event::<T>(Event::TokenUnlisted { owner: caller, collection_id, nft_id }.into());
+	}
+
 	impl_benchmark_test_suite!(RmrkMarket, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -9,7 +9,7 @@
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	traits::{Currency, ExistenceRequirement, ReservableCurrency},
+	traits::{Currency, ExistenceRequirement, LockIdentifier, ReservableCurrency},
 	transactional, BoundedVec,
 };
 use frame_system::{ensure_signed, RawOrigin};
@@ -21,7 +21,7 @@ pub use pallet::*;
 pub mod weights;
 pub use weights::WeightInfo;
 
-use rmrk_traits::{AccountIdOrCollectionNftTuple, NftInfo};
+use rmrk_traits::{AccountIdOrCollectionNftTuple, CallGroup, NftInfo, PausableCall};
 
 pub mod types;
 
@@ -40,6 +40,9 @@ mod tests;
 use crate::types::Offer;
 pub use pallet::*;
 
+/// Lock reason of the NFTs listed on the market
+pub const MARKET_LOCK_ID: LockIdentifier = *b"rmrkmrkt";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -274,7 +277,7 @@ pub mod pallet {
 			);
 
 			// Lock NFT to prevent transfers or interactions with the NFT
-			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), true);
+			pallet_rmrk_core::Pallet::<T>::set_lock(MARKET_LOCK_ID, (collection_id, nft_id))?;
 
 			// Add new ListInfo with listed_by, amount, Option<BlockNumber>
 			ListedNfts::<T>::insert(
@@ -309,8 +312,8 @@ pub mod pallet {
 				.ok_or(Error::<T>::TokenDoesNotExist)?;
 			// Ensure owner of NFT is performing call to unlist
 			ensure!(sender == owner, Error::<T>::NoPermission);
-			// Set the NFT lock to false to allow interactions with the NFT
-			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+			// Remove the market lock to allow interactions with the NFT
+			pallet_rmrk_core::Pallet::<T>::remove_lock(MARKET_LOCK_ID, (collection_id, nft_id));
 			// Remove from storage
 			ListedNfts::<T>::remove(collection_id, nft_id);
 			// Emit TokenUnlisted Event
@@ -467,6 +470,48 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Remove a listing regardless of who listed the NFT. Used by governance to take down
+		/// listings of abusive content.
+		///
+		/// Parameters:
+		/// - `origin` - Must satisfy `ProtocolOrigin`
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_unlist())]
+		#[transactional]
+		pub fn force_unlist(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			nft_id: T::ItemId,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+			let list_info = ListedNfts::<T>::take(collection_id, nft_id)
+				.ok_or(Error::<T>::CannotUnlistToken)?;
+			// Remove the market lock to allow interactions with the NFT
+			pallet_rmrk_core::Pallet::<T>::remove_lock(MARKET_LOCK_ID, (collection_id, nft_id));
+			Self::deposit_event(Event::TokenUnlisted {
+				owner: list_info.listed_by,
+				collection_id,
+				nft_id,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> PausableCall<T::CollectionId> for Call<T> {
+	fn is_paused(&self, is_paused: impl Fn(CallGroup, Option<&T::CollectionId>) -> bool) -> bool {
+		// Unlisting and withdrawing offers stay available while trading is paused
+		match self {
+			Call::buy { collection_id, .. } |
+			Call::list { collection_id, .. } |
+			Call::make_offer { collection_id, .. } |
+			Call::accept_offer { collection_id, .. } => is_paused(CallGroup::Trading, Some(collection_id)),
+			_ => false,
+		}
 	}
 }
 
@@ -520,8 +565,9 @@ impl<T: Config> Pallet<T> {
 			ensure!(list_price == amount, Error::<T>::PriceDiffersFromExpected);
 		}
 
-		// Set NFT Lock status to false to facilitate the purchase
-		pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+		// Remove the market lock to facilitate the purchase, locks set by other pallets
+		// still prevent the transfer
+		pallet_rmrk_core::Pallet::<T>::remove_lock(MARKET_LOCK_ID, (collection_id, nft_id));
 
 		// Transfer currency then transfer the NFT
 		<T as pallet::Config>::Currency::transfer(
@@ -532,7 +578,7 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		let new_owner = AccountIdOrCollectionNftTuple::AccountId(buyer.clone());
-		pallet_rmrk_core::Pallet::<T>::send(owner_origin, collection_id, nft_id, new_owner)?;
+		pallet_rmrk_core::Pallet::<T>::send(owner_origin, collection_id, nft_id, new_owner, None)?;
 
 		Self::deposit_event(Event::TokenSold {
 			owner,
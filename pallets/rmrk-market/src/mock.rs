@@ -99,8 +99,19 @@ parameter_types! {
 	pub const ResourceSymbolLimit: u32 = 10;
 	pub const PartsLimit: u32 = 10;
 	pub const MaxPriorities: u32 = 3;
+	pub const MaxPriorityContexts: u32 = 3;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const MaxResourcesOnMint: u32 = 3;
+	pub const MaxResourcesPerNft: u32 = 10;
+	pub const MaxProposalsPerNft: u32 = 3;
+	pub const MaxReplacementsPerNft: u32 = 3;
+	pub const MaxCollectionResources: u32 = 5;
+	pub const MaxCollectionResourceNfts: u32 = 5;
+	pub const MaxBatchSends: u32 = 5;
+	pub const MaxBatchResources: u32 = 5;
+	pub const MaxDestroyItems: u32 = 10;
+	pub const MaxLocks: u32 = 3;
+	pub const StorageDepositPerByte: Balance = 0;
 	pub const PropertiesLimit: u32 = 15;
 	pub const NestingBudget: u32 = 10;
 }
@@ -114,14 +125,25 @@ impl pallet_rmrk_core::Config for Test {
 	type ResourceSymbolLimit = ResourceSymbolLimit;
 	type PartsLimit = PartsLimit;
 	type MaxPriorities = MaxPriorities;
+	type MaxPriorityContexts = MaxPriorityContexts;
 	type CollectionSymbolLimit = CollectionSymbolLimit;
 	type MaxResourcesOnMint = MaxResourcesOnMint;
+	type MaxResourcesPerNft = MaxResourcesPerNft;
+	type MaxProposalsPerNft = MaxProposalsPerNft;
+	type MaxReplacementsPerNft = MaxReplacementsPerNft;
+	type MaxCollectionResources = MaxCollectionResources;
+	type MaxCollectionResourceNfts = MaxCollectionResourceNfts;
 	type PropertiesLimit = PropertiesLimit;
 	type NestingBudget = NestingBudget;
+	type MaxBatchSends = MaxBatchSends;
+	type MaxBatchResources = MaxBatchResources;
+	type MaxDestroyItems = MaxDestroyItems;
+	type MaxLocks = MaxLocks;
+	type StorageDepositPerByte = StorageDepositPerByte;
 	type WeightInfo = pallet_rmrk_core::weights::SubstrateWeight<Test>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = RmrkBenchmark;
-	type TransferHooks = ();
+	type NftHooks = ();
 }
 
 parameter_types! {
@@ -4,8 +4,9 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Contains};
 use mock::{RuntimeEvent as MockEvent, RuntimeOrigin as Origin};
+use rmrk_traits::{CallGroup, CollectionPolicy};
 
 use sp_runtime::Permill;
 use sp_std::convert::TryInto;
@@ -24,6 +25,7 @@ fn basic_collection() -> DispatchResult {
 		bvec![0u8; 20],
 		Some(5),
 		bvec![0u8; 15],
+		CollectionPolicy::default(),
 	)
 }
 
@@ -39,6 +41,7 @@ fn basic_mint(id: u32) -> DispatchResult {
 		bvec![0u8; 20],
 		true,
 		None,
+		None,
 	)
 }
 
@@ -77,6 +80,7 @@ fn list_works() {
 			COLLECTION_ID_0,
 			NFT_ID_1,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+			None,
 		));
 		// Successful send to NFT triggers NFTSent event
 		System::assert_last_event(MockEvent::RmrkCore(pallet_rmrk_core::Event::NFTSent {
@@ -128,6 +132,7 @@ fn list_non_transferable_fail() {
 			bvec![0u8; 20],
 			false, // non-transferable
 			None,
+			None,
 		));
 		assert_noop!(
 			RmrkMarket::list(Origin::signed(ALICE), COLLECTION_ID_0, 0, 10u128, None,),
@@ -152,6 +157,7 @@ fn list_frozen_fail() {
 			bvec![0u8; 20],
 			true, // transferable
 			None,
+			None,
 		));
 		// freeze NFT
 		assert_ok!(pallet_uniques::Pallet::<Test>::freeze(
@@ -291,6 +297,7 @@ fn send_wont_work_if_sent_after_list() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+				None,
 			),
 			pallet_uniques::Error::<Test>::Locked
 		);
@@ -350,6 +357,7 @@ fn send_to_nft_wont_work_after_list() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_1),
+				None,
 			),
 			pallet_uniques::Error::<Test>::Locked
 		);
@@ -381,6 +389,7 @@ fn send_to_nft_wont_work_after_list() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_1),
+			None,
 		));
 		// Successful send triggers NFTSent event
 		System::assert_last_event(MockEvent::RmrkCore(pallet_rmrk_core::Event::NFTSent {
@@ -429,6 +438,7 @@ fn accept_offer_wont_work_if_traded_to_nft_after_list() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_1),
+			None,
 		));
 		// Successful send to NFT triggers NFTSent event
 		System::assert_last_event(MockEvent::RmrkCore(pallet_rmrk_core::Event::NFTSent {
@@ -449,6 +459,38 @@ fn accept_offer_wont_work_if_traded_to_nft_after_list() {
 	});
 }
 
+#[test]
+fn unlist_keeps_locks_of_other_pallets() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		// Another pallet locks the NFT
+		assert_ok!(RmrkCore::set_lock(*b"staking ", (COLLECTION_ID_0, NFT_ID_0)));
+		// ALICE lists and unlists the NFT
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10u128,
+			None,
+		));
+		assert_ok!(RmrkMarket::unlist(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0,));
+		// The lock set by the other pallet is still active
+		assert_eq!(RmrkCore::locks((COLLECTION_ID_0, NFT_ID_0)).to_vec(), vec![*b"staking "]);
+		assert_noop!(
+			RmrkCore::send(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+				None,
+			),
+			pallet_uniques::Error::<Test>::Locked
+		);
+	});
+}
+
 #[test]
 fn unlist_works() {
 	new_test_ext().execute_with(|| {
@@ -514,6 +556,46 @@ fn unlist_works() {
 	});
 }
 
+#[test]
+fn force_unlist_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10u128,
+			None,
+		));
+		// Only ProtocolOrigin can force unlist
+		assert_noop!(
+			RmrkMarket::force_unlist(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(RmrkMarket::force_unlist(Origin::root(), COLLECTION_ID_0, NFT_ID_0));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenUnlisted {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert!(!RmrkMarket::is_nft_listed(COLLECTION_ID_0, NFT_ID_0));
+		// Market lock is released so the owner can send the NFT again
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+			None,
+		));
+		// Nothing left to unlist
+		assert_noop!(
+			RmrkMarket::force_unlist(Origin::root(), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::CannotUnlistToken
+		);
+	});
+}
+
 #[test]
 fn offer_works() {
 	new_test_ext().execute_with(|| {
@@ -818,3 +900,33 @@ fn accept_expired_offer_wont_works() {
 		);
 	});
 }
+
+#[test]
+fn paused_trading_is_filtered() {
+	new_test_ext().execute_with(|| {
+		type CallsNotPaused = pallet_rmrk_core::CallsNotPaused<Test>;
+
+		let buy = crate::Call::<Test>::buy {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			amount: None,
+		};
+		let unlist =
+			crate::Call::<Test>::unlist { collection_id: COLLECTION_ID_0, nft_id: NFT_ID_0 };
+		assert!(CallsNotPaused::contains(&buy));
+		assert_ok!(RmrkCore::pause_calls(
+			Origin::root(),
+			CallGroup::Trading,
+			Some(COLLECTION_ID_0)
+		));
+		assert!(!CallsNotPaused::contains(&buy));
+		// Listings can still be taken down while trading is paused
+		assert!(CallsNotPaused::contains(&unlist));
+	});
+}
+
+#[test]
+fn market_lock_id_matches_the_core_migration() {
+	// Locks set before `Lock` was replaced by `Locks` are migrated under this identifier
+	assert_eq!(MARKET_LOCK_ID, pallet_rmrk_core::migration::v1::MARKET_LOCK_ID);
+}
@@ -5,6 +5,10 @@
 //! DATE: 2022-11-14, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `Sergejs-MacBook-Air.local`, CPU: `<UNKNOWN>`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! The weights of the calls added or changed since this run are estimated from their storage
+//! accesses and marked as such. Regenerate this file with `make benchmark-market` before
+//! relying on them.
 
 // Executed Command:
 // ./target/release/rmrk-substrate
@@ -40,6 +44,7 @@ pub trait WeightInfo {
 	fn make_offer() -> Weight;
 	fn withdraw_offer() -> Weight;
 	fn accept_offer() -> Weight;
+	fn force_unlist() -> Weight;
 }
 
 /// Weight functions for `pallet_rmrk_core`.
@@ -47,7 +52,7 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: RmrkMarket ListedNfts (r:1 w:1)
-	// Storage: RmrkCore Lock (r:1 w:1)
+	// Storage: RmrkCore Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: RmrkCore Nfts (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:0)
@@ -61,7 +66,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques Asset (r:1 w:0)
 	// Storage: RmrkCore Nfts (r:1 w:0)
 	// Storage: Uniques Class (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:1)
+	// Storage: RmrkCore Locks (r:1 w:1)
 	// Storage: RmrkMarket ListedNfts (r:0 w:1)
 	fn list() -> Weight {
 		Weight::from_ref_time(26_000_000 as u64)
@@ -70,7 +75,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: RmrkMarket ListedNfts (r:1 w:1)
 	// Storage: Uniques Asset (r:1 w:0)
-	// Storage: RmrkCore Lock (r:1 w:1)
+	// Storage: RmrkCore Locks (r:1 w:1)
 	fn unlist() -> Weight {
 		Weight::from_ref_time(23_000_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
@@ -93,7 +98,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: RmrkMarket Offers (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
-	// Storage: RmrkCore Lock (r:1 w:1)
+	// Storage: RmrkCore Locks (r:1 w:1)
 	// Storage: RmrkCore Nfts (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:0)
 	// Storage: Uniques Account (r:0 w:2)
@@ -103,4 +108,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
+	// Storage: RmrkMarket ListedNfts (r:1 w:1)
+	// Storage: RmrkCore Locks (r:0 w:1)
+	fn force_unlist() -> Weight {
+		// Estimated from the storage accesses, not benchmarked yet
+		Weight::from_ref_time(19_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
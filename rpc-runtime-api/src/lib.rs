@@ -16,6 +16,8 @@ pub type PropertyKey = RpcString;
 
 pub type ThemeName = RpcString;
 
+pub type PriorityContext = RpcString;
+
 sp_api::decl_runtime_apis! {
 	pub trait RmrkApi<
 		AccountId,
@@ -86,6 +88,9 @@ sp_api::decl_runtime_apis! {
 		/// Get NFT resource priority
 		fn nft_resource_priority(collection_id: CollectionId, nft_id: NftId, resource_id: ResourceId) -> Result<Option<u32>>;
 
+		/// Get the NFT resource to show in a context, falling back to the default priorities
+		fn nft_resource_for_context(collection_id: CollectionId, nft_id: NftId, context: Option<PriorityContext>) -> Result<Option<ResourceInfo>>;
+
 		/// Get base info
 		fn base(base_id: BaseId) -> Result<Option<BaseInfo>>;
 
@@ -192,6 +192,16 @@ pub trait RmrkApi<
 		at: Option<BlockHash>,
 	) -> RpcResult<Option<u32>>;
 
+	#[method(name = "nftResourceForContext")]
+	/// Get the NFT resource to show in a context, falling back to the default priorities
+	fn nft_resource_for_context(
+		&self,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		context: Option<String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ResourceInfo>>;
+
 	#[method(name = "base")]
 	/// Get base info
 	fn base(&self, base_id: BaseId, at: Option<BlockHash>) -> RpcResult<Option<BaseInfo>>;
@@ -299,6 +309,15 @@ where
 	);
 	pass_method!(nft_resources(collection_id: CollectionId, nft_id: NftId) -> Vec<ResourceInfo>);
 	pass_method!(nft_resource_priority(collection_id: CollectionId, nft_id: NftId, resource_id: ResourceId) -> Option<u32>);
+	pass_method!(
+		nft_resource_for_context(
+			collection_id: CollectionId,
+			nft_id: NftId,
+
+			#[map(|context| context.map(|context| context.into_bytes()))]
+			context: Option<String>
+		) -> Option<ResourceInfo>
+	);
 	pass_method!(base(base_id: BaseId) -> Option<BaseInfo>);
 	pass_method!(base_parts(base_id: BaseId) -> Vec<PartType>);
 	pass_method!(theme_names(base_id: BaseId) -> Vec<ThemeName>);
@@ -49,7 +49,7 @@ use pallet_rmrk_core::{CollectionInfoOf, InstanceInfoOf, PropertyInfoOf, Resourc
 use pallet_rmrk_equip::{BaseInfoOf, BoundedThemeOf, PartTypeOf};
 use rmrk_traits::{
 	primitives::{BaseId, CollectionId, NftId, ResourceId},
-	NftChild,
+	CallGroup, NftChild, PausableCall,
 };
 
 /// Import the template pallet.
@@ -203,7 +203,18 @@ impl Contains<RuntimeCall> for BaseFilter {
 				RuntimeCall::Uniques(pallet_uniques::Call::thaw_collection { .. }) |
 				RuntimeCall::Uniques(pallet_uniques::Call::transfer { .. }) |
 				RuntimeCall::Uniques(pallet_uniques::Call::transfer_ownership { .. })
-		)
+		) && pallet_rmrk_core::CallsNotPaused::<Runtime>::contains(call)
+	}
+}
+
+impl PausableCall<CollectionId> for RuntimeCall {
+	fn is_paused(&self, is_paused: impl Fn(CallGroup, Option<&CollectionId>) -> bool) -> bool {
+		match self {
+			RuntimeCall::RmrkCore(call) => call.is_paused(is_paused),
+			RuntimeCall::RmrkEquip(call) => call.is_paused(is_paused),
+			RuntimeCall::RmrkMarket(call) => call.is_paused(is_paused),
+			_ => false,
+		}
 	}
 }
 
@@ -352,10 +363,21 @@ parameter_types! {
 	pub const ResourceSymbolLimit: u32 = 10;
 	pub const PartsLimit: u32 = 25;
 	pub const MaxPriorities: u32 = 25;
+	pub const MaxPriorityContexts: u32 = 10;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const MaxResourcesOnMint: u32 = 100;
+	pub const MaxResourcesPerNft: u32 = 200;
+	pub const MaxProposalsPerNft: u32 = 10;
+	pub const MaxReplacementsPerNft: u32 = 5;
+	pub const MaxCollectionResources: u32 = 20;
+	pub const MaxCollectionResourceNfts: u32 = 50;
 	pub const PropertiesLimit: u32 = 25;
 	pub const NestingBudget: u32 = 20;
+	pub const MaxBatchSends: u32 = 50;
+	pub const MaxBatchResources: u32 = 50;
+	pub const MaxDestroyItems: u32 = 20;
+	pub const MaxLocks: u32 = 10;
+	pub const StorageDepositPerByte: Balance = CENTS;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -367,14 +389,25 @@ impl pallet_rmrk_core::Config for Runtime {
 	type ResourceSymbolLimit = ResourceSymbolLimit;
 	type PartsLimit = PartsLimit;
 	type MaxPriorities = MaxPriorities;
+	type MaxPriorityContexts = MaxPriorityContexts;
 	type CollectionSymbolLimit = CollectionSymbolLimit;
 	type MaxResourcesOnMint = MaxResourcesOnMint;
+	type MaxResourcesPerNft = MaxResourcesPerNft;
+	type MaxProposalsPerNft = MaxProposalsPerNft;
+	type MaxReplacementsPerNft = MaxReplacementsPerNft;
+	type MaxCollectionResources = MaxCollectionResources;
+	type MaxCollectionResourceNfts = MaxCollectionResourceNfts;
 	type PropertiesLimit = PropertiesLimit;
 	type NestingBudget = NestingBudget;
+	type MaxBatchSends = MaxBatchSends;
+	type MaxBatchResources = MaxBatchResources;
+	type MaxDestroyItems = MaxDestroyItems;
+	type MaxLocks = MaxLocks;
+	type StorageDepositPerByte = StorageDepositPerByte;
 	type WeightInfo = pallet_rmrk_core::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = RmrkBenchmark;
-	type TransferHooks = ();
+	type NftHooks = RmrkEquip;
 }
 
 parameter_types! {
@@ -485,6 +518,8 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (pallet_rmrk_core::migration::v1::MigrateToV1<Runtime>,);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -492,6 +527,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 fn option_filter_keys_to_set<StringLimit: frame_support::traits::Get<u32>>(
@@ -659,6 +695,19 @@ impl_runtime_apis! {
 			Ok(priority)
 		}
 
+		fn nft_resource_for_context(
+			collection_id: CollectionId,
+			nft_id: NftId,
+			context: Option<pallet_rmrk_rpc_runtime_api::PriorityContext>
+		) -> pallet_rmrk_rpc_runtime_api::Result<Option<ResourceInfoOf<Runtime>>> {
+			let context: Option<BoundedVec<u8, <Self as pallet_uniques::Config>::KeyLimit>> = context
+				.map(|context| context.try_into())
+				.transpose()
+				.map_err(|_| DispatchError::Other("Can't read context"))?;
+
+			Ok(RmrkCore::resource_for_context(collection_id, nft_id, context.as_ref()))
+		}
+
 		fn base(base_id: BaseId) -> pallet_rmrk_rpc_runtime_api::Result<Option<BaseInfoOf<Runtime>>> {
 			Ok(RmrkEquip::bases(base_id))
 		}
@@ -1114,10 +1114,7 @@ export default {
         collectionId: 'u32',
         nftId: 'u32',
       },
-      change_collection_issuer: {
-        collectionId: 'u32',
-        newIssuer: 'MultiAddress',
-      },
+      __Unused8: 'Null',
       set_property: {
         collectionId: 'u32',
         maybeNftId: 'Option<u32>',
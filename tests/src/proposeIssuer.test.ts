@@ -11,7 +11,7 @@ describe("integration test: collection issuer", () => {
     api = await getApiConnection();
   });
 
-  it("change collection issuer", async () => {
+  it("propose and accept collection issuer", async () => {
     await createCollection(
       api,
       30,
@@ -24,7 +24,7 @@ describe("integration test: collection issuer", () => {
     });
   });
 
-  it("[negative] change not an owner NFT collection issuer", async () => {
+  it("[negative] propose issuer of not an owned NFT collection", async () => {
     await createCollection(
       api,
       31,
@@ -38,7 +38,7 @@ describe("integration test: collection issuer", () => {
     });
   });
 
-  it("[negative] change non-existigit NFT collection issuer", async () => {
+  it("[negative] propose issuer of non-existing NFT collection", async () => {
     await createCollection(
       api,
       32,
@@ -49,7 +49,8 @@ export async function createCollection(
   issuerUri: string,
   metadata: string,
   max: number | null,
-  symbol: string
+  symbol: string,
+  policy = { transferabilityMutable: false, revocable: false }
 ): Promise<number> {
   // const oldCollectionCount = await getCollectionsCount(api);
   const maxOptional = max ? max.toString() : null;
@@ -59,7 +60,8 @@ export async function createCollection(
     collectionId,
     metadata,
     maxOptional,
-    symbol
+    symbol,
+    policy
   );
   const events = await executeTransaction(api, issuer, tx);
 
@@ -130,15 +132,13 @@ export async function changeIssuer(
   const alice = privateKey(issuerUri, Number(ss58Format));
   const bob = privateKey(newIssuer, Number(ss58Format));
 
-  let tx = api.tx.uniques.setAcceptOwnership(
-    api.createType("Option<u32>", collectionId)
-  );
-  let events = await executeTransaction(api, bob, tx);
-  expect(isTxResultSuccess(events), "Error: Unable to accept ownership").to.be
+  let tx = api.tx.rmrkCore.proposeIssuer(collectionId, bob.address);
+  let events = await executeTransaction(api, alice, tx);
+  expect(isTxResultSuccess(events), "Error: Unable to propose issuer").to.be
     .true;
 
-  tx = api.tx.rmrkCore.changeCollectionIssuer(collectionId, bob.address);
-  events = await executeTransaction(api, alice, tx);
+  tx = api.tx.rmrkCore.acceptIssuer(collectionId);
+  events = await executeTransaction(api, bob, tx);
   const changeIssuerResult = extractRmrkCoreTxResult(
     events,
     "IssuerChanged",
@@ -269,7 +269,8 @@ export async function mintNft(
   transferable: boolean = true,
   resources:
     | { resource: { basic?: any; composable?: any; slot?: any }; id: number }[]
-    | null = null
+    | null = null,
+  expiresAt: number | null = null
 ): Promise<number> {
   let nftId = 0;
   const ss58Format = api.registry.getChainProperties()!.toJSON().ss58Format;
@@ -295,7 +296,8 @@ export async function mintNft(
     royaltyOptional,
     metadata,
     transferable,
-    resources
+    resources,
+    expiresAt
   );
 
   const events = await executeTransaction(api, issuer, tx);
@@ -464,7 +466,7 @@ export async function sendNft(
 
   const nftBeforeSendingOpt = await getNft(api, collectionId, nftId);
 
-  const tx = api.tx.rmrkCore.send(collectionId, nftId, newOwnerObj);
+  const tx = api.tx.rmrkCore.send(collectionId, nftId, newOwnerObj, null);
   const events = await executeTransaction(api, originalOwner, tx);
 
   const sendResult = extractRmrkCoreTxResult(events, "NFTSent", (data) => {
@@ -616,13 +618,14 @@ export async function rejectNft(
   api: ApiPromise,
   issuerUri: string,
   collectionId: number,
-  nftId: number
+  nftId: number,
+  burn: boolean = true
 ) {
   const ss58Format = api.registry.getChainProperties()!.toJSON().ss58Format;
   const issuer = privateKey(issuerUri, Number(ss58Format));
   let nftBeforeOpt = await getNft(api, collectionId, nftId);
 
-  const tx = api.tx.rmrkCore.rejectNft(collectionId, nftId);
+  const tx = api.tx.rmrkCore.rejectNft(collectionId, nftId, burn);
   const events = await executeTransaction(api, issuer, tx);
   const rejectResult = extractRmrkCoreTxResult(
     events,
@@ -656,8 +659,17 @@ export async function rejectNft(
   expect(isPendingBeforeReject, "Error: NFT should be pending to be rejected")
     .to.be.true;
 
-  expect(nftAfter.isNone, "Error: NFT should be burned after reject").to.be
-    .true;
+  if (burn) {
+    expect(nftAfter.isNone, "Error: NFT should be burned after reject").to.be
+      .true;
+  } else {
+    expect(nftAfter.isSome, "Error: NFT should not be burned after reject").to
+      .be.true;
+    expect(
+      nftAfter.unwrap().pending.isFalse,
+      "Error: NFT should not be pending after reject"
+    ).to.be.true;
+  }
 }
 
 export async function createBase(
@@ -1010,7 +1022,7 @@ export async function lockCollection(
 
   await getCollection(api, collectionId).then((collectionOption) => {
     const collection = collectionOption.unwrap();
-    expect(collection.max.unwrap().toNumber()).to.be.equal(0);
+    expect(collection.locked.isTrue).to.be.true;
   });
 }
 
@@ -1216,7 +1228,8 @@ export async function addNftBasicResource(
     collectionId,
     nftId,
     basicResource,
-    resId
+    resId,
+    null
   );
 
   const resource = await executeResourceCreation(
@@ -1280,7 +1293,8 @@ export async function addNftComposableResource(
     collectionId,
     nftId,
     composableResource,
-    resId
+    resId,
+    null
   );
 
   const resource = await executeResourceCreation(
@@ -1347,7 +1361,8 @@ export async function addNftSlotResource(
     collectionId,
     nftId,
     slotResource,
-    resId
+    resId,
+    null
   );
 
   const resource = await executeResourceCreation(
@@ -13,6 +13,17 @@ use serde::Serialize;
 use crate::serialize;
 use sp_std::result::Result;
 
+/// Policy of a collection regarding its non-transferable ("soulbound") NFTs, declared at
+/// creation.
+#[cfg_attr(feature = "std", derive(Serialize))]
+#[derive(Encode, Decode, Default, Eq, PartialEq, Copy, Clone, Debug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionPolicy {
+	/// The issuer can change the transferability of minted NFTs
+	pub transferability_mutable: bool,
+	/// The issuer can burn non-transferable NFTs it doesn't own
+	pub revocable: bool,
+}
+
 /// Collection info.
 #[cfg_attr(feature = "std", derive(PartialEq, Eq, Serialize))]
 #[derive(Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
@@ -35,6 +46,12 @@ pub struct CollectionInfo<BoundedString, BoundedSymbol, AccountId> {
 	#[cfg_attr(feature = "std", serde(with = "serialize::vec"))]
 	pub symbol: BoundedSymbol,
 	pub nfts_count: u32,
+	/// Locked collections can't mint new NFTs and their `max` can't be raised
+	pub locked: bool,
+	/// Whether an NFT was ever minted in the collection, after which it can't be unlocked
+	pub ever_minted: bool,
+	/// Soulbound policy of the collection
+	pub policy: CollectionPolicy,
 }
 
 /// Abstraction over a Collection system.
@@ -47,6 +64,7 @@ pub trait Collection<BoundedString, BoundedSymbol, AccountId, CollectionId> {
 		metadata: BoundedString,
 		max: Option<u32>,
 		symbol: BoundedSymbol,
+		policy: CollectionPolicy,
 	) -> Result<(), DispatchError>;
 	fn collection_burn(issuer: AccountId, collection_id: CollectionId) -> DispatchResult;
 	fn collection_change_issuer(
@@ -57,4 +75,19 @@ pub trait Collection<BoundedString, BoundedSymbol, AccountId, CollectionId> {
 		sender: AccountId,
 		collection_id: CollectionId,
 	) -> Result<CollectionId, DispatchError>;
+	fn collection_unlock(
+		sender: AccountId,
+		collection_id: CollectionId,
+	) -> Result<CollectionId, DispatchError>;
+	fn collection_set_max(
+		sender: AccountId,
+		collection_id: CollectionId,
+		max: Option<u32>,
+	) -> Result<CollectionId, DispatchError>;
+	fn collection_start_destroy(issuer: AccountId, collection_id: CollectionId) -> DispatchResult;
+	/// Returns the number of burned NFTs and of removed collection properties
+	fn collection_destroy_items(
+		collection_id: CollectionId,
+		limit: u32,
+	) -> Result<(u32, u32), DispatchError>;
 }
@@ -10,6 +10,7 @@ pub mod collection;
 pub mod misc;
 pub mod nft;
 pub mod part;
+pub mod pause;
 pub mod phantom_type;
 pub mod priority;
 pub mod property;
@@ -18,15 +19,18 @@ mod serialize;
 pub mod theme;
 
 pub use base::{Base, BaseInfo};
-pub use collection::{Collection, CollectionInfo};
+pub use collection::{Collection, CollectionInfo, CollectionPolicy};
+pub use misc::NftHooks;
+#[allow(deprecated)]
 pub use misc::TransferHooks;
 pub use nft::{AccountIdOrCollectionNftTuple, Nft, NftChild, NftInfo, RoyaltyInfo};
 pub use part::{EquippableList, FixedPart, PartType, SlotPart};
+pub use pause::{CallGroup, PausableCall};
 pub use priority::Priority;
 pub use property::{Property, PropertyInfo};
 pub use resource::{
-	BasicResource, ComposableResource, Resource, ResourceInfo, ResourceInfoMin, ResourceTypes,
-	SlotResource,
+	BasicResource, ComposableResource, MediaResource, Resource, ResourceInfo, ResourceInfoMin,
+	ResourceTypes, SlotResource,
 };
 pub use theme::{Theme, ThemeProperty};
 pub mod primitives {
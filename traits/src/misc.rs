@@ -2,8 +2,14 @@
 // This file is part of rmrk-substrate.
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
+use sp_runtime::DispatchResult;
+
+use crate::{nft::AccountIdOrCollectionNftTuple, primitives::ResourceId};
+
 /// Trait for pre-checks and post-checks for transfers that can be implemented downstream to extend
 /// the logic of RMRK's current funcitonality.
+#[deprecated(note = "Implement `NftHooks` instead, or wrap the implementation in \
+	`pallet_rmrk_core::TransferHooksAdapter` to use it as `NftHooks`")]
 pub trait TransferHooks<AccountId, CollectionId, NftId> {
 	/// Check if the NFT's pre-checks and post-checks for the transfer function based on the sender,
 	/// `collection_id` and `nft_id` parameters.
@@ -16,6 +22,7 @@ pub trait TransferHooks<AccountId, CollectionId, NftId> {
 	) -> bool;
 }
 
+#[allow(deprecated)]
 impl<AccountId, CollectionId, NftId> TransferHooks<AccountId, CollectionId, NftId> for () {
 	fn pre_check(_sender: &AccountId, _collection_id: &CollectionId, _nft_id: &NftId) -> bool {
 		true
@@ -30,3 +37,204 @@ impl<AccountId, CollectionId, NftId> TransferHooks<AccountId, CollectionId, NftI
 		true
 	}
 }
+
+/// Hooks called along the lifecycle of an NFT, that can be implemented downstream to extend the
+/// logic of RMRK's current functionality. An error returned by a hook fails the call and is
+/// returned to the caller. Every hook does nothing by default, and several implementations can be
+/// combined as a tuple.
+pub trait NftHooks<AccountId, CollectionId, NftId> {
+	/// Called before an NFT is transferred by `sender`.
+	fn pre_transfer(
+		_sender: &AccountId,
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called once an NFT has been transferred to `recipient`, the account of a parent NFT when
+	/// sent to an NFT.
+	fn post_transfer(
+		_sender: &AccountId,
+		_recipient: &AccountId,
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called once an NFT has been minted by `minter`.
+	fn post_mint(
+		_minter: &AccountId,
+		_owner: &AccountIdOrCollectionNftTuple<AccountId, CollectionId, NftId>,
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called once an NFT has been burned, for the NFT and for each of its burned children.
+	fn post_burn(
+		_owner: &AccountId,
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called once a pending NFT has been accepted.
+	fn post_accept(
+		_sender: &AccountId,
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called once a pending NFT has been rejected, `burned` tells whether it was burned or sent
+	/// back to its previous owner.
+	fn post_reject(
+		_sender: &AccountId,
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+		_burned: bool,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called once a resource has been added to an NFT, pending or not.
+	fn post_resource_add(
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+		_resource_id: &ResourceId,
+		_pending: bool,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called before a resource is removed from an NFT, while the resource is still in storage.
+	fn pre_resource_remove(
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+		_resource_id: &ResourceId,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called once a resource has been removed from an NFT.
+	fn post_resource_remove(
+		_collection_id: &CollectionId,
+		_nft_id: &NftId,
+		_resource_id: &ResourceId,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called once a property has been set on a collection, or on one of its NFTs.
+	fn post_property_set(
+		_collection_id: &CollectionId,
+		_maybe_nft_id: &Option<NftId>,
+		_key: &[u8],
+		_value: &[u8],
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, CollectionId, NftId> NftHooks<AccountId, CollectionId, NftId> for Tuple {
+	fn pre_transfer(
+		sender: &AccountId,
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::pre_transfer(sender, collection_id, nft_id)?; )* );
+		Ok(())
+	}
+
+	fn post_transfer(
+		sender: &AccountId,
+		recipient: &AccountId,
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::post_transfer(sender, recipient, collection_id, nft_id)?; )* );
+		Ok(())
+	}
+
+	fn post_mint(
+		minter: &AccountId,
+		owner: &AccountIdOrCollectionNftTuple<AccountId, CollectionId, NftId>,
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::post_mint(minter, owner, collection_id, nft_id)?; )* );
+		Ok(())
+	}
+
+	fn post_burn(
+		owner: &AccountId,
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::post_burn(owner, collection_id, nft_id)?; )* );
+		Ok(())
+	}
+
+	fn post_accept(
+		sender: &AccountId,
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::post_accept(sender, collection_id, nft_id)?; )* );
+		Ok(())
+	}
+
+	fn post_reject(
+		sender: &AccountId,
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+		burned: bool,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::post_reject(sender, collection_id, nft_id, burned)?; )* );
+		Ok(())
+	}
+
+	fn post_resource_add(
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+		resource_id: &ResourceId,
+		pending: bool,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::post_resource_add(collection_id, nft_id, resource_id, pending)?; )* );
+		Ok(())
+	}
+
+	fn pre_resource_remove(
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+		resource_id: &ResourceId,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::pre_resource_remove(collection_id, nft_id, resource_id)?; )* );
+		Ok(())
+	}
+
+	fn post_resource_remove(
+		collection_id: &CollectionId,
+		nft_id: &NftId,
+		resource_id: &ResourceId,
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::post_resource_remove(collection_id, nft_id, resource_id)?; )* );
+		Ok(())
+	}
+
+	fn post_property_set(
+		collection_id: &CollectionId,
+		maybe_nft_id: &Option<NftId>,
+		key: &[u8],
+		value: &[u8],
+	) -> DispatchResult {
+		for_tuples!( #( Tuple::post_property_set(collection_id, maybe_nft_id, key, value)?; )* );
+		Ok(())
+	}
+}
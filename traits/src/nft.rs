@@ -123,5 +123,6 @@ pub trait Nft<AccountId, BoundedString, BoundedResourceVec, CollectionId, NftId>
 		sender: AccountId,
 		collection_id: CollectionId,
 		nft_id: NftId,
+		burn: bool,
 	) -> DispatchResultWithPostInfo;
 }
@@ -0,0 +1,35 @@
+// Copyright (C) 2021-2022 RMRK
+// This file is part of rmrk-substrate.
+// License: Apache 2.0 modified by RMRK, see LICENSE.md
+
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::MaxEncodedLen;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Groups of calls that can be paused together, either globally or for a single collection.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CallGroup {
+	/// Minting NFTs and creating collections
+	Minting,
+	/// Burning NFTs and destroying collections
+	Burning,
+	/// Sending, accepting and rejecting NFTs, and managing transfer approvals
+	Transfers,
+	/// Adding, replacing, accepting and removing resources
+	Resources,
+	/// Equipping and unequipping items
+	Equipping,
+	/// Listing, buying and making or accepting offers on the market
+	Trading,
+	/// Setting the properties of collections and NFTs
+	Properties,
+}
+
+/// Implemented by the calls of the RMRK pallets, and by the runtime call to dispatch to them, so
+/// that paused calls can be filtered out before being dispatched.
+pub trait PausableCall<CollectionId> {
+	/// Returns whether the call must be filtered out. `is_paused` tells whether a call group is
+	/// paused for a collection, `None` being used for calls not targeting a collection.
+	fn is_paused(&self, is_paused: impl Fn(CallGroup, Option<&CollectionId>) -> bool) -> bool;
+}
@@ -71,6 +71,38 @@ pub struct SlotResource<BoundedString> {
 	pub slot: SlotId,
 }
 
+/// A basic resource describing its asset, so that clients can check the integrity of the asset
+/// and pick a renderer without fetching the metadata first.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+#[cfg_attr(feature = "std", serde(bound = "BoundedString: AsRef<[u8]>"))]
+pub struct MediaResource<BoundedString> {
+	/// Reference to IPFS location of metadata
+	#[cfg_attr(feature = "std", serde(with = "serialize::vec"))]
+	pub metadata: BoundedString,
+
+	/// MIME type of the asset, e.g. `image/svg+xml`
+	#[cfg_attr(feature = "std", serde(with = "serialize::opt_vec"))]
+	pub media_type: Option<BoundedString>,
+
+	/// Hash of the asset, e.g. its blake2 hash or multihash
+	#[cfg_attr(feature = "std", serde(with = "serialize::opt_vec"))]
+	pub hash: Option<BoundedString>,
+
+	/// Reference to the location of a thumbnail of the asset
+	#[cfg_attr(feature = "std", serde(with = "serialize::opt_vec"))]
+	pub thumb: Option<BoundedString>,
+
+	/// URI of the license of the asset
+	#[cfg_attr(feature = "std", serde(with = "serialize::opt_vec"))]
+	pub license: Option<BoundedString>,
+
+	/// Reference to the location of the asset, used as a fallback when the metadata can't be
+	/// fetched
+	#[cfg_attr(feature = "std", serde(with = "serialize::opt_vec"))]
+	pub src: Option<BoundedString>,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize))]
 #[cfg_attr(
@@ -84,6 +116,7 @@ pub enum ResourceTypes<BoundedString, BoundedParts> {
 	Basic(BasicResource<BoundedString>),
 	Composable(ComposableResource<BoundedString, BoundedParts>),
 	Slot(SlotResource<BoundedString>),
+	Media(MediaResource<BoundedString>),
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]